@@ -52,17 +52,17 @@ impl<T> ApiResponse<T> {
             errors: None,
         }
     }
+}
 
-    fn error(errors: Vec<String>) -> Self {
-        Self {
-            success: false,
-            data: None,
-            errors: Some(errors),
-        }
+// `ZodJson` works with any `HasSchema` type: `#[derive(ZodSchema)]` implements it,
+// and hand-written schemas can implement it directly.
+impl HasSchema for CreateUserRequest {
+    fn schema() -> impl Schema<Value> + Send + Sync {
+        create_user_schema()
     }
 }
 
-fn create_user_schema() -> impl Schema<Value> {
+fn create_user_schema() -> impl Schema<Value> + Send + Sync {
     object()
         .field("name", string().min(2).max(50))
         .field("email", string().email())
@@ -71,60 +71,37 @@ fn create_user_schema() -> impl Schema<Value> {
         .field("profile", create_profile_schema())
 }
 
-fn create_profile_schema() -> impl Schema<Value> {
+fn create_profile_schema() -> impl Schema<Value> + Send + Sync {
     object()
         .optional_field("bio", string().max(500))
         .optional_field("website", string().url())
         .field("social_links", create_social_links_schema())
 }
 
-fn create_social_links_schema() -> impl Schema<Value> {
-    object()
-}
-
-fn validate_user_request(data: &Value) -> Result<(), Vec<String>> {
-    let schema = create_user_schema();
-    match schema.validate(data) {
-        Ok(_) => Ok(()),
-        Err(validation_result) => {
-            let errors = validation_result
-                .issues
-                .iter()
-                .map(|issue| issue.to_string())
-                .collect();
-            Err(errors)
-        }
-    }
+fn create_social_links_schema() -> impl Schema<Value> + Send + Sync {
+    record(string(), string().url())
 }
 
 async fn health_check() -> impl IntoResponse {
     ResponseJson(ApiResponse::success("Server is running"))
 }
 
-async fn create_user(Json(payload): Json<Value>) -> impl IntoResponse {
-    match validate_user_request(&payload) {
-        Ok(_) => {
-            let user_data: CreateUserRequest = serde_json::from_value(payload).unwrap();
-
-            let new_user = User {
-                id: 1,
-                name: user_data.name,
-                email: user_data.email,
-                age: user_data.age,
-                interests: user_data.interests,
-                profile: user_data.profile,
-            };
-
-            (
-                StatusCode::CREATED,
-                ResponseJson(ApiResponse::success(new_user)),
-            )
-        }
-        Err(errors) => (
-            StatusCode::BAD_REQUEST,
-            ResponseJson(ApiResponse::<User>::error(errors)),
-        ),
-    }
+// Invalid bodies never reach the handler: `ZodJson` responds with 400 and the
+// validation issues as JSON
+async fn create_user(ZodJson(user_data): ZodJson<CreateUserRequest>) -> impl IntoResponse {
+    let new_user = User {
+        id: 1,
+        name: user_data.name,
+        email: user_data.email,
+        age: user_data.age,
+        interests: user_data.interests,
+        profile: user_data.profile,
+    };
+
+    (
+        StatusCode::CREATED,
+        ResponseJson(ApiResponse::success(new_user)),
+    )
 }
 
 async fn get_users() -> impl IntoResponse {
@@ -176,18 +153,12 @@ async fn validate_data(Json(payload): Json<Value>) -> impl IntoResponse {
                 "validated": validated_string,
                 "valid": true
             });
-            (StatusCode::OK, ResponseJson(ApiResponse::success(response)))
+            (StatusCode::OK, ResponseJson(ApiResponse::success(response))).into_response()
         }
+        // The `axum` feature enables `serde`, so the full issue list, with
+        // machine-readable codes, can be returned as-is
         Err(validation_result) => {
-            let errors = validation_result
-                .issues
-                .iter()
-                .map(|issue| issue.to_string())
-                .collect();
-            (
-                StatusCode::BAD_REQUEST,
-                ResponseJson(ApiResponse::<Value>::error(errors)),
-            )
+            (StatusCode::BAD_REQUEST, ResponseJson(validation_result)).into_response()
         }
     }
 }
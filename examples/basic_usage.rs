@@ -21,7 +21,7 @@ fn main() {
 
     // Email validation
     println!("\n📧 Email Validation:");
-    let email_schema = string().email();
+    let email_schema = string().email().label("email");
 
     match email_schema.safe_parse(&json!("user@example.com")) {
         Ok(email) => println!("✅ Valid email: {email}"),
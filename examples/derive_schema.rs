@@ -1,5 +1,10 @@
+#![allow(dead_code)]
+
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::Arc;
 use zod_rs::prelude::*;
 
 #[derive(Debug, Serialize, Deserialize, ZodSchema)]
@@ -75,7 +80,7 @@ struct CreatePostRequest {
 
 #[derive(Debug, Serialize, Deserialize, ZodSchema)]
 struct Phone {
-    #[zod(length(2))]
+    #[zod(length(2), regex("^[a-z]+$"), regex_flags("i"))]
     alpha_code: String,
 
     #[zod(starts_with("+"))]
@@ -89,6 +94,274 @@ struct Phone {
 
     #[zod(starts_with("+"), includes("-"))]
     full_number: String,
+
+    #[zod(e164)]
+    e164_number: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Signup {
+    #[zod(trim, to_lowercase, email)]
+    email: String,
+
+    #[zod(trim, min_length(3))]
+    display_name: String,
+
+    #[zod(min(13.0), int)]
+    age: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Account {
+    #[zod(
+        min_length(3, message = "Username must be at least 3 characters"),
+        max_length(20)
+    )]
+    username: String,
+
+    #[zod(email, message = "Please enter a valid email address")]
+    email: String,
+
+    #[zod(min(18.0, message = "You must be an adult"), positive)]
+    age: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+#[serde(rename_all = "camelCase")]
+struct Profile {
+    #[zod(min_length(1))]
+    first_name: String,
+
+    last_name: Option<String>,
+
+    #[serde(rename = "e-mail")]
+    #[zod(email)]
+    email_address: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Notification {
+    EmailSent {
+        #[serde(rename = "to")]
+        recipient: String,
+    },
+    #[serde(rename = "SMS")]
+    TextMessage(String),
+    PushDelivered,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema, PartialEq)]
+struct Point(#[zod(finite)] f64, #[zod(finite)] f64, Option<String>);
+
+/// Newtype structs validate as their inner value
+#[derive(Debug, Serialize, Deserialize, ZodSchema, PartialEq)]
+struct EmailAddress(#[zod(email)] String);
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Contact {
+    email: EmailAddress,
+    location: Point,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Reading {
+    #[zod(min(-273.15), max(1e9))]
+    kelvin_offset: f64,
+
+    // Spacing inside the parentheses does not matter
+    #[zod(min( -10 ), max(10))]
+    delta: i32,
+
+    #[zod(multiple_of(0.5), min(0.25))]
+    step: f64,
+}
+
+/// Quarter-hour slots from 9:00, in minutes since midnight
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Appointment {
+    #[zod(min(540), step(540, 15, message = "Pick a quarter-hour slot"))]
+    start_minute: u32,
+}
+
+/// Integer fields are limited to the range of their type
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Pixel {
+    red: u8,
+    offset: i8,
+
+    // The tighter of the attribute and the type's range applies
+    #[zod(min(1.0), max(100000.0))]
+    width: u16,
+
+    id: u64,
+    samples: Vec<u8>,
+}
+
+/// Sent to a browser, so IDs must survive a JavaScript `number`
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Bookmark {
+    #[zod(safe_int)]
+    id: u64,
+
+    #[zod(min_length(1))]
+    url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Article {
+    #[zod(enum("draft", "published", "archived"))]
+    status: String,
+
+    #[zod(enum("en", "fr", message = "Unsupported language"))]
+    language: Option<String>,
+
+    #[zod(each(enum("rust", "web")))]
+    topics: Vec<String>,
+}
+
+/// Maps validate as records with string keys
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Inventory {
+    metadata: HashMap<String, String>,
+
+    #[zod(each(max(100.0)))]
+    stock: BTreeMap<u32, u8>,
+
+    locations: Option<HashMap<String, Bookmark>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Mailing {
+    #[zod(min_length(1), each(min_length(1), email))]
+    recipients: Vec<String>,
+
+    #[zod(each(min(1.0), max(5.0)))]
+    ratings: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Gallery {
+    #[zod(
+        min_length(1, message = "Add at least one photo"),
+        max_length(3, message = "At most 3 photos")
+    )]
+    photos: Vec<String>,
+
+    #[zod(length(2), message = "Expected a latitude and a longitude")]
+    location: Vec<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Comment {
+    /// Assigned by the server; defaults to 0 when missing from the request
+    #[zod(skip)]
+    #[serde(default)]
+    id: u64,
+
+    #[zod(min_length(1))]
+    body: String,
+
+    #[zod(skip)]
+    created_at: Option<String>,
+
+    /// The comment being replied to; may be missing or `null`
+    #[zod(nullish, positive)]
+    reply_to: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Category {
+    #[zod(min_length(1))]
+    name: String,
+
+    children: Vec<Category>,
+
+    parent: Option<Box<Category>>,
+}
+
+/// Mutually recursive with `Edge`
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Node {
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Edge {
+    #[zod(nonnegative)]
+    weight: f64,
+
+    target: Option<Box<Node>>,
+}
+
+/// Smart pointers validate as the type they point to
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Folder {
+    #[allow(clippy::box_collection)]
+    #[zod(min_length(1))]
+    name: Box<String>,
+
+    settings: Box<StrictSettings>,
+
+    shared: Arc<StrictSettings>,
+
+    #[zod(each(positive))]
+    sizes: Rc<Vec<u32>>,
+}
+
+/// Rejects keys it does not declare
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+#[zod(strict)]
+struct StrictSettings {
+    theme: String,
+}
+
+/// Drops keys it does not declare from the validated value
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+#[zod(strip)]
+struct StrippedSettings {
+    theme: String,
+}
+
+/// Keeps keys it does not declare, whatever the default object mode
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+#[zod(passthrough)]
+struct OpenSettings {
+    theme: String,
+}
+
+/// Reusable checks for `#[zod(custom = "...")]`
+mod validators {
+    pub fn is_strong_password(password: &str) -> Result<(), String> {
+        if password.chars().any(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err("Password must contain a digit".to_string())
+        }
+    }
+
+    pub fn is_even(n: &u32) -> Result<(), String> {
+        if n % 2 == 0 {
+            Ok(())
+        } else {
+            Err(format!("{n} is not even"))
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct PasswordChange {
+    #[zod(min_length(8), custom = "crate::validators::is_strong_password")]
+    password: String,
+
+    #[zod(custom = "validators::is_even")]
+    pin_length: u32,
+
+    #[zod(custom = "validators::is_even")]
+    backup_pin_length: Option<u32>,
+
+    #[zod(each(custom = "validators::is_even"))]
+    seats: Vec<u32>,
 }
 
 // ==================== ENUM EXAMPLES ====================
@@ -399,7 +672,8 @@ mod tests {
             "country": "Japan",
             "code": "+81",
             "number": "9012345678",
-            "full_number": "+81-9012345678"
+            "full_number": "+81-9012345678",
+            "e164_number": "+819012345678"
         });
 
         assert!(Phone::validate_and_parse(&vaild_phone_dto).is_ok());
@@ -409,7 +683,8 @@ mod tests {
             "country": "Japan",
             "code": "0081",
             "number": "9012345678",
-            "full_number": "00819012345678"
+            "full_number": "00819012345678",
+            "e164_number": "+819012345678"
         });
 
         assert!(Phone::validate_and_parse(&invaild_phone_dto_code_full_number).is_err());
@@ -419,10 +694,145 @@ mod tests {
             "country": "Japan",
             "code": "+81",
             "number": "9012345678",
-            "full_number": "81-9012345678"
+            "full_number": "81-9012345678",
+            "e164_number": "+819012345678"
         });
 
         assert!(Phone::validate_and_parse(&invaild_phone_dto_full_number).is_err());
+
+        for alpha_code in ["jp", "Jp"] {
+            let mut dto = vaild_phone_dto.clone();
+            dto["alpha_code"] = json!(alpha_code);
+            assert!(Phone::validate_and_parse(&dto).is_ok(), "{alpha_code}");
+        }
+        let mut dto = vaild_phone_dto.clone();
+        dto["alpha_code"] = json!("J1");
+        let err = Phone::validate_and_parse(&dto).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["alpha_code"]);
+
+        for e164_number in ["00819012345678", "+81abc", "+"] {
+            let mut dto = vaild_phone_dto.clone();
+            dto["e164_number"] = json!(e164_number);
+            let err = Phone::validate_and_parse(&dto).unwrap_err();
+            assert_eq!(err.issues[0].path, vec!["e164_number"]);
+        }
+    }
+
+    #[test]
+    fn test_validate_and_parse_owned() {
+        let valid = json!({
+            "alpha_code": "JP",
+            "country": "Japan",
+            "code": "+81",
+            "number": "9012345678",
+            "full_number": "+81-9012345678",
+            "e164_number": "+819012345678"
+        });
+        let borrowed = Phone::validate_and_parse(&valid).unwrap();
+        let owned = Phone::validate_and_parse_owned(valid).unwrap();
+        assert_eq!(owned.e164_number, borrowed.e164_number);
+        assert_eq!(owned.full_number, borrowed.full_number);
+
+        // Integer fields still deserialize once the input is consumed
+        let user = User::validate_and_parse_owned(json!({
+            "username": "test_user",
+            "email": "test@example.com",
+            "age": 25,
+            "interests": ["testing"],
+            "score": 100.0,
+            "is_active": true
+        }))
+        .unwrap();
+        assert_eq!(user.age, 25);
+
+        let invalid = json!({"alpha_code": "JPN"});
+        assert_eq!(
+            Phone::validate_and_parse_owned(invalid.clone()).unwrap_err(),
+            Phone::validate_and_parse(&invalid).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_str_and_slice() {
+        let json = r#"{"name": "Widget", "price": 9.5, "category": "tools", "tags": [], "available": true}"#;
+
+        let from_str = Product::validate_str(json).unwrap();
+        let from_slice = Product::validate_slice(json.as_bytes()).unwrap();
+        assert_eq!(from_str.name, "Widget");
+        assert_eq!(from_slice.price, from_str.price);
+
+        assert!(matches!(
+            Product::validate_str(r#"{"name": "Widget""#),
+            Err(zod_rs_util::ParseError::Json(_))
+        ));
+        let Err(zod_rs_util::ParseError::Validation(err)) =
+            Product::validate_slice(br#"{"name": "", "price": -1}"#)
+        else {
+            panic!("expected a validation error");
+        };
+        assert!(err.issues.iter().any(|issue| issue.path == ["price"]));
+    }
+
+    #[test]
+    fn test_check_typed_value() {
+        let mut user = User {
+            username: "test_user".into(),
+            email: "test@example.com".into(),
+            age: 25,
+            interests: vec!["testing".into()],
+            bio: None,
+            score: 100.0,
+            is_active: true,
+        };
+        assert!(User::schema().check(&user).is_ok());
+
+        user.email = "not-an-email".into();
+        user.age = 7;
+        let err = User::schema().check(&user).unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+        assert!(err.issues.iter().any(|issue| issue.path == ["email"]));
+        assert!(err.issues.iter().any(|issue| issue.path == ["age"]));
+
+        // Serialization errors are reported too
+        let mut map = HashMap::new();
+        map.insert((1, 2), "tuple keys are not valid JSON");
+        let err = object().check(&map).unwrap_err();
+        assert!(err.issues[0]
+            .to_string()
+            .starts_with("Serialization failed:"));
+    }
+
+    #[test]
+    fn test_schema_name_is_type_name() {
+        assert_eq!(<User as HasSchema>::schema_name(), "User");
+        assert_eq!(<Status as HasSchema>::schema_name(), "Status");
+    }
+
+    #[test]
+    fn test_schema_shared_across_threads() {
+        // The first calls race to build the cached schema
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    let valid = json!({
+                        "alpha_code": "JP",
+                        "country": "Japan",
+                        "code": "+81",
+                        "number": "9012345678",
+                        "full_number": "+81-9012345678",
+                        "e164_number": "+819012345678"
+                    });
+                    Phone::validate_and_parse(&valid).is_ok()
+                        && Phone::validate_and_parse(&json!({})).is_err()
+                })
+            })
+            .collect();
+
+        assert!(handles.into_iter().all(|handle| handle.join().unwrap()));
+        assert_eq!(
+            Phone::schema().to_json_schema(),
+            Phone::schema().to_json_schema()
+        );
     }
 
     // ==================== ENUM TESTS ====================
@@ -443,7 +853,11 @@ mod tests {
 
         // Invalid: unknown variant
         let unknown = json!({"Unknown": null});
-        assert!(Status::validate_and_parse(&unknown).is_err());
+        let err = Status::validate_and_parse(&unknown).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid option: expected one of Active | Inactive | Pending"
+        );
 
         // Invalid: wrong format (string instead of object)
         let wrong_format = json!("Active");
@@ -576,4 +990,519 @@ mod tests {
         let result = Message::validate_json(invalid_json_str);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_and_parse_returns_normalized_strings() {
+        let signup = Signup::validate_and_parse(&json!({
+            "email": "  Alice@Example.COM ",
+            "display_name": "  Alice  ",
+            "age": 30
+        }))
+        .unwrap();
+
+        assert_eq!(signup.email, "alice@example.com");
+        assert_eq!(signup.display_name, "Alice");
+        assert_eq!(signup.age, 30);
+
+        let too_short = json!({
+            "email": "bob@example.com",
+            "display_name": "  B ",
+            "age": 30
+        });
+        assert!(Signup::validate_and_parse(&too_short).is_err());
+    }
+
+    #[test]
+    fn test_custom_constraint_messages() {
+        let err = Account::validate_and_parse(&json!({
+            "username": "ab",
+            "email": "nope",
+            "age": 12
+        }))
+        .unwrap_err();
+
+        let messages: Vec<String> = err.issues.iter().map(|issue| issue.to_string()).collect();
+        assert!(messages.contains(&"username: Username must be at least 3 characters".to_string()));
+        assert!(messages.contains(&"email: Please enter a valid email address".to_string()));
+        assert!(messages.contains(&"age: You must be an adult".to_string()));
+
+        // Constraints without a message keep the default one
+        let err = Account::validate_and_parse(&json!({
+            "username": "a".repeat(21),
+            "email": "a@b.com",
+            "age": 30
+        }))
+        .unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "username: Too big: expected string to have <= 20 characters, received 21"
+        );
+    }
+
+    #[test]
+    fn test_serde_rename_all_and_rename_on_struct() {
+        let profile = Profile::validate_and_parse(&json!({
+            "firstName": "Ada",
+            "lastName": "Lovelace",
+            "e-mail": "ada@example.com"
+        }))
+        .unwrap();
+
+        assert_eq!(profile.first_name, "Ada");
+        assert_eq!(profile.last_name.as_deref(), Some("Lovelace"));
+
+        // Rust field names are not accepted as JSON keys
+        let err = Profile::validate_and_parse(&json!({
+            "first_name": "Ada",
+            "email_address": "ada@example.com"
+        }))
+        .unwrap_err();
+        let paths: Vec<_> = err.issues.iter().map(|issue| issue.path.clone()).collect();
+        assert!(paths.contains(&vec!["firstName".to_string()]));
+        assert!(paths.contains(&vec!["e-mail".to_string()]));
+    }
+
+    #[test]
+    fn test_serde_rename_on_enum_variants() {
+        let sent = json!({"email_sent": {"to": "ada@example.com"}});
+        assert_eq!(
+            Notification::validate_and_parse(&sent).unwrap(),
+            Notification::EmailSent {
+                recipient: "ada@example.com".to_string()
+            }
+        );
+
+        let sms = json!({"SMS": "hello"});
+        assert!(Notification::validate_and_parse(&sms).is_ok());
+
+        let push = json!({"push_delivered": null});
+        assert!(Notification::validate_and_parse(&push).is_ok());
+
+        assert!(Notification::validate_and_parse(&json!({"EmailSent": {"to": "x"}})).is_err());
+    }
+
+    #[test]
+    fn test_tuple_struct_validates_json_array() {
+        let point = Point::validate_and_parse(&json!([1.5, -2, "origin"])).unwrap();
+        assert_eq!(point, Point(1.5, -2.0, Some("origin".to_string())));
+
+        let point = Point::validate_and_parse(&json!([0, 0, null])).unwrap();
+        assert_eq!(point, Point(0.0, 0.0, None));
+
+        let err = Point::validate_and_parse(&json!([1, "two", null])).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["1"]);
+        assert!(Point::validate_and_parse(&json!([1, 2])).is_err());
+        assert!(Point::validate_and_parse(&json!({"0": 1, "1": 2})).is_err());
+    }
+
+    #[test]
+    fn test_newtype_struct_unwraps_to_inner_schema() {
+        let email = EmailAddress::validate_and_parse(&json!("ada@example.com")).unwrap();
+        assert_eq!(email, EmailAddress("ada@example.com".to_string()));
+        assert!(EmailAddress::validate_and_parse(&json!("nope")).is_err());
+        assert!(EmailAddress::validate_and_parse(&json!(["ada@example.com"])).is_err());
+
+        let err = Contact::validate_and_parse(&json!({
+            "email": "nope",
+            "location": [1, 2, null]
+        }))
+        .unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["email"]);
+    }
+
+    #[test]
+    fn test_negative_fractional_and_exponent_bounds() {
+        let reading = json!({"kelvin_offset": -273.15, "delta": -10, "step": 0.5});
+        assert!(Reading::validate_and_parse(&reading).is_ok());
+
+        let too_cold = json!({"kelvin_offset": -273.16, "delta": 0, "step": 0.5});
+        let err = Reading::validate_and_parse(&too_cold).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "kelvin_offset: Too small: expected number to have >= -273.15, received -273.16"
+        );
+
+        let too_big = json!({"kelvin_offset": 1e9 + 1.0, "delta": 0, "step": 0.5});
+        assert!(Reading::validate_and_parse(&too_big).is_err());
+
+        let out_of_range = json!({"kelvin_offset": 0, "delta": -11, "step": 0.5});
+        assert!(Reading::validate_and_parse(&out_of_range).is_err());
+
+        let fractional = json!({"kelvin_offset": 0, "delta": 0, "step": 0.75});
+        assert!(Reading::validate_and_parse(&fractional).is_err());
+    }
+
+    #[test]
+    fn test_integer_fields_limited_to_type_range() {
+        let pixel =
+            json!({"red": 255, "offset": -128, "width": 65535, "id": 0, "samples": [0, 255]});
+        let parsed = Pixel::validate_and_parse(&pixel).unwrap();
+        assert_eq!(parsed.red, 255);
+        assert_eq!(parsed.width, 65535);
+
+        // Would overflow in `serde_json::from_value`; fails validation instead
+        let err = Pixel::validate_and_parse(&json!({
+            "red": 300, "offset": 0, "width": 1, "id": 0, "samples": []
+        }))
+        .unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "red: Too big: expected number to have <= 255, received 300"
+        );
+
+        let out_of_range = [
+            json!({"red": 0, "offset": -129, "width": 1, "id": 0, "samples": []}),
+            json!({"red": 0, "offset": 0, "width": 65536, "id": 0, "samples": []}),
+            json!({"red": 0, "offset": 0, "width": 0, "id": 0, "samples": []}),
+            json!({"red": 0, "offset": 0, "width": 1, "id": -1, "samples": []}),
+            json!({"red": 0, "offset": 0, "width": 1, "id": 0, "samples": [256]}),
+        ];
+        for value in &out_of_range {
+            assert!(Pixel::validate_and_parse(value).is_err(), "{value}");
+        }
+
+        let err = Pixel::validate_and_parse(&json!({
+            "red": 0, "offset": 0, "width": 1, "id": 0, "samples": [1, -1]
+        }))
+        .unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["samples", "1"]);
+    }
+
+    #[test]
+    fn test_safe_int_field() {
+        let max_safe = json!({"id": 9_007_199_254_740_991_u64, "url": "https://a.io"});
+        assert!(Bookmark::validate_and_parse(&max_safe).is_ok());
+
+        let err = Bookmark::validate_and_parse(&json!({
+            "id": 9_007_199_254_740_992_u64, "url": "https://a.io"
+        }))
+        .unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "id: Invalid number: must be a safe integer"
+        );
+    }
+
+    #[test]
+    fn test_step_field() {
+        for minute in [540, 555, 1020] {
+            assert!(Appointment::validate_and_parse(&json!({"start_minute": minute})).is_ok());
+        }
+
+        let err = Appointment::validate_and_parse(&json!({"start_minute": 550})).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "start_minute: Pick a quarter-hour slot"
+        );
+        assert!(Appointment::validate_and_parse(&json!({"start_minute": 525})).is_err());
+    }
+
+    #[test]
+    fn test_map_fields_validate_as_records() {
+        let inventory = Inventory::validate_and_parse(&json!({
+            "metadata": {"source": "import"},
+            "stock": {"7": 3, "12": 100},
+            "locations": {"home": {"id": 1, "url": "https://a.io"}}
+        }))
+        .unwrap();
+        assert_eq!(inventory.metadata["source"], "import");
+        assert_eq!(inventory.stock[&12], 100);
+        assert_eq!(inventory.locations.unwrap()["home"].id, 1);
+
+        let cases = [
+            (
+                json!({"metadata": {"source": 1}, "stock": {}}),
+                vec!["metadata", "source"],
+            ),
+            (
+                json!({"metadata": {}, "stock": {"7": 101}}),
+                vec!["stock", "7"],
+            ),
+            (
+                json!({"metadata": {}, "stock": {"7": -1}}),
+                vec!["stock", "7"],
+            ),
+            (json!({"metadata": [], "stock": {}}), vec!["metadata"]),
+            (
+                json!({"metadata": {}, "stock": {}, "locations": {"x": {"id": 1, "url": ""}}}),
+                vec!["locations", "x", "url"],
+            ),
+        ];
+        for (value, path) in cases {
+            let err = Inventory::validate_and_parse(&value).unwrap_err();
+            assert_eq!(err.issues[0].path, path, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_enum_attribute_on_string_fields() {
+        let article = json!({"status": "draft", "language": "fr", "topics": ["rust"]});
+        assert!(Article::validate_and_parse(&article).is_ok());
+
+        let err =
+            Article::validate_and_parse(&json!({"status": "deleted", "topics": []})).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "status: Invalid option: expected one of draft | published | archived"
+        );
+
+        let err = Article::validate_and_parse(&json!({
+            "status": "draft", "language": "de", "topics": ["go"]
+        }))
+        .unwrap_err();
+        let mut messages: Vec<_> = err.issues.iter().map(|i| i.to_string()).collect();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                "language: Unsupported language",
+                "topics.0: Invalid option: expected one of rust | web"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_each_constrains_vec_elements() {
+        let valid = json!({"recipients": ["ada@example.com"], "ratings": [1, 5]});
+        assert!(Mailing::validate_and_parse(&valid).is_ok());
+
+        let bad_email = json!({"recipients": ["ada@example.com", "nope"], "ratings": []});
+        let err = Mailing::validate_and_parse(&bad_email).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["recipients", "1"]);
+
+        let bad_rating = json!({"recipients": ["ada@example.com"], "ratings": [3, 6]});
+        let err = Mailing::validate_and_parse(&bad_rating).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["ratings", "1"]);
+
+        // Array-level constraints still apply to the array itself
+        let empty = json!({"recipients": [], "ratings": []});
+        assert!(Mailing::validate_and_parse(&empty).is_err());
+    }
+
+    #[test]
+    fn test_vec_constraint_messages() {
+        let valid = json!({"photos": ["a.png"], "location": [52.5, 13.4]});
+        assert!(Gallery::validate_and_parse(&valid).is_ok());
+
+        let err = Gallery::validate_and_parse(&json!({"photos": [], "location": [0]})).unwrap_err();
+        let messages: Vec<String> = err.issues.iter().map(|issue| issue.to_string()).collect();
+        assert!(messages.contains(&"photos: Add at least one photo".to_string()));
+        assert!(messages.contains(&"location: Expected a latitude and a longitude".to_string()));
+
+        let err = Gallery::validate_and_parse(&json!({
+            "photos": ["a", "b", "c", "d"],
+            "location": [0, 0, 0]
+        }))
+        .unwrap_err();
+        let messages: Vec<String> = err.issues.iter().map(|issue| issue.to_string()).collect();
+        assert!(messages.contains(&"photos: At most 3 photos".to_string()));
+        assert!(messages.contains(&"location: Expected a latitude and a longitude".to_string()));
+    }
+
+    #[test]
+    fn test_skipped_fields_are_not_validated() {
+        let comment = Comment::validate_and_parse(&json!({"body": "Nice post"})).unwrap();
+        assert_eq!(comment.id, 0);
+        assert_eq!(comment.created_at, None);
+
+        // Skipped keys present in the input pass through to deserialization
+        let comment = Comment::validate_and_parse(&json!({
+            "id": 7,
+            "body": "Nice post",
+            "created_at": "2024-01-01"
+        }))
+        .unwrap();
+        assert_eq!(comment.id, 7);
+        assert_eq!(comment.created_at.as_deref(), Some("2024-01-01"));
+
+        // ... so a bad skipped value surfaces as a deserialization error
+        let err = Comment::validate_and_parse(&json!({"id": "seven", "body": "Hi"})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["id"]);
+        assert!(err.issues[0]
+            .to_string()
+            .starts_with("id: Deserialization failed"));
+    }
+
+    #[test]
+    fn test_nullish_field() {
+        for input in [
+            json!({"body": "Hi"}),
+            json!({"body": "Hi", "reply_to": null}),
+        ] {
+            assert_eq!(Comment::validate_and_parse(&input).unwrap().reply_to, None);
+        }
+
+        let comment = Comment::validate_and_parse(&json!({"body": "Hi", "reply_to": 3})).unwrap();
+        assert_eq!(comment.reply_to, Some(3));
+        assert!(Comment::validate_and_parse(&json!({"body": "Hi", "reply_to": 0})).is_err());
+
+        assert_eq!(
+            Comment::schema().validate(&json!({"body": "Hi"})).unwrap(),
+            json!({"body": "Hi", "reply_to": null})
+        );
+    }
+
+    #[test]
+    fn test_container_object_modes() {
+        let input = json!({"theme": "dark", "beta": true});
+
+        let err = StrictSettings::validate_and_parse(&input).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "Unrecognized key: beta");
+        assert!(StrictSettings::validate_and_parse(&json!({"theme": "dark"})).is_ok());
+        assert_eq!(
+            StrictSettings::schema().to_json_schema()["additionalProperties"],
+            json!(false)
+        );
+
+        assert_eq!(
+            StrippedSettings::schema().validate(&input).unwrap(),
+            json!({"theme": "dark"})
+        );
+        assert_eq!(OpenSettings::schema().validate(&input).unwrap(), input);
+    }
+
+    #[test]
+    fn test_smart_pointer_fields() {
+        let folder = json!({
+            "name": "docs",
+            "settings": {"theme": "dark"},
+            "shared": {"theme": "light"},
+            "sizes": [1, 2]
+        });
+        let parsed = Folder::validate_and_parse(&folder).unwrap();
+        assert_eq!(*parsed.name, "docs");
+        assert_eq!(parsed.shared.theme, "light");
+
+        // `Box<StrictSettings>` delegates to `StrictSettings::schema()`, strict mode included
+        let invalid = json!({
+            "name": "",
+            "settings": {"theme": "dark", "beta": true},
+            "shared": {},
+            "sizes": [0]
+        });
+        let err = Folder::validate_and_parse(&invalid).unwrap_err();
+        assert_eq!(err.issues.len(), 4);
+        assert_eq!(
+            err.errors_for_path(&["settings"])[0].to_string(),
+            "settings: Unrecognized key: beta"
+        );
+        assert_eq!(err.errors_for_path(&["shared", "theme"]).len(), 1);
+        assert_eq!(err.errors_for_path(&["sizes", "0"]).len(), 1);
+    }
+
+    #[test]
+    fn test_custom_validators() {
+        let valid = json!({
+            "password": "hunter22",
+            "pin_length": 4,
+            "backup_pin_length": 6,
+            "seats": [2, 4]
+        });
+        assert!(PasswordChange::validate_and_parse(&valid).is_ok());
+
+        // Built-in rules run first; the custom check sees only values that passed them
+        let err = PasswordChange::validate_and_parse(&json!({
+            "password": "hunter",
+            "pin_length": 4,
+            "seats": []
+        }))
+        .unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "password: Too small: expected string to have >= 8 characters, received 6"
+        );
+
+        let err = PasswordChange::validate_and_parse(&json!({
+            "password": "hunter-two",
+            "pin_length": 5,
+            "backup_pin_length": 3,
+            "seats": [2, 7]
+        }))
+        .unwrap_err();
+        let messages: Vec<String> = err.issues.iter().map(|i| i.to_string()).collect();
+        assert_eq!(err.issues.len(), 4);
+        assert!(messages.contains(&"password: Password must contain a digit".to_string()));
+        assert!(messages.contains(&"pin_length: 5 is not even".to_string()));
+        assert!(messages.contains(&"backup_pin_length: 3 is not even".to_string()));
+        assert!(messages.contains(&"seats.1: 7 is not even".to_string()));
+
+        // Missing optional fields skip the check
+        let change = PasswordChange::validate_and_parse(&json!({
+            "password": "hunter22",
+            "pin_length": 4,
+            "seats": []
+        }))
+        .unwrap();
+        assert_eq!(change.backup_pin_length, None);
+    }
+
+    #[test]
+    fn test_recursive_struct() {
+        let tree = json!({
+            "name": "root",
+            "children": [
+                {
+                    "name": "child",
+                    "children": [{"name": "grandchild", "children": []}]
+                }
+            ],
+            "parent": {"name": "origin", "children": []}
+        });
+
+        let category = Category::validate_and_parse(&tree).unwrap();
+        assert_eq!(category.children[0].children[0].name, "grandchild");
+        assert_eq!(category.parent.unwrap().name, "origin");
+
+        let malformed = json!({
+            "name": "root",
+            "children": [
+                {"name": "child", "children": [{"name": "", "children": []}]}
+            ]
+        });
+
+        let err = Category::validate_and_parse(&malformed).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(
+            err.issues[0].path,
+            vec!["children", "0", "children", "0", "name"]
+        );
+    }
+
+    #[test]
+    fn test_mutually_recursive_structs() {
+        let graph = json!({
+            "edges": [{"weight": 1, "target": {"edges": [{"weight": 2}]}}]
+        });
+        assert!(Node::validate_and_parse(&graph).is_ok());
+
+        let negative = json!({
+            "edges": [{"weight": 1, "target": {"edges": [{"weight": -2}]}}]
+        });
+        let err = Node::validate_and_parse(&negative).unwrap_err();
+        assert_eq!(
+            err.issues[0].path,
+            vec!["edges", "0", "target", "edges", "0", "weight"]
+        );
+    }
+
+    #[test]
+    fn test_user_json_schema_matches_golden_file() {
+        let golden: serde_json::Value =
+            serde_json::from_str(include_str!("golden/user.schema.json")).unwrap();
+        assert_eq!(User::schema().to_json_schema(), golden);
+    }
+
+    fn parse_as<T: HasSchema + serde::de::DeserializeOwned>(value: &Value) -> bool {
+        T::validate_and_parse(value).is_ok()
+    }
+
+    #[test]
+    fn test_has_schema_in_generic_code() {
+        let point = json!([1.5, -2.0, null]);
+        assert!(parse_as::<Point>(&point));
+        assert!(!parse_as::<Category>(&point));
+
+        let errors = <Point as HasSchema>::validate_and_parse(&json!([1])).unwrap_err();
+        assert_eq!(errors, Point::validate_and_parse(&json!([1])).unwrap_err());
+    }
 }
@@ -2,24 +2,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use zod_rs::prelude::*;
 
-#[derive(Debug, Default, Serialize, Deserialize, ZodSchema)]
-struct ApiFieldError {
-    path: String,
-    message: String,
-}
-
-#[derive(Debug, Default, Serialize, Deserialize)]
-struct ApiFieldErrors {
-    errors: Vec<ApiFieldError>,
-}
-
 #[derive(Debug, Serialize, Deserialize, ZodSchema)]
 struct RegisterDto {
     #[zod(min_length(3))]
     username: String,
     #[zod(email, ends_with("@doe.com"))]
     email: String,
-    #[zod(string, min_length(8))]
+    #[zod(min_length(8))]
     password: String,
 }
 
@@ -27,6 +16,9 @@ fn main() {
     println!("🦀 Starting zod-rs i18n Example");
     println!("====================================");
 
+    // Every error rendered with `Display` or `to_string()` now uses Arabic
+    set_default_locale(Locale::Ar);
+
     let input_dto = &json!({
         "username": "j.doe",
         "email": "john@gmail.com",
@@ -38,20 +30,17 @@ fn main() {
     match register_dto {
         Ok(dto) => println!("✅ Valid dto: {}", dto.username),
         Err(err) => {
-            let mut field_errors = ApiFieldErrors::default();
-
-            for issue in err.issues {
-                field_errors.errors.push(ApiFieldError {
-                    path: issue.path.join("."),
-                    message: issue.error.local(Locale::Ar),
-                });
-            }
-
-            for issue in field_errors.errors {
-                println!("❌ Invalid {}: {}\n", issue.path, issue.message)
+            for issue in err {
+                println!("❌ {issue}\n")
             }
         }
     }
 
+    // A single schema can pin its own locale regardless of the default
+    let contact = object().field("email", string().email()).locale(Locale::Fr);
+    if let Err(err) = contact.validate(&json!({"email": "nope"})) {
+        println!("❌ {}\n", err.issues[0]);
+    }
+
     println!("\n🎉 i18n example completed!");
 }
@@ -66,7 +66,7 @@ fn create_settings_schema() -> impl Schema<Value> {
 }
 
 fn create_metadata_schema() -> impl Schema<Value> {
-    object()
+    record(string(), string())
 }
 
 fn create_product_schema() -> impl Schema<Value> {
@@ -98,7 +98,6 @@ where
         },
         Err(validation_result) => {
             let errors: Vec<String> = validation_result
-                .issues
                 .iter()
                 .map(|issue| issue.to_string())
                 .collect();
@@ -308,6 +308,35 @@ fn performance_comparison_example() {
         duration,
         duration.as_micros() as f64 / iterations as f64
     );
+
+    // A large batch whose first item is already invalid: collect-all keeps going
+    // through every item, abort-early stops at the first failure
+    let batch_schema = array(ProductCreateRequest::schema());
+    let mut items = vec![json!({ "name": "" })];
+    items.extend(std::iter::repeat(product_data.clone()).take(999));
+    let batch = Value::Array(items);
+
+    for (label, opts) in [
+        ("collect-all", ValidateOptions::default()),
+        (
+            "abort-early",
+            ValidateOptions {
+                abort_early: true,
+                ..Default::default()
+            },
+        ),
+    ] {
+        let start = std::time::Instant::now();
+        for _ in 0..100 {
+            let _ = batch_schema.validate_with(&batch, opts);
+        }
+        let duration = start.elapsed();
+
+        println!(
+            "✅ Rejected 100 invalid 1000-item batches ({}) in {:?}",
+            label, duration
+        );
+    }
 }
 
 fn schema_reuse_example() {
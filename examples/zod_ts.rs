@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use zod_rs_ts::ZodTs;
 
+// `User::export()` writes the schema to `<crate root>/../target/zod-ts/User.ts`
 #[derive(Debug, Serialize, Deserialize, ZodTs)]
+#[zod(export_to = "../target/zod-ts/")]
 struct User {
     #[zod(min_length(2), max_length(50))]
     username: String,
@@ -12,7 +15,7 @@ struct User {
     #[zod(min(18.0), max(120.0), int)]
     age: u32,
 
-    #[zod(min_length(1), max_length(10))]
+    #[zod(min_length(1), max_length(10), each(min_length(2)))]
     interests: Vec<String>,
 
     bio: Option<String>,
@@ -23,7 +26,10 @@ struct User {
     is_active: bool,
 }
 
+// `export` also generates a test that calls `Status::export()`, so `cargo test`
+// keeps `status.ts` in sync
 #[derive(Debug, Serialize, Deserialize, ZodTs)]
+#[zod(export, export_to = "../target/zod-ts/status.ts")]
 enum Status {
     Active,
     Inactive,
@@ -38,8 +44,37 @@ enum Message {
 
 #[derive(Debug, Serialize, Deserialize, ZodTs)]
 enum Event {
-    Click { x: i32, y: i32 },
-    Scroll { delta: f64 },
+    /// Pointer position in CSS pixels
+    Click {
+        x: i32,
+        y: i32,
+    },
+    Scroll {
+        delta: f64,
+    },
+}
+
+/// Per-workspace settings.
+///
+/// Doc comments are carried over to the generated schema as JSDoc.
+#[derive(Debug, Serialize, Deserialize, ZodTs)]
+struct Settings {
+    /// Request quota per endpoint
+    limits: HashMap<String, i32>,
+    #[zod(each(min_length(1)))]
+    labels: BTreeMap<String, String>,
+    tags: Option<HashMap<String, Vec<String>>>,
+}
+
+// serde writes `None` as `null` unless `skip_serializing_if` drops the key
+#[derive(Debug, Serialize, Deserialize, ZodTs)]
+struct Profile {
+    bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[zod(nullable)]
+    avatar: Option<String>,
 }
 
 fn main() {
@@ -54,6 +89,12 @@ fn main() {
 
     println!("\n=== Event Schema ===\n");
     println!("{}", Event::zod_ts());
+
+    println!("\n=== Settings Schema ===\n");
+    println!("{}", Settings::zod_ts());
+
+    println!("\n=== Profile Schema ===\n");
+    println!("{}", Profile::zod_ts());
 }
 
 #[cfg(test)]
@@ -68,7 +109,7 @@ mod tests {
         assert!(ts.contains("username: z.string().min(2).max(50)"));
         assert!(ts.contains("email: z.string().email()"));
         assert!(ts.contains("age: z.number().int().min(18).max(120)"));
-        assert!(ts.contains("bio: z.string().optional()"));
+        assert!(ts.contains("bio: z.string().nullable()"));
         assert!(ts.contains("export type User = z.infer<typeof UserSchema>"));
     }
 
@@ -96,4 +137,52 @@ mod tests {
         assert!(ts.contains("Scroll:"));
         assert!(ts.contains("delta: z.number()"));
     }
+
+    #[test]
+    fn test_settings_zod_ts() {
+        let ts = Settings::zod_ts();
+        assert!(ts.contains("limits: z.record(z.string(), z.number().int())"));
+        assert!(ts.contains("labels: z.record(z.string(), z.string().min(1))"));
+        assert!(ts.contains("tags: z.record(z.string(), z.array(z.string())).nullable()"));
+    }
+
+    #[test]
+    fn test_profile_nullable_vs_optional() {
+        let ts = Profile::zod_ts();
+        assert!(ts.contains("bio: z.string().nullable()"));
+        assert!(ts.contains("nickname: z.string().optional()"));
+        assert!(ts.contains("avatar: z.string().nullable().optional()"));
+
+        // The TS shape must accept exactly what serde produces
+        let json = serde_json::to_value(Profile {
+            bio: None,
+            nickname: None,
+            avatar: None,
+        })
+        .unwrap();
+        assert_eq!(json, serde_json::json!({"bio": null}));
+    }
+
+    #[test]
+    fn test_doc_comments_become_jsdoc() {
+        let ts = Settings::zod_ts();
+        assert!(ts.contains(
+            "/**\n * Per-workspace settings.\n *\n * Doc comments are carried over to the generated schema as JSDoc.\n */\nexport const SettingsSchema"
+        ));
+        assert!(ts.contains(
+            "  /** Request quota per endpoint */\n  limits: z.record(z.string(), z.number().int())"
+        ));
+
+        let ts = Event::zod_ts();
+        assert!(ts.contains("  /** Pointer position in CSS pixels */\n  z.object({ Click:"));
+        assert!(ts.contains("export const EventSchema"));
+    }
+
+    #[test]
+    fn test_export_writes_schema_file() {
+        User::export().unwrap();
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/zod-ts/User.ts");
+        assert_eq!(std::fs::read_to_string(path).unwrap(), User::zod_ts());
+    }
 }
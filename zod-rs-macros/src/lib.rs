@@ -1,72 +1,121 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Meta};
+use quote::{quote, quote_spanned};
+use std::collections::HashMap;
+use syn::{ext::IdentExt, parse_macro_input, Attribute, Data, DeriveInput, Fields};
 
+/// Derives a schema from a struct or enum, with rules given in `#[zod(...)]`:
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use zod_rs::prelude::*;
+///
+/// #[derive(Deserialize, ZodSchema)]
+/// struct Tag {
+///     #[zod(min_length(1))]
+///     name: String,
+/// }
+///
+/// assert!(Tag::validate_and_parse(&json!({"name": ""})).is_err());
+/// ```
+///
+/// A misspelled rule is a compile error rather than a check that silently never runs:
+///
+/// ```compile_fail
+/// use serde::Deserialize;
+/// use zod_rs::prelude::*;
+///
+/// #[derive(Deserialize, ZodSchema)]
+/// struct Tag {
+///     #[zod(min_lenght(1))]
+///     name: String,
+/// }
+/// ```
+///
+/// An object mode only applies to structs with named fields; on an enum or a tuple
+/// struct it is a compile error rather than an attribute that is silently ignored:
+///
+/// ```compile_fail
+/// use serde::Deserialize;
+/// use zod_rs::prelude::*;
+///
+/// #[derive(Deserialize, ZodSchema)]
+/// #[zod(strict)]
+/// enum Shape {
+///     Circle { radius: f64 },
+/// }
+/// ```
+///
+/// Type hints are deprecated: the schema always follows the field's type, so they
+/// are ignored with a warning.
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// # use zod_rs::prelude::*;
+/// #[derive(Deserialize, ZodSchema)]
+/// struct Tag {
+///     #[zod(string, min_length(1))]
+///     name: String,
+/// }
+///
+/// assert!(Tag::validate_and_parse(&json!({"name": ""})).is_err());
+/// ```
 #[proc_macro_derive(ZodSchema, attributes(zod))]
 pub fn derive_zod_schema(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
+    let object_mode = match parse_object_mode(&input.attrs) {
+        Ok(object_mode) => object_mode,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
+    let is_named_struct = matches!(
+        &input.data,
+        Data::Struct(data_struct) if matches!(data_struct.fields, Fields::Named(_))
+    );
+    if let (Some(mode), false) = (&object_mode, is_named_struct) {
+        let error = syn::Error::new_spanned(
+            mode,
+            format!("`{mode}` only applies to structs with named fields"),
+        );
+        return TokenStream::from(error.to_compile_error());
+    }
+    let object_mode = object_mode.map(|mode| quote! { .#mode() });
+
     match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
             Fields::Named(fields) => {
+                let rename_all = parse_serde_attributes(&input.attrs).rename_all;
                 let field_validations = fields.named.iter().map(|field| {
-                    let field_name = &field.ident;
-                    let field_name_str = field_name.as_ref().unwrap().to_string();
+                    let field_name_str = serde_field_name(field, rename_all);
                     let field_type = &field.ty;
                     let field_attrs = &field.attrs;
 
                     generate_field_validation_with_attrs(&field_name_str, field_type, field_attrs)
                 });
 
-                let expanded = quote! {
-                    impl #name {
-                        pub fn schema() -> impl zod_rs::Schema<serde_json::Value> {
-                            zod_rs::object()
-                                #(#field_validations)*
-                        }
-
-                        pub fn validate_and_parse(value: &serde_json::Value) -> Result<Self, zod_rs_util::ValidationResult> {
-                            match Self::schema().validate(value) {
-                                Ok(_) => {
-                                    serde_json::from_value(value.clone())
-                                        .map_err(|e| zod_rs_util::ValidationError::custom(format!("Deserialization failed: {}", e)).into())
-                                }
-                                Err(validation_result) => Err(validation_result)
-                            }
-                        }
-
-                        pub fn from_json(json_str: &str) -> Result<Self, zod_rs_util::ParseError> {
-                            let value: serde_json::Value = serde_json::from_str(json_str)?;
-                            Ok(Self::validate_and_parse(&value)?)
-                        }
-
-                        pub fn validate_json(json_str: &str) -> Result<serde_json::Value, zod_rs_util::ParseError> {
-                            let value: serde_json::Value = serde_json::from_str(json_str)?;
-                            Self::schema().validate(&value)?;
-                            Ok(value)
-                        }
-                    }
-                };
-
-                TokenStream::from(expanded)
+                generate_schema_impl(
+                    name,
+                    quote! {
+                        zod_rs::object()
+                            #(#field_validations)*
+                            #object_mode
+                    },
+                )
             }
-            Fields::Unnamed(_) => {
-                let error = syn::Error::new_spanned(
-                    &input,
-                    "ZodSchema can only be derived for structs with named fields, not tuple structs",
-                );
-                TokenStream::from(error.to_compile_error())
+            Fields::Unnamed(fields) => {
+                generate_schema_impl(name, generate_tuple_struct_schema(fields))
             }
             Fields::Unit => {
                 let error = syn::Error::new_spanned(
                     &input,
-                    "ZodSchema can only be derived for structs with named fields, not unit structs",
+                    "ZodSchema can only be derived for structs with fields, not unit structs",
                 );
                 TokenStream::from(error.to_compile_error())
             }
         },
-        Data::Enum(data_enum) => generate_enum_schema(name, data_enum),
+        Data::Enum(data_enum) => generate_enum_schema(name, data_enum, &input.attrs),
         Data::Union(_) => {
             let error = syn::Error::new_spanned(
                 &input,
@@ -89,180 +138,474 @@ struct ZodAttributes {
     includes: Option<String>,
     email: bool,
     url: bool,
+    uuid: bool,
+    datetime: bool,
+    e164: bool,
+    trim: bool,
+    to_lowercase: bool,
+    to_uppercase: bool,
     regex: Option<String>,
+    regex_flags: Option<String>,
+    enum_values: Option<Vec<String>>,
     positive: bool,
     negative: bool,
     nonnegative: bool,
     nonpositive: bool,
     int: bool,
+    safe_int: bool,
     finite: bool,
+    multiple_of: Option<f64>,
+    step: Option<(f64, f64)>,
+    message: Option<String>,
+    messages: HashMap<String, String>,
+    each: Option<Box<ZodAttributes>>,
+    skip: bool,
+    nullish: bool,
+    custom: Option<syn::Path>,
+    type_hints: Vec<syn::Ident>,
 }
 
-fn parse_zod_attributes(attrs: &[Attribute]) -> ZodAttributes {
+fn parse_zod_attributes(attrs: &[Attribute]) -> syn::Result<ZodAttributes> {
     let mut zod_attrs = ZodAttributes::default();
 
     for attr in attrs {
         if attr.path().is_ident("zod") {
-            if let Meta::List(meta_list) = &attr.meta {
-                let tokens: Vec<_> = meta_list.tokens.clone().into_iter().collect();
-                let mut i = 0;
-
-                while i < tokens.len() {
-                    let token_str = tokens[i].to_string();
-
-                    match token_str.as_str() {
-                        "min_length" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_number_from_parens(&value_token) {
-                                    zod_attrs.min_length = Some(value);
-                                }
-                                i += 1; // Skip the value token
-                            }
-                        }
-                        "max_length" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_number_from_parens(&value_token) {
-                                    zod_attrs.max_length = Some(value);
-                                }
-                                i += 1;
-                            }
-                        }
-                        "length" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_number_from_parens(&value_token) {
-                                    zod_attrs.length = Some(value);
-                                }
-                                i += 1;
-                            }
-                        }
-                        "min" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value_str) = extract_string_from_parens(&value_token) {
-                                    if let Ok(value) = value_str.parse::<f64>() {
-                                        zod_attrs.min = Some(value);
-                                    }
-                                }
-                                i += 1;
-                            }
-                        }
-                        "max" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value_str) = extract_string_from_parens(&value_token) {
-                                    if let Ok(value) = value_str.parse::<f64>() {
-                                        zod_attrs.max = Some(value);
-                                    }
-                                }
-                                i += 1;
-                            }
-                        }
-                        "starts_with" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_string_from_parens(&value_token) {
-                                    zod_attrs.starts_with = Some(strip_quotes(&value));
-                                }
-                                i += 1;
-                            }
-                        }
-                        "ends_with" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_string_from_parens(&value_token) {
-                                    zod_attrs.ends_with = Some(strip_quotes(&value));
-                                }
-                                i += 1;
-                            }
-                        }
-                        "includes" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_string_from_parens(&value_token) {
-                                    zod_attrs.includes = Some(strip_quotes(&value));
-                                }
-                                i += 1;
-                            }
-                        }
-                        "regex" => {
-                            if i + 1 < tokens.len() {
-                                let value_token = tokens[i + 1].to_string();
-                                if let Some(value) = extract_string_from_parens(&value_token) {
-                                    zod_attrs.regex = Some(strip_quotes(&value));
-                                }
-                                i += 1;
-                            }
-                        }
-                        "email" => {
-                            zod_attrs.email = true;
-                        }
-                        "url" => {
-                            zod_attrs.url = true;
-                        }
-                        "positive" => {
-                            zod_attrs.positive = true;
-                        }
-                        "negative" => {
-                            zod_attrs.negative = true;
-                        }
-                        "nonnegative" => {
-                            zod_attrs.nonnegative = true;
-                        }
-                        "nonpositive" => {
-                            zod_attrs.nonpositive = true;
-                        }
-                        "int" => {
-                            zod_attrs.int = true;
-                        }
-                        "finite" => {
-                            zod_attrs.finite = true;
-                        }
-                        "," => {
-                            // Skip commas
-                        }
-                        _ => {
-                            // Skip unknown tokens
-                        }
+            attr.parse_nested_meta(|meta| parse_zod_rule(&mut zod_attrs, meta))?;
+        }
+    }
+
+    Ok(zod_attrs)
+}
+
+/// Reads `#[zod(strict)]`, `#[zod(strip)]` or `#[zod(passthrough)]` on a struct
+/// and returns the mode, named after its `ObjectSchema` method. Without one, the
+/// schema follows `set_default_object_mode`.
+fn parse_object_mode(attrs: &[Attribute]) -> syn::Result<Option<syn::Ident>> {
+    let mut mode: Option<syn::Ident> = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("zod") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.get_ident().map(|ident| ident.to_string());
+            if !matches!(ident.as_deref(), Some("strict" | "strip" | "passthrough")) {
+                // Skip other rules, along with any arguments
+                if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<syn::Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::Group>()?;
+                }
+                return Ok(());
+            }
+            let ident = meta.path.get_ident().unwrap();
+            if let Some(previous) = &mode {
+                return Err(meta.error(format!(
+                    "conflicting object modes `{previous}` and `{ident}`"
+                )));
+            }
+            mode = Some(ident.clone());
+            Ok(())
+        })?;
+    }
+
+    Ok(mode)
+}
+
+fn parse_zod_rule(
+    zod_attrs: &mut ZodAttributes,
+    meta: syn::meta::ParseNestedMeta,
+) -> syn::Result<()> {
+    let rule = meta
+        .path
+        .get_ident()
+        .map(|ident| ident.to_string())
+        .unwrap_or_default();
+
+    match rule.as_str() {
+        "min_length" => {
+            let (value, message) = parse_rule_args(&meta, parse_usize)?;
+            zod_attrs.min_length = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "max_length" => {
+            let (value, message) = parse_rule_args(&meta, parse_usize)?;
+            zod_attrs.max_length = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "length" => {
+            let (value, message) = parse_rule_args(&meta, parse_usize)?;
+            zod_attrs.length = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "min" => {
+            let (value, message) = parse_rule_args(&meta, parse_f64)?;
+            zod_attrs.min = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "max" => {
+            let (value, message) = parse_rule_args(&meta, parse_f64)?;
+            zod_attrs.max = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "multiple_of" => {
+            let (value, message) = parse_rule_args(&meta, parse_f64)?;
+            zod_attrs.multiple_of = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "step" => {
+            let (value, message) = parse_rule_args(&meta, parse_f64_pair)?;
+            zod_attrs.step = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "starts_with" => {
+            let (value, message) = parse_rule_args(&meta, parse_string)?;
+            zod_attrs.starts_with = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "ends_with" => {
+            let (value, message) = parse_rule_args(&meta, parse_string)?;
+            zod_attrs.ends_with = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "includes" => {
+            let (value, message) = parse_rule_args(&meta, parse_string)?;
+            zod_attrs.includes = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "regex" => {
+            let (value, message) = parse_rule_args(&meta, parse_string)?;
+            zod_attrs.regex = Some(value);
+            zod_attrs.set_message(rule, message);
+        }
+        "regex_flags" => {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let flags: syn::LitStr = content.parse()?;
+            if let Some(flag) = flags.value().chars().find(|c| !"ims".contains(*c)) {
+                return Err(syn::Error::new(
+                    flags.span(),
+                    format!("unsupported regex flag `{flag}`, expected `i`, `m` or `s`"),
+                ));
+            }
+            zod_attrs.regex_flags = Some(flags.value());
+        }
+        "enum" => {
+            let (values, message) = parse_rule_args(&meta, parse_string_list)?;
+            zod_attrs.enum_values = Some(values);
+            zod_attrs.set_message(rule, message);
+        }
+        "message" => {
+            let message: syn::LitStr = meta.value()?.parse()?;
+            zod_attrs.message = Some(message.value());
+        }
+        "custom" => {
+            let path: syn::LitStr = meta.value()?.parse()?;
+            zod_attrs.custom = Some(path.parse()?);
+        }
+        "each" => {
+            let mut each = ZodAttributes::default();
+            meta.parse_nested_meta(|inner| parse_zod_rule(&mut each, inner))?;
+            zod_attrs.each = Some(Box::new(each));
+        }
+        "skip" => zod_attrs.skip = true,
+        "nullish" => zod_attrs.nullish = true,
+        // Only affects `ZodTs`; an `Option` field already accepts `null` here
+        "nullable" => {}
+        "trim" => zod_attrs.trim = true,
+        "to_lowercase" => zod_attrs.to_lowercase = true,
+        "to_uppercase" => zod_attrs.to_uppercase = true,
+        "email" | "url" | "uuid" | "datetime" | "e164" | "positive" | "negative"
+        | "nonnegative" | "nonpositive" | "int" | "safe_int" | "finite" => {
+            let flag = match rule.as_str() {
+                "email" => &mut zod_attrs.email,
+                "url" => &mut zod_attrs.url,
+                "uuid" => &mut zod_attrs.uuid,
+                "datetime" => &mut zod_attrs.datetime,
+                "e164" => &mut zod_attrs.e164,
+                "positive" => &mut zod_attrs.positive,
+                "negative" => &mut zod_attrs.negative,
+                "nonnegative" => &mut zod_attrs.nonnegative,
+                "nonpositive" => &mut zod_attrs.nonpositive,
+                "int" => &mut zod_attrs.int,
+                "safe_int" => &mut zod_attrs.safe_int,
+                _ => &mut zod_attrs.finite,
+            };
+            *flag = true;
+
+            if meta.input.peek(syn::token::Paren) {
+                let (_, message) = parse_rule_args(&meta, |_| Ok(()))?;
+                zod_attrs.set_message(rule, message);
+            }
+        }
+        // The schema always follows the field's type. These were accepted, and
+        // ignored, before rules were parsed strictly; they now warn instead.
+        "string" | "number" | "boolean" | "array" | "object" => {
+            if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            zod_attrs
+                .type_hints
+                .push(meta.path.get_ident().unwrap().clone());
+        }
+        _ => {
+            // A typo such as `min_lenght` would otherwise silently drop the check
+            let path = &meta.path;
+            return Err(meta.error(format!("unknown zod rule `{}`", quote!(#path))));
+        }
+    }
+
+    Ok(())
+}
+
+impl ZodAttributes {
+    fn set_message(&mut self, rule: String, message: Option<String>) {
+        if let Some(message) = message {
+            self.messages.insert(rule, message);
+        }
+    }
+}
+
+/// Parses a rule's parenthesized arguments: the value, optionally followed by
+/// `message = "..."`, e.g. `min_length(3, message = "Too short")`. Flags such as
+/// `email(message = "...")` pass a value parser that consumes nothing.
+fn parse_rule_args<T>(
+    meta: &syn::meta::ParseNestedMeta,
+    parse_value: impl FnOnce(syn::parse::ParseStream) -> syn::Result<T>,
+) -> syn::Result<(T, Option<String>)> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+
+    let value = parse_value(&content)?;
+    let mut message = None;
+
+    if !content.is_empty() {
+        // Flags have no value, so their message is not preceded by a comma
+        if !content.peek(syn::Ident) {
+            content.parse::<syn::Token![,]>()?;
+        }
+
+        let key: syn::Ident = content.parse()?;
+        if key != "message" {
+            return Err(syn::Error::new(key.span(), "expected `message`"));
+        }
+        content.parse::<syn::Token![=]>()?;
+        message = Some(content.parse::<syn::LitStr>()?.value());
+        content.parse::<Option<syn::Token![,]>>()?;
+
+        if !content.is_empty() {
+            return Err(content.error("unexpected tokens after `message`"));
+        }
+    }
+
+    Ok((value, message))
+}
+
+fn parse_usize(input: syn::parse::ParseStream) -> syn::Result<usize> {
+    input.parse::<syn::LitInt>()?.base10_parse()
+}
+
+/// Parses an integer or float literal with an optional leading `-`.
+fn parse_f64(input: syn::parse::ParseStream) -> syn::Result<f64> {
+    let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+
+    let value = if input.peek(syn::LitFloat) {
+        input.parse::<syn::LitFloat>()?.base10_parse::<f64>()?
+    } else {
+        input.parse::<syn::LitInt>()?.base10_parse::<f64>()?
+    };
+
+    Ok(if negative { -value } else { value })
+}
+
+/// Parses two comma-separated numbers, e.g. the `start, step` of `step(9, 15)`.
+fn parse_f64_pair(input: syn::parse::ParseStream) -> syn::Result<(f64, f64)> {
+    let first = parse_f64(input)?;
+    input.parse::<syn::Token![,]>()?;
+    Ok((first, parse_f64(input)?))
+}
+
+fn parse_string(input: syn::parse::ParseStream) -> syn::Result<String> {
+    Ok(input.parse::<syn::LitStr>()?.value())
+}
+
+/// Parses one or more comma-separated string literals, leaving a trailing
+/// `, message = "..."` to the caller.
+fn parse_string_list(input: syn::parse::ParseStream) -> syn::Result<Vec<String>> {
+    let mut values = vec![parse_string(input)?];
+
+    while input.peek(syn::Token![,]) && input.peek2(syn::LitStr) {
+        input.parse::<syn::Token![,]>()?;
+        values.push(parse_string(input)?);
+    }
+
+    Ok(values)
+}
+
+/// Serde's `rename_all` conventions.
+#[derive(Debug, Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// Renames a `snake_case` field name.
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
                     }
+                }
 
-                    i += 1;
+                if matches!(self, Self::Camel) {
+                    lowercase_first(&pascal)
+                } else {
+                    pascal
                 }
             }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
         }
     }
 
-    zod_attrs
+    /// Renames a `PascalCase` variant name.
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_string(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => lowercase_first(variant),
+            Self::Snake | Self::ScreamingSnake | Self::Kebab | Self::ScreamingKebab => {
+                let mut snake = String::new();
+                for (i, c) in variant.char_indices() {
+                    if c.is_uppercase() && i > 0 {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                match self {
+                    Self::Snake => snake,
+                    other => other.apply_to_field(&snake),
+                }
+            }
+        }
+    }
 }
 
-fn extract_number_from_parens(token: &str) -> Option<usize> {
-    token
-        .strip_prefix('(')
-        .and_then(|s| s.strip_suffix(')'))
-        .and_then(|inner| inner.parse::<usize>().ok())
+fn lowercase_first(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
 
-fn extract_string_from_parens(token: &str) -> Option<String> {
-    token
-        .strip_prefix('(')
-        .and_then(|s| s.strip_suffix(')'))
-        .map(|s| s.to_string())
+#[derive(Default)]
+struct SerdeAttributes {
+    rename: Option<String>,
+    rename_all: Option<RenameRule>,
+    rename_all_fields: Option<RenameRule>,
 }
 
-/// Safely removes surrounding quotes from a string value
-fn strip_quotes(value: &str) -> String {
-    // Try to strip regular quotes first
-    if let Some(inner) = value.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
-        return inner.to_string();
+/// Reads the renaming options from `#[serde(...)]`. Since schemas validate
+/// incoming JSON, the `deserialize` name wins when serialize and deserialize differ.
+fn parse_serde_attributes(attrs: &[Attribute]) -> SerdeAttributes {
+    let mut serde_attrs = SerdeAttributes::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        // Unsupported or malformed options are serde's to report
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                if let Some(name) = parse_serde_name(&meta)? {
+                    serde_attrs.rename = Some(name);
+                }
+            } else if meta.path.is_ident("rename_all") {
+                if let Some(rule) = parse_serde_name(&meta)? {
+                    serde_attrs.rename_all = RenameRule::from_str(&rule);
+                }
+            } else if meta.path.is_ident("rename_all_fields") {
+                if let Some(rule) = parse_serde_name(&meta)? {
+                    serde_attrs.rename_all_fields = RenameRule::from_str(&rule);
+                }
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        });
     }
-    // Try to strip raw string literal (r"...")
-    if let Some(inner) = value.strip_prefix("r\"").and_then(|s| s.strip_suffix('"')) {
-        return inner.to_string();
+
+    serde_attrs
+}
+
+/// Parses `= "name"` or `(serialize = "a", deserialize = "b")`.
+fn parse_serde_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(syn::Token![=]) {
+        let name: syn::LitStr = meta.value()?.parse()?;
+        return Ok(Some(name.value()));
+    }
+
+    let mut deserialize = None;
+    meta.parse_nested_meta(|inner| {
+        let name: syn::LitStr = inner.value()?.parse()?;
+        if inner.path.is_ident("deserialize") {
+            deserialize = Some(name.value());
+        }
+        Ok(())
+    })?;
+
+    Ok(deserialize)
+}
+
+/// The JSON key serde uses for `field`.
+fn serde_field_name(field: &syn::Field, rename_all: Option<RenameRule>) -> String {
+    if let Some(name) = parse_serde_attributes(&field.attrs).rename {
+        return name;
+    }
+
+    let name = field.ident.as_ref().unwrap().unraw().to_string();
+    match rename_all {
+        Some(rule) => rule.apply_to_field(&name),
+        None => name,
     }
-    // Return as-is if no quotes
-    value.to_string()
 }
 
 fn generate_field_validation_with_attrs(
@@ -270,22 +613,93 @@ fn generate_field_validation_with_attrs(
     field_type: &syn::Type,
     attrs: &[Attribute],
 ) -> proc_macro2::TokenStream {
-    let zod_attrs = parse_zod_attributes(attrs);
+    let zod_attrs = match parse_zod_attributes(attrs) {
+        Ok(zod_attrs) => zod_attrs,
+        Err(error) => {
+            let error = error.to_compile_error();
+            return quote! { .field(#field_name, #error) };
+        }
+    };
+
+    if zod_attrs.skip {
+        return quote! {};
+    }
+
     let is_optional = is_option_type(field_type);
 
+    if zod_attrs.nullish && !is_optional {
+        let error = syn::Error::new_spanned(field_type, "`nullish` requires an `Option` field")
+            .to_compile_error();
+        return quote! { .field(#field_name, #error) };
+    }
+
     if is_optional {
         let inner_type = get_option_inner_type(field_type);
         let base_validation = generate_base_validation_with_attrs(&inner_type, &zod_attrs);
-        quote! { .optional_field(#field_name, #base_validation) }
+        if zod_attrs.nullish {
+            quote! { .nullish_field(#field_name, #base_validation) }
+        } else {
+            quote! { .optional_field(#field_name, #base_validation) }
+        }
     } else {
         let base_validation = generate_base_validation_with_attrs(field_type, &zod_attrs);
         quote! { .field(#field_name, #base_validation) }
     }
 }
 
+/// The schema for `field_type` with its rules, followed by the `custom` check if
+/// there is one. The check receives the value as `&field_type`, e.g. the inner
+/// type of an `Option` field or the element type inside `each(...)`. It is called
+/// through a closure so that deref coercion applies, and a `fn(&str)` works for a
+/// `String` field.
 fn generate_base_validation_with_attrs(
     field_type: &syn::Type,
     zod_attrs: &ZodAttributes,
+) -> proc_macro2::TokenStream {
+    let validation = generate_rule_validation(field_type, zod_attrs);
+
+    let validation = match &zod_attrs.custom {
+        Some(check) => quote! {
+            zod_rs::__private::custom::<#field_type, _, _, _>(
+                #validation,
+                |value: &#field_type| #check(value),
+            )
+        },
+        None => validation,
+    };
+
+    if zod_attrs.type_hints.is_empty() {
+        return validation;
+    }
+
+    let warnings = zod_attrs.type_hints.iter().map(type_hint_warning);
+    quote! {{
+        #(#warnings)*
+        #validation
+    }}
+}
+
+/// A deprecation warning at a type hint such as `#[zod(string)]`, raised by
+/// using a deprecated item, since stable proc macros cannot emit warnings.
+fn type_hint_warning(hint: &syn::Ident) -> proc_macro2::TokenStream {
+    let note = format!(
+        "`{hint}` has no effect, the schema follows the field's type; \
+         remove it, as it will be rejected like other unknown rules"
+    );
+
+    quote_spanned! {hint.span()=>
+        {
+            #[deprecated(note = #note)]
+            #[allow(non_camel_case_types)]
+            struct zod_type_hint;
+            let _ = zod_type_hint;
+        }
+    }
+}
+
+fn generate_rule_validation(
+    field_type: &syn::Type,
+    zod_attrs: &ZodAttributes,
 ) -> proc_macro2::TokenStream {
     if let syn::Type::Path(type_path) = field_type {
         if let Some(segment) = type_path.path.segments.last() {
@@ -293,34 +707,94 @@ fn generate_base_validation_with_attrs(
 
             match type_name.as_str() {
                 "String" => {
+                    // A fixed set of values makes the other string rules redundant
+                    if let Some(values) = &zod_attrs.enum_values {
+                        let mut validation = quote! { zod_rs::enum_values(&[#(#values),*]) };
+
+                        if let Some(message) = zod_attrs
+                            .messages
+                            .get("enum")
+                            .or(zod_attrs.message.as_ref())
+                        {
+                            validation = quote! { #validation.message(#message) };
+                        }
+
+                        return validation;
+                    }
+
                     let mut validation = quote! { zod_rs::string() };
 
                     if let Some(min) = zod_attrs.min_length {
                         validation = quote! { #validation.min(#min) };
+                        validation = with_message(validation, zod_attrs, "min_length");
                     }
                     if let Some(max) = zod_attrs.max_length {
                         validation = quote! { #validation.max(#max) };
+                        validation = with_message(validation, zod_attrs, "max_length");
                     }
                     if let Some(length) = zod_attrs.length {
                         validation = quote! { #validation.length(#length) };
+                        validation = with_message(validation, zod_attrs, "length");
                     }
                     if zod_attrs.email {
                         validation = quote! { #validation.email() };
+                        validation = with_message(validation, zod_attrs, "email");
                     }
                     if zod_attrs.url {
                         validation = quote! { #validation.url() };
+                        validation = with_message(validation, zod_attrs, "url");
+                    }
+                    if zod_attrs.uuid {
+                        validation = quote! { #validation.uuid() };
+                        validation = with_message(validation, zod_attrs, "uuid");
+                    }
+                    if zod_attrs.datetime {
+                        validation = quote! { #validation.datetime() };
+                        validation = with_message(validation, zod_attrs, "datetime");
+                    }
+                    if zod_attrs.e164 {
+                        validation = quote! { #validation.e164() };
+                        validation = with_message(validation, zod_attrs, "e164");
+                    }
+                    if zod_attrs.trim {
+                        validation = quote! { #validation.trim() };
+                    }
+                    if zod_attrs.to_lowercase {
+                        validation = quote! { #validation.to_lowercase() };
+                    }
+                    if zod_attrs.to_uppercase {
+                        validation = quote! { #validation.to_uppercase() };
                     }
                     if let Some(regex) = &zod_attrs.regex {
-                        validation = quote! { #validation.regex(#regex) };
+                        let flags = zod_attrs.regex_flags.as_deref().unwrap_or_default();
+                        let flags = [
+                            ('i', quote! { .case_insensitive() }),
+                            ('m', quote! { .multi_line() }),
+                            ('s', quote! { .dot_all() }),
+                        ]
+                        .into_iter()
+                        .filter(|(flag, _)| flags.contains(*flag))
+                        .map(|(_, method)| method);
+                        validation = quote! {
+                            #validation.regex_with_flags(#regex, zod_rs::RegexFlags::new() #(#flags)*)
+                        };
+                        validation = with_message(validation, zod_attrs, "regex");
                     }
                     if let Some(starts_with) = &zod_attrs.starts_with {
                         validation = quote! { #validation.starts_with(#starts_with) };
+                        validation = with_message(validation, zod_attrs, "starts_with");
                     }
                     if let Some(ends_with) = &zod_attrs.ends_with {
                         validation = quote! { #validation.ends_with(#ends_with) };
+                        validation = with_message(validation, zod_attrs, "ends_with");
                     }
                     if let Some(includes) = &zod_attrs.includes {
                         validation = quote! { #validation.includes(#includes) };
+                        validation = with_message(validation, zod_attrs, "includes");
+                    }
+
+                    if let Some(message) = &zod_attrs.message {
+                        validation = quote! { #validation.message(#message) };
                     }
 
                     validation
@@ -344,48 +818,100 @@ fn generate_base_validation_with_attrs(
                         )
                     {
                         validation = quote! { #validation.int() };
+                        validation = with_message(validation, zod_attrs, "int");
                     }
-                    if let Some(min) = zod_attrs.min {
-                        validation = quote! { #validation.min(#min) };
+                    // The type's range only applies where it is tighter than the user's bound
+                    let bounds = integer_bounds(&type_name);
+                    match (zod_attrs.min, bounds) {
+                        (Some(min), Some((type_min, _))) if min < type_min => {
+                            validation = quote! { #validation.min(#type_min) };
+                        }
+                        (Some(min), _) => {
+                            validation = quote! { #validation.min(#min) };
+                            validation = with_message(validation, zod_attrs, "min");
+                        }
+                        (None, Some((type_min, _))) => {
+                            validation = quote! { #validation.min(#type_min) };
+                        }
+                        (None, None) => {}
                     }
-                    if let Some(max) = zod_attrs.max {
-                        validation = quote! { #validation.max(#max) };
+                    match (zod_attrs.max, bounds) {
+                        (Some(max), Some((_, type_max))) if max > type_max => {
+                            validation = quote! { #validation.max(#type_max) };
+                        }
+                        (Some(max), _) => {
+                            validation = quote! { #validation.max(#max) };
+                            validation = with_message(validation, zod_attrs, "max");
+                        }
+                        (None, Some((_, type_max))) => {
+                            validation = quote! { #validation.max(#type_max) };
+                        }
+                        (None, None) => {}
                     }
                     if zod_attrs.positive {
                         validation = quote! { #validation.positive() };
+                        validation = with_message(validation, zod_attrs, "positive");
                     }
                     if zod_attrs.negative {
                         validation = quote! { #validation.negative() };
+                        validation = with_message(validation, zod_attrs, "negative");
                     }
                     if zod_attrs.nonnegative {
                         validation = quote! { #validation.nonnegative() };
+                        validation = with_message(validation, zod_attrs, "nonnegative");
                     }
                     if zod_attrs.nonpositive {
                         validation = quote! { #validation.nonpositive() };
+                        validation = with_message(validation, zod_attrs, "nonpositive");
+                    }
+                    if zod_attrs.safe_int {
+                        validation = quote! { #validation.safe_int() };
+                        validation = with_message(validation, zod_attrs, "safe_int");
                     }
                     if zod_attrs.finite {
                         validation = quote! { #validation.finite() };
+                        validation = with_message(validation, zod_attrs, "finite");
+                    }
+                    if let Some(multiple_of) = zod_attrs.multiple_of {
+                        validation = quote! { #validation.multiple_of(#multiple_of) };
+                        validation = with_message(validation, zod_attrs, "multiple_of");
+                    }
+                    if let Some((start, step)) = zod_attrs.step {
+                        validation = quote! { #validation.step(#start, #step) };
+                        validation = with_message(validation, zod_attrs, "step");
+                    }
+
+                    if let Some(message) = &zod_attrs.message {
+                        validation = quote! { #validation.message(#message) };
                     }
 
                     validation
                 }
-                "bool" => {
-                    quote! { zod_rs::boolean() }
-                }
+                "bool" => without_message(field_type, zod_attrs, quote! { zod_rs::boolean() }),
                 "Vec" => {
                     if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
                         if let Some(syn::GenericArgument::Type(inner_type)) = args.args.first() {
-                            let inner_validation = generate_element_validation(inner_type);
+                            let inner_validation = match &zod_attrs.each {
+                                Some(each) => generate_base_validation_with_attrs(inner_type, each),
+                                None => generate_element_validation(inner_type),
+                            };
                             let mut validation = quote! { zod_rs::array(#inner_validation) };
 
                             if let Some(min) = zod_attrs.min_length {
                                 validation = quote! { #validation.min(#min) };
+                                validation = with_message(validation, zod_attrs, "min_length");
                             }
                             if let Some(max) = zod_attrs.max_length {
                                 validation = quote! { #validation.max(#max) };
+                                validation = with_message(validation, zod_attrs, "max_length");
                             }
                             if let Some(length) = zod_attrs.length {
                                 validation = quote! { #validation.length(#length) };
+                                validation = with_message(validation, zod_attrs, "length");
+                            }
+
+                            if let Some(message) = &zod_attrs.message {
+                                validation = quote! { #validation.message(#message) };
                             }
 
                             validation
@@ -396,10 +922,26 @@ fn generate_base_validation_with_attrs(
                         quote! { zod_rs::array(zod_rs::string()) }
                     }
                 }
-                _ => {
-                    let type_ident = &segment.ident;
-                    quote! { #type_ident::schema() }
-                }
+                "HashMap" | "BTreeMap" => without_message(
+                    field_type,
+                    zod_attrs,
+                    generate_map_validation(segment, zod_attrs.each.as_deref()),
+                ),
+                "Box" | "Rc" | "Arc" => match pointee_type(segment) {
+                    Some(inner_type) => generate_rule_validation(inner_type, zod_attrs),
+                    None => quote! { zod_rs::string() },
+                },
+                _ => match chrono_validation(&type_name) {
+                    Some(validation) => match &zod_attrs.message {
+                        Some(message) => quote! { #validation.message(#message) },
+                        None => validation,
+                    },
+                    None => without_message(
+                        field_type,
+                        zod_attrs,
+                        generate_nested_schema(&segment.ident),
+                    ),
+                },
             }
         } else {
             quote! { zod_rs::string() }
@@ -409,6 +951,51 @@ fn generate_base_validation_with_attrs(
     }
 }
 
+fn with_message(
+    validation: proc_macro2::TokenStream,
+    zod_attrs: &ZodAttributes,
+    rule: &str,
+) -> proc_macro2::TokenStream {
+    match zod_attrs.messages.get(rule) {
+        Some(message) => quote! { #validation.with_message(#message) },
+        None => validation,
+    }
+}
+
+/// Returns `validation`, or a compile error if a message was given for a field
+/// whose schema has no constraints to attach it to, such as a `bool` or a
+/// nested struct.
+fn without_message(
+    field_type: &syn::Type,
+    zod_attrs: &ZodAttributes,
+    validation: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if zod_attrs.message.is_none() && zod_attrs.messages.is_empty() {
+        return validation;
+    }
+
+    syn::Error::new_spanned(field_type, "`message` is not supported on this field type")
+        .to_compile_error()
+}
+
+/// The range of an integer type, so that values which would overflow during
+/// deserialization fail validation instead. 64-bit bounds are rounded to the
+/// nearest `f64`, and `isize`/`usize` are assumed to be 64 bits wide.
+fn integer_bounds(type_name: &str) -> Option<(f64, f64)> {
+    let bounds = match type_name {
+        "i8" => (i8::MIN as f64, i8::MAX as f64),
+        "i16" => (i16::MIN as f64, i16::MAX as f64),
+        "i32" => (i32::MIN as f64, i32::MAX as f64),
+        "i64" | "isize" => (i64::MIN as f64, i64::MAX as f64),
+        "u8" => (0.0, u8::MAX as f64),
+        "u16" => (0.0, u16::MAX as f64),
+        "u32" => (0.0, u32::MAX as f64),
+        "u64" | "usize" => (0.0, u64::MAX as f64),
+        _ => return None,
+    };
+    Some(bounds)
+}
+
 fn generate_element_validation(field_type: &syn::Type) -> proc_macro2::TokenStream {
     if let syn::Type::Path(type_path) = field_type {
         if let Some(segment) = type_path.path.segments.last() {
@@ -417,14 +1004,18 @@ fn generate_element_validation(field_type: &syn::Type) -> proc_macro2::TokenStre
             match type_name.as_str() {
                 "String" => quote! { zod_rs::string() },
                 "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize" => {
-                    quote! { zod_rs::number().int() }
+                    let (min, max) = integer_bounds(&type_name).unwrap();
+                    quote! { zod_rs::number().int().min(#min).max(#max) }
                 }
                 "f32" | "f64" => quote! { zod_rs::number() },
                 "bool" => quote! { zod_rs::boolean() },
-                _ => {
-                    let type_ident = &segment.ident;
-                    quote! { #type_ident::schema() }
-                }
+                "HashMap" | "BTreeMap" => generate_map_validation(segment, None),
+                "Box" | "Rc" | "Arc" => match pointee_type(segment) {
+                    Some(inner_type) => generate_element_validation(inner_type),
+                    None => quote! { zod_rs::string() },
+                },
+                _ => chrono_validation(&type_name)
+                    .unwrap_or_else(|| generate_nested_schema(&segment.ident)),
             }
         } else {
             quote! { zod_rs::string() }
@@ -434,6 +1025,65 @@ fn generate_element_validation(field_type: &syn::Type) -> proc_macro2::TokenStre
     }
 }
 
+/// Maps validate as a record. JSON object keys are always strings, whatever the
+/// Rust key type, so only the values are checked, with `each(...)` if given.
+fn generate_map_validation(
+    segment: &syn::PathSegment,
+    each: Option<&ZodAttributes>,
+) -> proc_macro2::TokenStream {
+    let value_type = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .nth(1),
+        _ => None,
+    };
+
+    let value_validation = match (value_type, each) {
+        (Some(value_type), Some(each)) => generate_base_validation_with_attrs(value_type, each),
+        (Some(value_type), None) => generate_element_validation(value_type),
+        (None, _) => quote! { zod_rs::string() },
+    };
+
+    quote! { zod_rs::record(zod_rs::string(), #value_validation) }
+}
+
+/// With the `chrono` feature, chrono's date and time types validate as the ISO 8601
+/// strings serde writes for them.
+fn chrono_validation(type_name: &str) -> Option<proc_macro2::TokenStream> {
+    if !cfg!(feature = "chrono") {
+        return None;
+    }
+
+    let format = match type_name {
+        "DateTime" | "NaiveDateTime" => quote! { datetime },
+        "NaiveDate" => quote! { date },
+        "NaiveTime" => quote! { time },
+        _ => return None,
+    };
+    Some(quote! { zod_rs::string().#format() })
+}
+
+/// Nested derived types are referenced through `lazy`, so that recursive and
+/// mutually recursive types don't build their schemas forever.
+fn generate_nested_schema(type_ident: &syn::Ident) -> proc_macro2::TokenStream {
+    quote! { zod_rs::lazy(#type_ident::schema) }
+}
+
+/// The `T` of `Box<T>`, `Rc<T>` or `Arc<T>`, which serde represents as `T`.
+fn pointee_type(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+        if let Some(syn::GenericArgument::Type(inner_type)) = args.args.first() {
+            return Some(inner_type);
+        }
+    }
+    None
+}
+
 fn is_option_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty {
         if let Some(segment) = type_path.path.segments.last() {
@@ -458,56 +1108,130 @@ fn get_option_inner_type(ty: &syn::Type) -> syn::Type {
     syn::parse_quote! { String }
 }
 
-fn generate_enum_schema(name: &syn::Ident, data_enum: &syn::DataEnum) -> TokenStream {
+fn generate_enum_schema(
+    name: &syn::Ident,
+    data_enum: &syn::DataEnum,
+    attrs: &[Attribute],
+) -> TokenStream {
+    let container = parse_serde_attributes(attrs);
+
     let variant_schemas = data_enum.variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
-        let variant_name_str = variant_name.to_string();
+        let serde = parse_serde_attributes(&variant.attrs);
+        let variant_name_str = serde.rename.unwrap_or_else(|| {
+            let name = variant.ident.unraw().to_string();
+            match container.rename_all {
+                Some(rule) => rule.apply_to_variant(&name),
+                None => name,
+            }
+        });
+        let field_rule = serde.rename_all.or(container.rename_all_fields);
 
-        generate_variant_schema(&variant_name_str, &variant.fields)
+        generate_variant_schema(&variant_name_str, &variant.fields, field_rule)
     });
 
+    generate_schema_impl(
+        name,
+        quote! {
+            zod_rs::enum_schema()
+                #(#variant_schemas)*
+        },
+    )
+}
+
+/// Emits the inherent `schema()`/`validate_and_parse()`/... methods around the
+/// given schema expression. The schema is built on first use and shared by every
+/// later call.
+fn generate_schema_impl(name: &syn::Ident, schema: proc_macro2::TokenStream) -> TokenStream {
     let expanded = quote! {
         impl #name {
-            pub fn schema() -> impl zod_rs::Schema<serde_json::Value> {
-                zod_rs::union()
-                    #(#variant_schemas)*
+            pub fn schema() -> impl zod_rs::Schema<serde_json::Value> + Send + Sync {
+                static SCHEMA: std::sync::LazyLock<
+                    Box<dyn zod_rs::Schema<serde_json::Value> + Send + Sync>,
+                > = std::sync::LazyLock::new(|| Box::new(#schema));
+                &**SCHEMA
             }
 
             pub fn validate_and_parse(value: &serde_json::Value) -> Result<Self, zod_rs_util::ValidationResult> {
-                match Self::schema().validate(value) {
-                    Ok(_) => {
-                        serde_json::from_value(value.clone())
-                            .map_err(|e| zod_rs_util::ValidationError::custom(format!("Deserialization failed: {}", e)).into())
-                    }
-                    Err(validation_result) => Err(validation_result)
-                }
+                <Self as zod_rs::HasSchema>::validate_and_parse(value)
+            }
+
+            pub fn validate_and_parse_owned(value: serde_json::Value) -> Result<Self, zod_rs_util::ValidationResult> {
+                <Self as zod_rs::HasSchema>::validate_and_parse_owned(value)
             }
 
             pub fn from_json(json_str: &str) -> Result<Self, zod_rs_util::ParseError> {
-                let value: serde_json::Value = serde_json::from_str(json_str)?;
-                Ok(Self::validate_and_parse(&value)?)
+                Self::validate_str(json_str)
+            }
+
+            pub fn validate_str(json_str: &str) -> Result<Self, zod_rs_util::ParseError> {
+                <Self as zod_rs::HasSchema>::validate_str(json_str)
+            }
+
+            pub fn validate_slice(json: &[u8]) -> Result<Self, zod_rs_util::ParseError> {
+                <Self as zod_rs::HasSchema>::validate_slice(json)
             }
 
             pub fn validate_json(json_str: &str) -> Result<serde_json::Value, zod_rs_util::ParseError> {
                 let value: serde_json::Value = serde_json::from_str(json_str)?;
-                Self::schema().validate(&value)?;
+                zod_rs::__private::validate_top_level::<Self>(&value)?;
                 Ok(value)
             }
         }
+
+        impl zod_rs::HasSchema for #name {
+            fn schema() -> impl zod_rs::Schema<serde_json::Value> + Send + Sync {
+                #name::schema()
+            }
+
+            fn schema_name() -> &'static str {
+                stringify!(#name)
+            }
+        }
     };
 
     TokenStream::from(expanded)
 }
 
-fn generate_variant_schema(variant_name: &str, fields: &Fields) -> proc_macro2::TokenStream {
+/// Tuple structs validate as a JSON array of their fields. Newtype structs
+/// unwrap to the inner field's schema, matching serde's representation.
+fn generate_tuple_struct_schema(fields: &syn::FieldsUnnamed) -> proc_macro2::TokenStream {
+    let element_validations = fields.unnamed.iter().map(generate_positional_validation);
+
+    if fields.unnamed.len() == 1 {
+        quote! { zod_rs::__private::json_output(#(#element_validations)*) }
+    } else {
+        quote! {
+            zod_rs::tuple()
+                #(.element(#element_validations))*
+        }
+    }
+}
+
+fn generate_positional_validation(field: &syn::Field) -> proc_macro2::TokenStream {
+    let zod_attrs = match parse_zod_attributes(&field.attrs) {
+        Ok(zod_attrs) => zod_attrs,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    if is_option_type(&field.ty) {
+        let inner_type = get_option_inner_type(&field.ty);
+        let base_validation = generate_base_validation_with_attrs(&inner_type, &zod_attrs);
+        quote! { zod_rs::optional(#base_validation) }
+    } else {
+        generate_base_validation_with_attrs(&field.ty, &zod_attrs)
+    }
+}
+
+fn generate_variant_schema(
+    variant_name: &str,
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+) -> proc_macro2::TokenStream {
     match fields {
         // Unit variant: {"VariantName": null}
         Fields::Unit => {
             quote! {
-                .variant(
-                    zod_rs::object()
-                        .field(#variant_name, zod_rs::null())
-                )
+                .variant(#variant_name, zod_rs::null())
             }
         }
 
@@ -518,7 +1242,7 @@ fn generate_variant_schema(variant_name: &str, fields: &Fields) -> proc_macro2::
 
         // Struct variant (named fields): {"VariantName": {"field1": ..., "field2": ...}}
         Fields::Named(fields_named) => {
-            generate_struct_variant_schema(variant_name, fields_named)
+            generate_struct_variant_schema(variant_name, fields_named, rename_all)
         }
     }
 }
@@ -531,32 +1255,18 @@ fn generate_tuple_variant_schema(
 
     if field_count == 1 {
         // Single element: {"VariantName": value}
-        let field = fields.unnamed.first().unwrap();
-        let field_type = &field.ty;
-        let field_attrs = &field.attrs;
-        let inner_validation =
-            generate_base_validation_with_attrs(field_type, &parse_zod_attributes(field_attrs));
+        let inner_validation = generate_positional_validation(fields.unnamed.first().unwrap());
 
         quote! {
-            .variant(
-                zod_rs::object()
-                    .field(#variant_name, #inner_validation)
-            )
+            .variant(#variant_name, #inner_validation)
         }
     } else {
         // Multiple elements: {"VariantName": [value1, value2, ...]}
-        let element_validations = fields.unnamed.iter().map(|field| {
-            let field_type = &field.ty;
-            let field_attrs = &field.attrs;
-            generate_base_validation_with_attrs(field_type, &parse_zod_attributes(field_attrs))
-        });
+        let element_validations = fields.unnamed.iter().map(generate_positional_validation);
 
         quote! {
-            .variant(
-                zod_rs::object()
-                    .field(#variant_name, zod_rs::tuple()
-                        #(.element(#element_validations))*
-                    )
+            .variant(#variant_name, zod_rs::tuple()
+                #(.element(#element_validations))*
             )
         }
     }
@@ -565,10 +1275,10 @@ fn generate_tuple_variant_schema(
 fn generate_struct_variant_schema(
     variant_name: &str,
     fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
 ) -> proc_macro2::TokenStream {
     let field_validations = fields.named.iter().map(|field| {
-        let field_name = &field.ident;
-        let field_name_str = field_name.as_ref().unwrap().to_string();
+        let field_name_str = serde_field_name(field, rename_all);
         let field_type = &field.ty;
         let field_attrs = &field.attrs;
 
@@ -576,11 +1286,8 @@ fn generate_struct_variant_schema(
     });
 
     quote! {
-        .variant(
-            zod_rs::object()
-                .field(#variant_name, zod_rs::object()
-                    #(#field_validations)*
-                )
+        .variant(#variant_name, zod_rs::object()
+            #(#field_validations)*
         )
     }
 }
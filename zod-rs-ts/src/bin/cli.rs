@@ -1,5 +1,10 @@
+#[path = "../codegen.rs"]
+mod codegen;
+#[path = "../extract.rs"]
+mod extract;
+
 use clap::{Parser, Subcommand};
-use regex::Regex;
+use extract::extract_schemas;
 use std::fs;
 use std::path::PathBuf;
 use walkdir::WalkDir;
@@ -58,10 +63,11 @@ fn generate_schemas(
     for entry in WalkDir::new(input)
         .into_iter()
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().map_or(false, |ext| ext == "rs"))
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "rs"))
     {
         let content = fs::read_to_string(entry.path())?;
-        let schemas = extract_schemas(&content);
+        let schemas =
+            extract_schemas(&content).map_err(|e| format!("{}: {}", entry.path().display(), e))?;
 
         if !schemas.is_empty() {
             if single_file {
@@ -69,7 +75,7 @@ fn generate_schemas(
             } else {
                 // Write individual files
                 for (name, schema) in schemas {
-                    let file_name = format!("{}.ts", to_snake_case(&name));
+                    let file_name = format!("{}.ts", codegen::to_snake_case(&name));
                     let file_path = output.join(&file_name);
                     fs::create_dir_all(output)?;
                     fs::write(&file_path, schema)?;
@@ -107,294 +113,367 @@ fn generate_schemas(
     Ok(())
 }
 
-fn extract_schemas(content: &str) -> Vec<(String, String)> {
-    let mut schemas = Vec::new();
-
-    // Regex to find structs/enums with ZodTs derive
-    let derive_re = Regex::new(r#"#\[derive\([^)]*ZodTs[^)]*\)\]"#).unwrap();
-    let struct_re = Regex::new(r#"struct\s+(\w+)"#).unwrap();
-    let enum_re = Regex::new(r#"enum\s+(\w+)"#).unwrap();
-
-    let lines: Vec<&str> = content.lines().collect();
-
-    for (i, line) in lines.iter().enumerate() {
-        if derive_re.is_match(line) {
-            // Look ahead for struct or enum definition
-            for j in i + 1..std::cmp::min(i + 5, lines.len()) {
-                if let Some(caps) = struct_re.captures(lines[j]) {
-                    let name = caps.get(1).unwrap().as_str().to_string();
-                    if let Some(schema) = generate_struct_schema(&lines, j, &name) {
-                        schemas.push((name, schema));
-                    }
-                    break;
-                } else if let Some(caps) = enum_re.captures(lines[j]) {
-                    let name = caps.get(1).unwrap().as_str().to_string();
-                    if let Some(schema) = generate_enum_schema(&lines, j, &name) {
-                        schemas.push((name, schema));
-                    }
-                    break;
-                }
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_field_in_struct() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Config {
+    limits: HashMap<String, i32>,
+    labels: Option<BTreeMap<String, String>>,
+    groups: BTreeMap<String, Vec<String>>,
+    flags: std::collections::HashMap<String, bool>,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains("limits: z.record(z.string(), z.number().int())"));
+        assert!(schema.contains("labels: z.record(z.string(), z.string()).nullable()"));
+        assert!(schema.contains("groups: z.record(z.string(), z.array(z.string()))"));
+        assert!(schema.contains("flags: z.record(z.string(), z.boolean())"));
     }
 
-    schemas
+    #[test]
+    fn test_nested_generics() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Report {
+    scores: HashMap<String, Vec<Option<i32>>>,
+    owners: BTreeMap<String, Vec<crate::models::User>>,
+    matrix: Vec<Vec<f64>>,
+    tags: Option<Vec<Option<String>>>,
+    totals: std::collections::BTreeMap<u32, HashMap<String, u64>>,
 }
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
 
-fn generate_struct_schema(lines: &[&str], start: usize, name: &str) -> Option<String> {
-    let mut fields = Vec::new();
-    let mut in_struct = false;
-    let mut brace_count = 0;
-    let mut current_attrs: Vec<String> = Vec::new();
-
-    for line in lines.iter().skip(start) {
-        let trimmed = line.trim();
+        assert!(
+            schema.contains("scores: z.record(z.string(), z.array(z.number().int().nullable()))")
+        );
+        assert!(schema.contains("owners: z.record(z.string(), z.array(UserSchema))"));
+        assert!(schema.contains("matrix: z.array(z.array(z.number()))"));
+        assert!(schema.contains("tags: z.array(z.string().nullable()).nullable()"));
+        assert!(
+            schema.contains("totals: z.record(z.string(), z.record(z.string(), z.number().int()))")
+        );
+    }
 
-        if trimmed.contains('{') {
-            in_struct = true;
-            brace_count += trimmed.matches('{').count();
-            brace_count -= trimmed.matches('}').count();
-            continue;
-        }
+    #[test]
+    fn test_smart_pointers_are_transparent() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Folder {
+    #[zod(min_length(1))]
+    name: Box<String>,
+    settings: std::sync::Arc<Settings>,
+    owner: Option<Box<User>>,
+    #[zod(each(positive))]
+    sizes: Rc<Vec<u32>>,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains("name: z.string().min(1)"));
+        assert!(schema.contains("settings: SettingsSchema"));
+        assert!(schema.contains("owner: UserSchema.nullable()"));
+        assert!(schema.contains("sizes: z.array(z.number().int().positive())"));
+    }
 
-        if in_struct {
-            brace_count += trimmed.matches('{').count();
-            brace_count -= trimmed.matches('}').count();
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_types() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Booking {
+    created_at: DateTime<Utc>,
+    updated_at: chrono::DateTime<FixedOffset>,
+    day: NaiveDate,
+    starts: Option<NaiveTime>,
+    reminders: Vec<NaiveDateTime>,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains("created_at: z.string().datetime()"));
+        assert!(schema.contains("updated_at: z.string().datetime({ offset: true })"));
+        assert!(schema.contains("day: z.string().date()"));
+        assert!(schema.contains("starts: z.string().time().nullable()"));
+        assert!(schema.contains("reminders: z.array(z.string().datetime({ local: true }))"));
+    }
 
-            if brace_count == 0 {
-                break;
-            }
+    #[test]
+    fn test_option_nullable_vs_optional() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Profile {
+    bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[zod(nullable)]
+    avatar: Option<String>,
+    #[zod(nullable)]
+    score: f64,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains("bio: z.string().nullable()"));
+        assert!(schema.contains("nickname: z.string().optional()"));
+        assert!(schema.contains("avatar: z.string().nullable().optional()"));
+        assert!(schema.contains("score: z.number().nullable()"));
+    }
 
-            if trimmed.starts_with("#[zod(") {
-                current_attrs.push(trimmed.to_string());
-            } else if trimmed.contains(':') && !trimmed.starts_with("//") {
-                // Parse field
-                let parts: Vec<&str> = trimmed.split(':').collect();
-                if parts.len() >= 2 {
-                    let field_name = parts[0].trim().trim_start_matches("pub ");
-                    let field_type = parts[1]
-                        .trim()
-                        .trim_end_matches(',')
-                        .trim();
+    #[test]
+    fn test_nullish_attribute() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Filter {
+    #[zod(nullish)]
+    query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[zod(nullish, min(1))]
+    page: Option<u32>,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
 
-                    let zod_type = rust_type_to_zod_simple(field_type, &current_attrs);
-                    fields.push(format!("  {}: {}", field_name, zod_type));
-                }
-                current_attrs.clear();
-            }
-        }
+        assert!(schema.contains("query: z.string().nullish()"));
+        assert!(schema.contains("page: z.number().int().min(1).nullish()"));
     }
 
-    if fields.is_empty() {
-        return None;
+    #[test]
+    fn test_struct_enum_variants() {
+        let source = r#"
+#[derive(ZodTs)]
+enum Response {
+    Ok,
+    Error { code: i32, message: String },
+    Moved {
+        #[zod(url)]
+        location: String,
+        // Seconds until the move expires
+        ttl: Option<u64>,
+    },
+    Pair(String, HashMap<String, i32>),
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains("z.object({ Ok: z.null() })"));
+        assert!(schema.contains(
+            "z.object({ Error: z.object({ code: z.number().int(), message: z.string() }) })"
+        ));
+        assert!(schema.contains(
+            "z.object({ Moved: z.object({ location: z.string().url(), ttl: z.number().int().nullable() }) })"
+        ));
+        assert!(schema.contains(
+            "z.object({ Pair: z.tuple([z.string(), z.record(z.string(), z.number().int())]) })"
+        ));
     }
 
-    let schema_name = format!("{}Schema", name);
-    Some(format!(
-        r#"import {{ z }} from 'zod';
+    #[test]
+    fn test_layout_the_line_scanner_could_not_handle() {
+        let source = r#"
+mod api {
+    use zod_rs_ts::ZodTs;
+
+    #[derive(Debug, zod_rs_ts::ZodTs)]
+    /// A page of results; the doc comment sits between the derive and the struct
+    #[serde(rename_all = "camelCase")]
+    pub struct Page {
+        pub items: Vec<
+            String,
+        >,
+        #[zod(regex(r"^[a-z]{2,4}$"))] pub locale: String,
+    }
 
-export const {} = z.object({{
-{}
-}});
+    #[derive(Debug)]
+    struct NotExported {
+        value: i32,
+    }
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        assert_eq!(schemas.len(), 1);
+
+        let (name, schema) = &schemas[0];
+        assert_eq!(name, "Page");
+        assert!(schema.contains("items: z.array(z.string())"));
+        assert!(schema.contains("locale: z.string().regex(/^[a-z]{2,4}$/)"));
+    }
 
-export type {} = z.infer<typeof {}>;"#,
-        schema_name,
-        fields.join(",\n"),
-        name,
-        schema_name
-    ))
+    #[test]
+    fn test_enum_attribute() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Article {
+    #[zod(enum("draft", "published", message = "Unknown status"))]
+    status: String,
+    #[zod(enum("en", "fr"))]
+    language: Option<String>,
+    #[zod(each(enum("rust", "web")))]
+    topics: Vec<String>,
 }
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
 
-fn generate_enum_schema(lines: &[&str], start: usize, name: &str) -> Option<String> {
-    let mut variants = Vec::new();
-    let mut in_enum = false;
-    let mut brace_count = 0;
+        assert!(schema.contains(r#"status: z.enum(["draft", "published"])"#));
+        assert!(schema.contains(r#"language: z.enum(["en", "fr"]).nullable()"#));
+        assert!(schema.contains(r#"topics: z.array(z.enum(["rust", "web"]))"#));
+    }
 
-    for line in lines.iter().skip(start) {
-        let trimmed = line.trim();
+    #[test]
+    fn test_e164_attribute() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Contact {
+    #[zod(e164)]
+    phone: String,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
 
-        if trimmed.contains('{') && !in_enum {
-            in_enum = true;
-            brace_count += trimmed.matches('{').count();
-            brace_count -= trimmed.matches('}').count();
-            continue;
-        }
+        assert!(schema.contains(r"phone: z.string().regex(/^\+[1-9]\d{1,14}$/)"));
+    }
 
-        if in_enum {
-            brace_count += trimmed.matches('{').count();
-            brace_count -= trimmed.matches('}').count();
+    #[test]
+    fn test_strings_are_escaped() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Escaped {
+    #[zod(starts_with("a\"b"), ends_with("c\\d"), includes("line\nbreak"))]
+    text: String,
+    #[zod(regex(r"^https?://[a-z]+/\d+$"))]
+    link: String,
+    #[zod(enum("say \"hi\"", r"back\slash"))]
+    choice: String,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains(
+            r#"text: z.string().startsWith("a\"b").endsWith("c\\d").includes("line\nbreak")"#
+        ));
+        assert!(schema.contains(r"link: z.string().regex(/^https?:\/\/[a-z]+\/\d+$/)"));
+        assert!(schema.contains(r#"choice: z.enum(["say \"hi\"", "back\\slash"])"#));
+    }
 
-            if brace_count == 0 {
-                break;
-            }
+    #[test]
+    fn test_regex_flags_attribute() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Country {
+    #[zod(regex("^[a-z]+$"), regex_flags("si"))]
+    code: String,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
 
-            if !trimmed.is_empty() && !trimmed.starts_with("//") && !trimmed.starts_with("#") {
-                // Parse variant
-                let variant_name = trimmed
-                    .split(|c| c == '(' || c == '{' || c == ',')
-                    .next()
-                    .unwrap_or("")
-                    .trim();
-
-                if !variant_name.is_empty() {
-                    if trimmed.contains('(') {
-                        // Tuple variant
-                        if let Some(inner) = trimmed
-                            .split('(')
-                            .nth(1)
-                            .and_then(|s| s.split(')').next())
-                        {
-                            let types: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
-                            if types.len() == 1 {
-                                let zod_type =
-                                    rust_type_to_zod_simple(types[0], &[]);
-                                variants.push(format!(
-                                    "z.object({{ {}: {} }})",
-                                    variant_name, zod_type
-                                ));
-                            } else {
-                                let tuple_types: Vec<String> = types
-                                    .iter()
-                                    .map(|t| rust_type_to_zod_simple(t, &[]))
-                                    .collect();
-                                variants.push(format!(
-                                    "z.object({{ {}: z.tuple([{}]) }})",
-                                    variant_name,
-                                    tuple_types.join(", ")
-                                ));
-                            }
-                        }
-                    } else if trimmed.contains('{') {
-                        // Struct variant - simplified handling
-                        variants.push(format!(
-                            "z.object({{ {}: z.object({{  }}) }})",
-                            variant_name
-                        ));
-                    } else {
-                        // Unit variant
-                        variants.push(format!("z.object({{ {}: z.null() }})", variant_name));
-                    }
-                }
-            }
-        }
+        assert!(schema.contains("code: z.string().regex(/^[a-z]+$/is)"));
     }
 
-    if variants.is_empty() {
-        return None;
+    #[test]
+    fn test_safe_int_attribute() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Tweet {
+    #[zod(safe_int)]
+    id: u64,
+    #[zod(safe_int, nonnegative)]
+    score: f64,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains("id: z.number().int().safe()"));
+        assert!(schema.contains("score: z.number().nonnegative().safe()"));
     }
 
-    let schema_name = format!("{}Schema", name);
-    Some(format!(
-        r#"import {{ z }} from 'zod';
+    #[test]
+    fn test_step_attribute() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Slot {
+    #[zod(step(9, 15))]
+    minute: u32,
+    #[zod(min(0.5), step(0.5, 0.25))]
+    volume: Option<f64>,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
+
+        assert!(schema.contains(
+            "minute: z.number().int().refine((n) => { const q = (n - 9) / 15; \
+             return Math.abs(q - Math.round(q)) < 1e-9; }, \
+             { message: \"Must be 9 plus a multiple of 15\" })"
+        ));
+        assert!(schema
+            .contains("volume: z.number().min(0.5).refine((n) => { const q = (n - 0.5) / 0.25;"));
+        assert!(schema.contains("{ message: \"Must be 0.5 plus a multiple of 0.25\" }).nullable()"));
+    }
 
-export const {} = z.union([
-  {}
-]);
+    #[test]
+    fn test_container_object_modes() {
+        let source = r#"
+#[derive(ZodTs)]
+#[zod(strict)]
+struct Closed {
+    id: i32,
+}
 
-export type {} = z.infer<typeof {}>;"#,
-        schema_name,
-        variants.join(",\n  "),
-        name,
-        schema_name
-    ))
+#[derive(ZodTs)]
+#[zod(strip)]
+struct Stripped {
+    id: i32,
 }
 
-fn rust_type_to_zod_simple(rust_type: &str, attrs: &[String]) -> String {
-    let rust_type = rust_type.trim();
-    let is_optional = rust_type.starts_with("Option<");
-
-    let inner_type = if is_optional {
-        rust_type
-            .strip_prefix("Option<")
-            .and_then(|s| s.strip_suffix('>'))
-            .unwrap_or(rust_type)
-    } else {
-        rust_type
-    };
-
-    let mut base = match inner_type {
-        "String" | "&str" | "str" => "z.string()".to_string(),
-        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
-        | "usize" => "z.number().int()".to_string(),
-        "f32" | "f64" => "z.number()".to_string(),
-        "bool" => "z.boolean()".to_string(),
-        other => {
-            if other.starts_with("Vec<") {
-                let element_type = other
-                    .strip_prefix("Vec<")
-                    .and_then(|s| s.strip_suffix('>'))
-                    .unwrap_or("unknown");
-                let inner_zod = rust_type_to_zod_simple(element_type, &[]);
-                format!("z.array({})", inner_zod)
-            } else {
-                format!("{}Schema", other)
-            }
-        }
-    };
+#[derive(ZodTs)]
+#[zod(passthrough)]
+struct Open {
+    id: i32,
+}
+"#;
+        let schemas = extract_schemas(source).unwrap();
 
-    // Apply attributes
-    for attr in attrs {
-        if attr.contains("email") {
-            base.push_str(".email()");
-        }
-        if attr.contains("url") {
-            base.push_str(".url()");
-        }
-        if let Some(min) = extract_attr_value(attr, "min_length") {
-            base.push_str(&format!(".min({})", min));
-        }
-        if let Some(max) = extract_attr_value(attr, "max_length") {
-            base.push_str(&format!(".max({})", max));
-        }
-        if let Some(min) = extract_attr_value(attr, "min") {
-            base.push_str(&format!(".min({})", min));
-        }
-        if let Some(max) = extract_attr_value(attr, "max") {
-            base.push_str(&format!(".max({})", max));
-        }
-        if attr.contains("positive") {
-            base.push_str(".positive()");
-        }
-        if attr.contains("negative") {
-            base.push_str(".negative()");
-        }
-        if attr.contains("nonnegative") {
-            base.push_str(".nonnegative()");
-        }
-        if attr.contains("nonpositive") {
-            base.push_str(".nonpositive()");
-        }
-        if attr.contains("int") && !base.contains(".int()") {
-            base.push_str(".int()");
-        }
+        assert!(schemas[0].1.contains("\n}).strict();"));
+        assert!(schemas[1].1.contains("\n});"));
+        assert!(schemas[2].1.contains("\n}).passthrough();"));
     }
 
-    if is_optional {
-        format!("{}.optional()", base)
-    } else {
-        base
-    }
+    #[test]
+    fn test_custom_attribute_is_ignored() {
+        let source = r#"
+#[derive(ZodTs)]
+struct Signup {
+    #[zod(min_length(8), custom = "crate::validators::is_strong_password")]
+    password: String,
 }
+"#;
+        let schemas = extract_schemas(source).unwrap();
+        let (_, schema) = &schemas[0];
 
-fn extract_attr_value(attr: &str, name: &str) -> Option<String> {
-    let pattern = format!(r"{}[\s]*\(([^)]+)\)", name);
-    let re = Regex::new(&pattern).ok()?;
-    re.captures(attr)
-        .and_then(|caps| caps.get(1))
-        .map(|m| m.as_str().trim().to_string())
-}
+        assert!(schema.contains("password: z.string().min(8)"));
+    }
 
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    for (i, c) in s.chars().enumerate() {
-        if c.is_uppercase() {
-            if i > 0 {
-                result.push('_');
-            }
-            result.push(c.to_lowercase().next().unwrap());
-        } else {
-            result.push(c);
-        }
+    #[test]
+    fn test_invalid_source_is_an_error() {
+        assert!(extract_schemas("#[derive(ZodTs)] struct Broken {").is_err());
     }
-    result
 }
@@ -0,0 +1,783 @@
+//! Rust type to Zod conversion, shared by the `ZodTs` derive and the CLI.
+
+use syn::{Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Lit, Meta};
+
+/// Generates the TypeScript module for a struct with named fields or an enum.
+pub(crate) fn generate_ts(input: &DeriveInput) -> syn::Result<String> {
+    let name_str = input.ident.to_string();
+
+    match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => {
+                let field_schemas: Vec<String> = fields
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let field_name = field.ident.as_ref().unwrap().to_string();
+                        format!(
+                            "{}  {}: {}",
+                            jsdoc(&field.attrs, "  "),
+                            field_name,
+                            field_to_zod(field)
+                        )
+                    })
+                    .collect();
+
+                let fields_str = field_schemas.join(",\n");
+                let schema_name = format!("{}Schema", name_str);
+
+                Ok(format!(
+                    r#"import {{ z }} from 'zod';
+
+{}export const {} = z.object({{
+{}
+}}){};
+
+export type {} = z.infer<typeof {}>;"#,
+                    jsdoc(&input.attrs, ""),
+                    schema_name,
+                    fields_str,
+                    object_mode(&input.attrs),
+                    name_str,
+                    schema_name
+                ))
+            }
+            _ => Err(syn::Error::new_spanned(
+                input,
+                "ZodTs can only be derived for structs with named fields",
+            )),
+        },
+        Data::Enum(data_enum) => {
+            let variant_schemas: Vec<String> = data_enum
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_name = variant.ident.to_string();
+                    format!(
+                        "{}  {}",
+                        jsdoc(&variant.attrs, "  "),
+                        generate_variant_ts(&variant_name, &variant.fields)
+                    )
+                })
+                .collect();
+
+            let variants_str = variant_schemas.join(",\n");
+            let schema_name = format!("{}Schema", name_str);
+
+            Ok(format!(
+                r#"import {{ z }} from 'zod';
+
+{}export const {} = z.union([
+{}
+]);
+
+export type {} = z.infer<typeof {}>;"#,
+                jsdoc(&input.attrs, ""),
+                schema_name,
+                variants_str,
+                name_str,
+                schema_name
+            ))
+        }
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            input,
+            "ZodTs cannot be derived for Rust unions",
+        )),
+    }
+}
+
+pub(crate) fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Renders the `///` doc comments in `attrs` as a JSDoc block, one line per doc line,
+/// or returns an empty string when there are none.
+fn jsdoc(attrs: &[Attribute], indent: &str) -> String {
+    let mut lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flat_map(|doc| {
+            // `split` rather than `lines`, so that an empty `///` line is kept
+            doc.split('\n')
+                .map(|line| {
+                    // `/// text` is stored as `" text"`; a closing `*/` would end the comment early
+                    let line = line.strip_prefix(' ').unwrap_or(line);
+                    line.trim_end().replace("*/", "*\\/")
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let first = lines.iter().position(|line| !line.is_empty());
+    lines.drain(..first.unwrap_or(lines.len()));
+
+    match lines.as_slice() {
+        [] => String::new(),
+        [line] => format!("{}/** {} */\n", indent, line),
+        lines => {
+            let mut block = format!("{}/**\n", indent);
+            for line in lines {
+                if line.is_empty() {
+                    block.push_str(&format!("{} *\n", indent));
+                } else {
+                    block.push_str(&format!("{} * {}\n", indent, line));
+                }
+            }
+            block.push_str(&format!("{} */\n", indent));
+            block
+        }
+    }
+}
+
+fn generate_variant_ts(variant_name: &str, fields: &Fields) -> String {
+    match fields {
+        Fields::Unit => {
+            format!("z.object({{ {}: z.null() }})", variant_name)
+        }
+        Fields::Unnamed(fields_unnamed) => {
+            let field_count = fields_unnamed.unnamed.len();
+            if field_count == 1 {
+                let field = fields_unnamed.unnamed.first().unwrap();
+                let attrs = parse_zod_attributes(&field.attrs);
+                let zod_type = type_to_zod(&field.ty, &attrs);
+                format!("z.object({{ {}: {} }})", variant_name, zod_type)
+            } else {
+                let element_types: Vec<String> = fields_unnamed
+                    .unnamed
+                    .iter()
+                    .map(|field| {
+                        let attrs = parse_zod_attributes(&field.attrs);
+                        type_to_zod(&field.ty, &attrs)
+                    })
+                    .collect();
+                let tuple_str = element_types.join(", ");
+                format!("z.object({{ {}: z.tuple([{}]) }})", variant_name, tuple_str)
+            }
+        }
+        Fields::Named(fields_named) => {
+            let field_schemas: Vec<String> = fields_named
+                .named
+                .iter()
+                .map(|field| {
+                    let field_name = field.ident.as_ref().unwrap().to_string();
+                    format!("{}: {}", field_name, field_to_zod(field))
+                })
+                .collect();
+            let fields_str = field_schemas.join(", ");
+            format!(
+                "z.object({{ {}: z.object({{ {} }}) }})",
+                variant_name, fields_str
+            )
+        }
+    }
+}
+
+/// Builds the Zod type of a named field. serde writes `None` as `null`, so an
+/// `Option` is `.nullable()` unless `skip_serializing_if` drops it from the output,
+/// in which case it is `.optional()`. `#[zod(nullable)]` adds `.nullable()` to any field,
+/// and `#[zod(nullish)]` replaces both with `.nullish()`.
+fn field_to_zod(field: &syn::Field) -> String {
+    let attrs = parse_zod_attributes(&field.attrs);
+    let option_inner = option_inner_type(&field.ty);
+    let is_optional = option_inner.is_some();
+    let skips_none = has_skip_serializing_if(&field.attrs);
+
+    let mut zod_type = type_to_zod(option_inner.unwrap_or(&field.ty), &attrs);
+    if attrs.nullish {
+        zod_type.push_str(".nullish()");
+        return zod_type;
+    }
+    if (is_optional && !skips_none) || attrs.nullable {
+        zod_type.push_str(".nullable()");
+    }
+    if is_optional && skips_none {
+        zod_type.push_str(".optional()");
+    }
+
+    zod_type
+}
+
+fn has_skip_serializing_if(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("serde")
+            && matches!(&attr.meta, Meta::List(meta_list) if meta_list
+                .tokens
+                .clone()
+                .into_iter()
+                .any(|token| token.to_string() == "skip_serializing_if"))
+    })
+}
+
+#[derive(Default)]
+pub(crate) struct ZodAttributes {
+    min: Option<f64>,
+    max: Option<f64>,
+    length: Option<usize>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    starts_with: Option<String>,
+    ends_with: Option<String>,
+    includes: Option<String>,
+    email: bool,
+    url: bool,
+    uuid: bool,
+    datetime: bool,
+    e164: bool,
+    trim: bool,
+    to_lowercase: bool,
+    to_uppercase: bool,
+    regex: Option<String>,
+    regex_flags: Option<String>,
+    enum_values: Option<Vec<String>>,
+    positive: bool,
+    negative: bool,
+    nonnegative: bool,
+    nonpositive: bool,
+    int: bool,
+    safe_int: bool,
+    finite: bool,
+    multiple_of: Option<f64>,
+    step: Option<(f64, f64)>,
+    nullable: bool,
+    nullish: bool,
+    each: Option<Box<ZodAttributes>>,
+}
+
+/// The Zod call for a struct's `#[zod(strict)]` or `#[zod(passthrough)]`. Zod
+/// strips unknown keys by default, so `#[zod(strip)]` needs none.
+fn object_mode(attrs: &[Attribute]) -> &'static str {
+    for attr in attrs {
+        if let (true, Meta::List(meta_list)) = (attr.path().is_ident("zod"), &attr.meta) {
+            for token in meta_list.tokens.clone() {
+                match token.to_string().as_str() {
+                    "strict" => return ".strict()",
+                    "passthrough" => return ".passthrough()",
+                    _ => {}
+                }
+            }
+        }
+    }
+    ""
+}
+
+fn parse_zod_attributes(attrs: &[Attribute]) -> ZodAttributes {
+    let mut zod_attrs = ZodAttributes::default();
+
+    for attr in attrs {
+        if attr.path().is_ident("zod") {
+            if let Meta::List(meta_list) = &attr.meta {
+                parse_zod_tokens(meta_list.tokens.clone(), &mut zod_attrs);
+            }
+        }
+    }
+
+    zod_attrs
+}
+
+fn parse_zod_tokens(tokens: proc_macro2::TokenStream, zod_attrs: &mut ZodAttributes) {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token_str = tokens[i].to_string();
+
+        match token_str.as_str() {
+            "min_length" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_number_from_parens(&value_token) {
+                    zod_attrs.min_length = Some(value);
+                }
+                i += 1;
+            }
+            "max_length" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_number_from_parens(&value_token) {
+                    zod_attrs.max_length = Some(value);
+                }
+                i += 1;
+            }
+            "length" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_number_from_parens(&value_token) {
+                    zod_attrs.length = Some(value);
+                }
+                i += 1;
+            }
+            "min" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value_str) = extract_string_from_parens(&value_token) {
+                    if let Ok(value) = value_str.parse::<f64>() {
+                        zod_attrs.min = Some(value);
+                    }
+                }
+                i += 1;
+            }
+            "max" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value_str) = extract_string_from_parens(&value_token) {
+                    if let Ok(value) = value_str.parse::<f64>() {
+                        zod_attrs.max = Some(value);
+                    }
+                }
+                i += 1;
+            }
+            "multiple_of" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value_str) = extract_string_from_parens(&value_token) {
+                    if let Ok(value) = value_str.parse::<f64>() {
+                        zod_attrs.multiple_of = Some(value);
+                    }
+                }
+                i += 1;
+            }
+            "step" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string().replace(' ', "");
+                let args = value_token
+                    .strip_prefix('(')
+                    .and_then(|s| s.strip_suffix(')'))
+                    .unwrap_or_default();
+                let mut values = args.split(',').map(|value| value.parse::<f64>());
+                if let (Some(Ok(start)), Some(Ok(step))) = (values.next(), values.next()) {
+                    zod_attrs.step = Some((start, step));
+                }
+                i += 1;
+            }
+            "starts_with" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_string_from_parens(&value_token) {
+                    zod_attrs.starts_with = Some(strip_quotes(&value));
+                }
+                i += 1;
+            }
+            "ends_with" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_string_from_parens(&value_token) {
+                    zod_attrs.ends_with = Some(strip_quotes(&value));
+                }
+                i += 1;
+            }
+            "includes" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_string_from_parens(&value_token) {
+                    zod_attrs.includes = Some(strip_quotes(&value));
+                }
+                i += 1;
+            }
+            "regex" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_string_from_parens(&value_token) {
+                    zod_attrs.regex = Some(strip_quotes(&value));
+                }
+                i += 1;
+            }
+            "regex_flags" if i + 1 < tokens.len() => {
+                let value_token = tokens[i + 1].to_string();
+                if let Some(value) = extract_string_from_parens(&value_token) {
+                    zod_attrs.regex_flags = Some(strip_quotes(&value));
+                }
+                i += 1;
+            }
+            "enum" if i + 1 < tokens.len() => {
+                if let proc_macro2::TokenTree::Group(group) = &tokens[i + 1] {
+                    // String literals up to a trailing `message = "..."`
+                    let values = group
+                        .stream()
+                        .into_iter()
+                        .take_while(|token| token.to_string() != "message")
+                        .filter(|token| matches!(token, proc_macro2::TokenTree::Literal(_)))
+                        .map(|token| strip_quotes(&token.to_string()))
+                        .collect();
+                    zod_attrs.enum_values = Some(values);
+                }
+                i += 1;
+            }
+            "email" => {
+                zod_attrs.email = true;
+            }
+            "url" => {
+                zod_attrs.url = true;
+            }
+            "uuid" => {
+                zod_attrs.uuid = true;
+            }
+            "datetime" => {
+                zod_attrs.datetime = true;
+            }
+            "e164" => {
+                zod_attrs.e164 = true;
+            }
+            "trim" => {
+                zod_attrs.trim = true;
+            }
+            "to_lowercase" => {
+                zod_attrs.to_lowercase = true;
+            }
+            "to_uppercase" => {
+                zod_attrs.to_uppercase = true;
+            }
+            "positive" => {
+                zod_attrs.positive = true;
+            }
+            "negative" => {
+                zod_attrs.negative = true;
+            }
+            "nonnegative" => {
+                zod_attrs.nonnegative = true;
+            }
+            "nonpositive" => {
+                zod_attrs.nonpositive = true;
+            }
+            "int" => {
+                zod_attrs.int = true;
+            }
+            "safe_int" => {
+                zod_attrs.safe_int = true;
+            }
+            "finite" => {
+                zod_attrs.finite = true;
+            }
+            "nullable" => {
+                zod_attrs.nullable = true;
+            }
+            "nullish" => {
+                zod_attrs.nullish = true;
+            }
+            "each" if i + 1 < tokens.len() => {
+                if let proc_macro2::TokenTree::Group(group) = &tokens[i + 1] {
+                    let mut each = ZodAttributes::default();
+                    parse_zod_tokens(group.stream(), &mut each);
+                    zod_attrs.each = Some(Box::new(each));
+                }
+                i += 1;
+            }
+            "," => {}
+            _ => {}
+        }
+
+        i += 1;
+    }
+}
+
+fn extract_number_from_parens(token: &str) -> Option<usize> {
+    extract_string_from_parens(token).and_then(|inner| inner.parse::<usize>().ok())
+}
+
+/// Returns the first argument inside the parentheses, dropping a trailing
+/// `message = "..."` (used by the schema derive only).
+fn extract_string_from_parens(token: &str) -> Option<String> {
+    token
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .map(|s| first_arg(s).to_string())
+}
+
+fn first_arg(args: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in args.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ',' if !in_string => return args[..index].trim(),
+            _ => {}
+        }
+    }
+
+    args.trim()
+}
+
+/// The value of a Rust string literal token, with escapes resolved: `"a\"b"` and
+/// `r#"a"b"#` both give `a"b`. Anything else is returned as-is.
+pub(crate) fn strip_quotes(value: &str) -> String {
+    match syn::parse_str::<syn::LitStr>(value) {
+        Ok(literal) => literal.value(),
+        Err(_) => value.to_string(),
+    }
+}
+
+/// Renders `value` as a double-quoted JavaScript string literal.
+fn js_string(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            // Line and paragraph separators end a line in older engines
+            c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => {
+                literal.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Renders a regex pattern as the body of a JavaScript regex literal, escaping the
+/// `/` and line breaks that would otherwise end it early.
+fn js_regex(pattern: &str) -> String {
+    let mut body = String::with_capacity(pattern.len());
+    let mut escaped = false;
+    for c in pattern.chars() {
+        match c {
+            '/' if !escaped => body.push_str("\\/"),
+            '\n' | '\r' => {
+                if !escaped {
+                    body.push('\\');
+                }
+                body.push(if c == '\n' { 'n' } else { 'r' });
+            }
+            c => body.push(c),
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    body
+}
+
+/// Converts a Rust type and its `#[zod(...)]` attributes into a Zod expression,
+/// recursing through `Option`, `Vec`, map and smart pointer type arguments. This is the only type
+/// mapping: the derive and the CLI both reach it through `generate_ts`, so their output
+/// is identical.
+pub(crate) fn type_to_zod(ty: &syn::Type, attrs: &ZodAttributes) -> String {
+    let (ident, args) = match ty {
+        syn::Type::Reference(reference) => return type_to_zod(&reference.elem, attrs),
+        syn::Type::Paren(paren) => return type_to_zod(&paren.elem, attrs),
+        ty => match last_segment(ty) {
+            Some(segment) => segment,
+            None => return String::from("z.unknown()"),
+        },
+    };
+
+    match (ident.as_str(), args.as_slice()) {
+        ("String" | "str", _) if attrs.enum_values.is_some() => {
+            let values: Vec<String> = attrs
+                .enum_values
+                .iter()
+                .flatten()
+                .map(|value| js_string(value))
+                .collect();
+            format!("z.enum([{}])", values.join(", "))
+        }
+        ("String" | "str", _) => {
+            let mut chain = String::from("z.string()");
+
+            // Normalizations run in chain order in Zod, so they must precede the checks
+            if attrs.trim {
+                chain.push_str(".trim()");
+            }
+            if attrs.to_lowercase {
+                chain.push_str(".toLowerCase()");
+            }
+            if attrs.to_uppercase {
+                chain.push_str(".toUpperCase()");
+            }
+            if let Some(len) = attrs.length {
+                chain.push_str(&format!(".length({})", len));
+            }
+            if let Some(min) = attrs.min_length {
+                chain.push_str(&format!(".min({})", min));
+            }
+            if let Some(max) = attrs.max_length {
+                chain.push_str(&format!(".max({})", max));
+            }
+            if attrs.email {
+                chain.push_str(".email()");
+            }
+            if attrs.url {
+                chain.push_str(".url()");
+            }
+            if attrs.uuid {
+                chain.push_str(".uuid()");
+            }
+            if attrs.datetime {
+                chain.push_str(".datetime()");
+            }
+            if attrs.e164 {
+                chain.push_str(r".regex(/^\+[1-9]\d{1,14}$/)");
+            }
+            if let Some(ref pattern) = attrs.regex {
+                // JavaScript lists flags in this order
+                let flags: String = "ims"
+                    .chars()
+                    .filter(|flag| attrs.regex_flags.as_deref().unwrap_or("").contains(*flag))
+                    .collect();
+                chain.push_str(&format!(".regex(/{}/{})", js_regex(pattern), flags));
+            }
+            if let Some(ref prefix) = attrs.starts_with {
+                chain.push_str(&format!(".startsWith({})", js_string(prefix)));
+            }
+            if let Some(ref suffix) = attrs.ends_with {
+                chain.push_str(&format!(".endsWith({})", js_string(suffix)));
+            }
+            if let Some(ref substr) = attrs.includes {
+                chain.push_str(&format!(".includes({})", js_string(substr)));
+            }
+
+            chain
+        }
+        (
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+            | "usize",
+            _,
+        ) => {
+            let mut chain = String::from("z.number().int()");
+            append_number_validators(&mut chain, attrs);
+            chain
+        }
+        ("f32" | "f64", _) => {
+            let mut chain = String::from("z.number()");
+            if attrs.int {
+                chain.push_str(".int()");
+            }
+            append_number_validators(&mut chain, attrs);
+            chain
+        }
+        ("bool", _) => String::from("z.boolean()"),
+        // serde represents smart pointers as the value they point to
+        ("Box" | "Rc" | "Arc", [inner]) => type_to_zod(inner, attrs),
+        // Attributes other than `nullable` describe the inner value
+        ("Option", [inner]) => format!("{}.nullable()", type_to_zod(inner, attrs)),
+        ("Vec", [element]) => {
+            let default_attrs = ZodAttributes::default();
+            let element_attrs = attrs.each.as_deref().unwrap_or(&default_attrs);
+            let mut chain = format!("z.array({})", type_to_zod(element, element_attrs));
+
+            if let Some(len) = attrs.length {
+                chain.push_str(&format!(".length({})", len));
+            }
+            if let Some(min) = attrs.min_length {
+                chain.push_str(&format!(".min({})", min));
+            }
+            if let Some(max) = attrs.max_length {
+                chain.push_str(&format!(".max({})", max));
+            }
+
+            chain
+        }
+        // JSON object keys are always strings, whatever the Rust key type
+        ("HashMap" | "BTreeMap", [_, value]) => {
+            let default_attrs = ZodAttributes::default();
+            let value_attrs = attrs.each.as_deref().unwrap_or(&default_attrs);
+            format!("z.record(z.string(), {})", type_to_zod(value, value_attrs))
+        }
+        ("DateTime" | "NaiveDateTime" | "NaiveDate" | "NaiveTime", _)
+            if cfg!(feature = "chrono") =>
+        {
+            chrono_to_zod(&ident, &args)
+        }
+        (name, _) => format!("{}Schema", name),
+    }
+}
+
+/// Maps chrono's date and time types to the ISO 8601 strings serde writes for them.
+/// `DateTime<Utc>` always ends in `Z`, other time zones write an offset, and the naive
+/// types have none.
+fn chrono_to_zod(ident: &str, args: &[&syn::Type]) -> String {
+    let format = match ident {
+        "DateTime" => match args.first().and_then(|tz| last_segment(tz)) {
+            Some((tz, _)) if tz == "Utc" => "datetime()",
+            _ => "datetime({ offset: true })",
+        },
+        "NaiveDateTime" => "datetime({ local: true })",
+        "NaiveDate" => "date()",
+        _ => "time()",
+    };
+    format!("z.string().{}", format)
+}
+
+/// Returns the last path segment of a type and its type arguments, so
+/// `std::collections::HashMap<K, V>` gives `HashMap` and `[K, V]`.
+fn last_segment(ty: &syn::Type) -> Option<(String, Vec<&syn::Type>)> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let args = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Some((segment.ident.to_string(), args))
+}
+
+fn append_number_validators(chain: &mut String, attrs: &ZodAttributes) {
+    if let Some(min) = attrs.min {
+        chain.push_str(&format!(".min({})", min));
+    }
+    if let Some(max) = attrs.max {
+        chain.push_str(&format!(".max({})", max));
+    }
+    if attrs.positive {
+        chain.push_str(".positive()");
+    }
+    if attrs.negative {
+        chain.push_str(".negative()");
+    }
+    if attrs.nonnegative {
+        chain.push_str(".nonnegative()");
+    }
+    if attrs.nonpositive {
+        chain.push_str(".nonpositive()");
+    }
+    if attrs.safe_int {
+        chain.push_str(".safe()");
+    }
+    if attrs.finite {
+        chain.push_str(".finite()");
+    }
+    if let Some(multiple_of) = attrs.multiple_of {
+        chain.push_str(&format!(".multipleOf({})", multiple_of));
+    }
+    // Zod has no offset grid, so check it like `multipleOf` does, with a tolerance
+    if let Some((start, step)) = attrs.step {
+        chain.push_str(&format!(
+            ".refine((n) => {{ const q = (n - {start}) / {step}; \
+             return Math.abs(q - Math.round(q)) < 1e-9; }}, \
+             {{ message: {} }})",
+            js_string(&format!("Must be {start} plus a multiple of {step}"))
+        ));
+    }
+}
+
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    match last_segment(ty)? {
+        (ident, args) if ident == "Option" && args.len() == 1 => Some(args[0]),
+        _ => None,
+    }
+}
@@ -0,0 +1,47 @@
+//! Finds the types deriving `ZodTs` in a source file. Used by the CLI, which cannot
+//! link against this proc-macro crate and includes the module by path instead.
+
+use crate::codegen;
+use syn::{punctuated::Punctuated, Attribute, DeriveInput, Item, Path, Token};
+
+/// Parses a Rust source file and generates the schema of every type deriving `ZodTs`,
+/// using the same conversion as the derive macro.
+pub fn extract_schemas(content: &str) -> syn::Result<Vec<(String, String)>> {
+    let file = syn::parse_file(content)?;
+    let mut schemas = Vec::new();
+    collect_schemas(&file.items, &mut schemas)?;
+    Ok(schemas)
+}
+
+fn collect_schemas(items: &[Item], schemas: &mut Vec<(String, String)>) -> syn::Result<()> {
+    for item in items {
+        let input = match item {
+            Item::Struct(item) if derives_zod_ts(&item.attrs) => DeriveInput::from(item.clone()),
+            Item::Enum(item) if derives_zod_ts(&item.attrs) => DeriveInput::from(item.clone()),
+            Item::Mod(module) => {
+                if let Some((_, items)) = &module.content {
+                    collect_schemas(items, schemas)?;
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        schemas.push((input.ident.to_string(), codegen::generate_ts(&input)?));
+    }
+
+    Ok(())
+}
+
+/// Matches `#[derive(ZodTs)]` as well as paths like `#[derive(zod_rs_ts::ZodTs)]`.
+fn derives_zod_ts(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| path.segments.last().is_some_and(|seg| seg.ident == "ZodTs"))
+}
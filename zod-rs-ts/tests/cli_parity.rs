@@ -0,0 +1,72 @@
+//! The CLI and the derive must generate byte-identical schemas for the same type.
+
+#![allow(dead_code)]
+
+#[path = "../src/codegen.rs"]
+mod codegen;
+#[path = "../src/extract.rs"]
+mod extract;
+
+use std::collections::HashMap;
+use zod_rs_ts::ZodTs;
+
+/// A user account.
+///
+/// Closing a comment with */ must not break the output.
+#[derive(ZodTs)]
+struct Account {
+    /// Unique, lowercase handle
+    #[zod(
+        trim,
+        min_length(3),
+        max_length(20),
+        regex(r"^[a-z_]+$"),
+        regex_flags("i")
+    )]
+    username: String,
+    #[zod(email, ends_with(".org"))]
+    email: String,
+    #[zod(e164)]
+    phone: String,
+    #[zod(starts_with("\"/"), regex(r"^[^/]+/\w+$"))]
+    path: String,
+    #[zod(min(0.0), max(5.0), multiple_of(0.5))]
+    rating: f64,
+    #[zod(nonnegative)]
+    balance: i64,
+    #[zod(safe_int)]
+    external_id: u64,
+    #[zod(min_length(1), each(uuid))]
+    sessions: Vec<String>,
+    limits: HashMap<String, u32>,
+    #[zod(nullable)]
+    bio: Option<String>,
+    #[zod(nullish, url)]
+    website: Option<String>,
+    #[zod(enum("free", "pro", message = "Unknown plan"))]
+    plan: String,
+}
+
+#[derive(ZodTs)]
+enum Event {
+    /// The resource was closed
+    Closed,
+    Renamed(String),
+    Moved(i32, i32),
+    Failed {
+        code: u16,
+        #[zod(min_length(1))]
+        reason: String,
+        retry_in: Option<u64>,
+    },
+}
+
+#[test]
+fn test_cli_output_matches_derive() {
+    let schemas = extract::extract_schemas(include_str!("cli_parity.rs")).unwrap();
+    let generated: HashMap<_, _> = schemas.into_iter().collect();
+
+    assert_eq!(generated.len(), 2);
+    assert_eq!(generated["Account"], Account::zod_ts());
+    assert_eq!(generated["Event"], Event::zod_ts());
+}
@@ -1,33 +1,99 @@
-use crate::{locales::Locale, ValidationError};
-use std::fmt;
+use crate::{
+    locales::{default_locale, Locale},
+    ValidationError,
+};
+use std::{borrow::Cow, fmt};
+
+/// How an issue's path is written in messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathFormat {
+    /// Keys joined by dots, e.g. `items.1.name`. Ambiguous when keys contain dots.
+    #[default]
+    Dotted,
+    /// An RFC 6901 JSON Pointer, e.g. `/items/1/name`.
+    JsonPointer,
+}
+
+impl PathFormat {
+    fn render(self, path: &[String]) -> String {
+        match self {
+            PathFormat::Dotted => path.join("."),
+            PathFormat::JsonPointer => path
+                .iter()
+                .map(|key| format!("/{}", key.replace('~', "~0").replace('/', "~1")))
+                .collect(),
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ValidationIssue {
     pub path: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub error: ValidationError,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) locale: Option<Locale>,
 }
 
 impl ValidationIssue {
+    pub fn new(path: Vec<String>, error: ValidationError) -> Self {
+        Self {
+            path,
+            error,
+            locale: None,
+        }
+    }
+
+    /// The locale `Display` renders this issue in, overriding
+    /// [`default_locale`](crate::default_locale). Set by
+    /// [`ValidationResult::with_locale`](crate::ValidationResult::with_locale).
+    pub fn locale(&self) -> Option<Locale> {
+        self.locale
+    }
+
+    /// The machine-readable code of the error; see [`ValidationError::code`].
+    pub fn code(&self) -> Cow<'static, str> {
+        self.error.code()
+    }
+
     pub fn local(&self, lang: Locale) -> String {
+        self.local_with_path(lang, PathFormat::Dotted)
+    }
+
+    /// Like [`local`](Self::local), with the path rendered in `format`.
+    pub fn local_with_path(&self, lang: Locale, format: PathFormat) -> String {
         if self.path.is_empty() {
             self.error.local(lang)
         } else {
-            format!("{}: {}", self.path.join("."), self.error.local(lang))
+            format!("{}: {}", format.render(&self.path), self.error.local(lang))
         }
     }
-}
 
-impl fmt::Display for ValidationIssue {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Returns the path as an RFC 6901 JSON Pointer, e.g. `/items/1/name`, with `~`
+    /// and `/` in keys escaped as `~0` and `~1`. The root is the empty string.
+    pub fn json_pointer(&self) -> String {
+        PathFormat::JsonPointer.render(&self.path)
+    }
+
+    /// Like [`local`](Self::local), with union variants listed as by
+    /// [`ValidationError::local_verbose`].
+    pub fn local_verbose(&self, lang: Locale) -> String {
         if self.path.is_empty() {
-            write!(f, "{}", self.error.local(Locale::En))
+            self.error.local_verbose(lang)
         } else {
-            write!(
-                f,
+            format!(
                 "{}: {}",
                 self.path.join("."),
-                self.error.local(Locale::En)
+                self.error.local_verbose(lang)
             )
         }
     }
 }
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locale = self.locale.unwrap_or_else(default_locale);
+        write!(f, "{}", self.local(locale))
+    }
+}
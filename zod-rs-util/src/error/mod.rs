@@ -2,17 +2,27 @@ pub mod issue;
 pub mod result;
 
 use crate::{
-    locales::{localizer, Locale},
+    locales::{default_locale, localizer, union_labels, Locale},
     ValidationIssue,
 };
 use serde_json::Value;
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
+/// A single validation failure.
+///
+/// With the `serde` feature this serializes with a stable snake_case `code`
+/// (e.g. `"too_small"`) next to the variant's fields.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "code", rename_all = "snake_case")
+)]
 pub enum ValidationError {
     Required,
     InvalidType {
         expected: ValidationType,
+        #[cfg_attr(feature = "serde", serde(rename = "received"))]
         input: ValidationType,
     },
     InvalidValue {
@@ -25,15 +35,24 @@ pub enum ValidationError {
         origin: ValidationOrigin,
         maximum: String,
         inclusive: bool,
+        /// The offending number or size, when the schema attached it.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        received: Option<String>,
     },
     TooSmall {
         origin: ValidationOrigin,
         minimum: String,
         inclusive: bool,
+        /// The offending number or size, when the schema attached it.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        received: Option<String>,
     },
     InvalidFormat {
         format: StringFormat,
         detail: Option<String>,
+        /// The offending string, when the schema attached it.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        received: Option<String>,
     },
     InvalidNumber {
         constraint: NumberConstraint,
@@ -41,8 +60,33 @@ pub enum ValidationError {
     UnrecognizedKeys {
         keys: Vec<String>,
     },
+    /// Holds the issues of every variant, one list per variant in declaration order.
+    /// Their paths are relative to the union.
     InvalidUnion {
-        issues: Vec<ValidationIssue>,
+        branches: Vec<Vec<ValidationIssue>>,
+        /// The variant labels, in the same order; empty when no variant is labeled.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+        labels: Vec<Option<String>>,
+    },
+    Empty {
+        origin: ValidationOrigin,
+    },
+    NotUnique {
+        duplicate_of: usize,
+    },
+    /// Fewer than `minimum` array elements matched the schema passed to `contains`.
+    TooFewMatches {
+        minimum: usize,
+        found: usize,
+    },
+    DuplicateSetElement {
+        duplicate_of: usize,
+    },
+    /// A key failed the key schema of a record. `detail` is the key schema's error;
+    /// the issue path ends with the key itself.
+    InvalidKey {
+        origin: ValidationOrigin,
+        detail: Box<ValidationError>,
     },
     Custom {
         message: String,
@@ -53,11 +97,125 @@ impl ValidationError {
     pub fn local(&self, locale: Locale) -> String {
         localizer(locale).localize(self)
     }
+
+    /// Like [`local`](Self::local), but lists the issues of every union variant below
+    /// the one-line summary, numbered by variant:
+    ///
+    /// ```text
+    /// Invalid input; none of the 2 options matched:
+    ///   1) Invalid input: expected string, received number
+    ///   2) Invalid input: expected bool, received number
+    /// ```
+    ///
+    /// Labeled variants are grouped under their label instead:
+    ///
+    /// ```text
+    /// Invalid input; did not match any of: EmailString, UUID
+    ///   EmailString: Invalid email address, received "x"
+    ///   UUID: Invalid UUID, received "x"
+    /// ```
+    pub fn local_verbose(&self, locale: Locale) -> String {
+        let summary = self.local(locale);
+
+        let Self::InvalidUnion { branches, labels } = self else {
+            return summary;
+        };
+        if branches.is_empty() {
+            return summary;
+        }
+
+        let names = union_labels(labels);
+        let mut output = match names {
+            Some(_) => summary,
+            None => format!("{summary}:"),
+        };
+        for (index, branch) in branches.iter().enumerate() {
+            let marker = match &names {
+                Some(names) => format!("{}:", names[index]),
+                None => format!("{})", index + 1),
+            };
+            let indent = " ".repeat(marker.len() + 3);
+
+            for (position, issue) in branch.iter().enumerate() {
+                let lead = if position == 0 {
+                    format!("\n  {marker} ")
+                } else {
+                    format!("\n{indent}")
+                };
+                // Nested unions are indented below their own line
+                let text = issue
+                    .local_verbose(locale)
+                    .replace('\n', &format!("\n{indent}"));
+                output.push_str(&lead);
+                output.push_str(&text);
+            }
+        }
+
+        output
+    }
+
+    /// Returns a stable machine-readable code for branching on the kind of failure,
+    /// independent of the locale. These codes do not change between releases:
+    ///
+    /// | Variant | Code |
+    /// |---|---|
+    /// | `Required` | `required` |
+    /// | `InvalidType` | `invalid_type` |
+    /// | `InvalidValue` | `invalid_value` |
+    /// | `InvalidValues` | `invalid_values` |
+    /// | `TooBig` | `too_big` |
+    /// | `TooSmall` | `too_small` |
+    /// | `InvalidFormat` | `invalid_format:<format>`, e.g. `invalid_format:email` or `invalid_format:starts_with` |
+    /// | `InvalidNumber` | `invalid_number:<constraint>`, e.g. `invalid_number:positive` or `invalid_number:multiple_of` |
+    /// | `UnrecognizedKeys` | `unrecognized_keys` |
+    /// | `InvalidUnion` | `invalid_union` |
+    /// | `Empty` | `empty` |
+    /// | `NotUnique` | `not_unique` |
+    /// | `TooFewMatches` | `too_few_matches` |
+    /// | `DuplicateSetElement` | `duplicate_set_element` |
+    /// | `InvalidKey` | `invalid_key` |
+    /// | `Custom` | `custom` |
+    ///
+    /// The part before any `:` is the `code` the error serializes with.
+    ///
+    /// ```
+    /// use zod_rs_util::{StringFormat, ValidationError};
+    ///
+    /// let error = ValidationError::invalid_format(StringFormat::custom("email"), None);
+    /// assert_eq!(error.code(), "invalid_format:email");
+    /// assert_eq!(ValidationError::required().code(), "required");
+    /// ```
+    pub fn code(&self) -> Cow<'static, str> {
+        let code = match self {
+            Self::Required => "required",
+            Self::InvalidType { .. } => "invalid_type",
+            Self::InvalidValue { .. } => "invalid_value",
+            Self::InvalidValues { .. } => "invalid_values",
+            Self::TooBig { .. } => "too_big",
+            Self::TooSmall { .. } => "too_small",
+            Self::InvalidFormat { format, .. } => {
+                return Cow::Owned(format!("invalid_format:{format}"));
+            }
+            Self::InvalidNumber { constraint } => {
+                return Cow::Borrowed(constraint.code());
+            }
+            Self::UnrecognizedKeys { .. } => "unrecognized_keys",
+            Self::InvalidUnion { .. } => "invalid_union",
+            Self::Empty { .. } => "empty",
+            Self::NotUnique { .. } => "not_unique",
+            Self::TooFewMatches { .. } => "too_few_matches",
+            Self::DuplicateSetElement { .. } => "duplicate_set_element",
+            Self::InvalidKey { .. } => "invalid_key",
+            Self::Custom { .. } => "custom",
+        };
+
+        Cow::Borrowed(code)
+    }
 }
 
 impl fmt::Display for ValidationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.local(Locale::En))?;
+        write!(f, "{}", self.local(default_locale()))?;
         Ok(())
     }
 }
@@ -86,6 +244,7 @@ impl ValidationError {
             origin,
             maximum: maximum.into(),
             inclusive,
+            received: None,
         }
     }
 
@@ -98,11 +257,53 @@ impl ValidationError {
             origin,
             minimum: minimum.into(),
             inclusive,
+            received: None,
         }
     }
 
     pub fn invalid_format(format: StringFormat, detail: Option<String>) -> Self {
-        Self::InvalidFormat { format, detail }
+        Self::InvalidFormat {
+            format,
+            detail,
+            received: None,
+        }
+    }
+
+    /// Attaches the value that failed to a `TooBig`, `TooSmall` or `InvalidFormat`
+    /// error, so that messages end in e.g. ", received 150". Other errors are
+    /// returned unchanged.
+    ///
+    /// ```
+    /// use zod_rs_util::{ValidationError, ValidationOrigin};
+    ///
+    /// let error = ValidationError::too_big(ValidationOrigin::Number, "100", true);
+    /// assert_eq!(error.to_string(), "Too big: expected number to have <= 100");
+    ///
+    /// let error = error.with_received("150");
+    /// assert_eq!(error.received(), Some("150"));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Too big: expected number to have <= 100, received 150"
+    /// );
+    /// ```
+    pub fn with_received(mut self, value: impl Into<String>) -> Self {
+        if let Self::TooBig { received, .. }
+        | Self::TooSmall { received, .. }
+        | Self::InvalidFormat { received, .. } = &mut self
+        {
+            *received = Some(value.into());
+        }
+        self
+    }
+
+    /// The value attached with [`with_received`](Self::with_received).
+    pub fn received(&self) -> Option<&str> {
+        match self {
+            Self::TooBig { received, .. }
+            | Self::TooSmall { received, .. }
+            | Self::InvalidFormat { received, .. } => received.as_deref(),
+            _ => None,
+        }
     }
 
     pub fn invalid_number(constraint: NumberConstraint) -> Self {
@@ -113,8 +314,43 @@ impl ValidationError {
         Self::UnrecognizedKeys { keys }
     }
 
-    pub fn invalid_union(issues: Vec<ValidationIssue>) -> Self {
-        Self::InvalidUnion { issues }
+    pub fn invalid_union(branches: Vec<Vec<ValidationIssue>>) -> Self {
+        Self::InvalidUnion {
+            branches,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Like [`invalid_union`](Self::invalid_union), naming each variant with its
+    /// label, if it has one. Messages then list the variants by name.
+    pub fn invalid_labeled_union(
+        branches: Vec<Vec<ValidationIssue>>,
+        labels: Vec<Option<String>>,
+    ) -> Self {
+        Self::InvalidUnion { branches, labels }
+    }
+
+    pub fn empty(origin: ValidationOrigin) -> Self {
+        Self::Empty { origin }
+    }
+
+    pub fn not_unique(duplicate_of: usize) -> Self {
+        Self::NotUnique { duplicate_of }
+    }
+
+    pub fn too_few_matches(minimum: usize, found: usize) -> Self {
+        Self::TooFewMatches { minimum, found }
+    }
+
+    pub fn duplicate_set_element(duplicate_of: usize) -> Self {
+        Self::DuplicateSetElement { duplicate_of }
+    }
+
+    pub fn invalid_key(origin: ValidationOrigin, detail: ValidationError) -> Self {
+        Self::InvalidKey {
+            origin,
+            detail: Box::new(detail),
+        }
     }
 
     pub fn custom(message: impl Into<String>) -> Self {
@@ -128,7 +364,11 @@ impl ValidationError {
 pub enum ValidationOrigin {
     String,
     Array,
+    Set,
+    /// The byte length of a string, or of the data it encodes. Displayed as "data".
+    Bytes,
     Number,
+    Object,
 }
 
 impl fmt::Display for ValidationOrigin {
@@ -136,7 +376,10 @@ impl fmt::Display for ValidationOrigin {
         let value = match self {
             ValidationOrigin::String => "string",
             ValidationOrigin::Array => "array",
+            ValidationOrigin::Set => "set",
+            ValidationOrigin::Bytes => "data",
             ValidationOrigin::Number => "number",
+            ValidationOrigin::Object => "object",
         };
 
         write!(f, "{value}")?;
@@ -208,13 +451,77 @@ impl StringFormat {
     }
 }
 
+impl fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            StringFormat::StartsWith => "starts_with",
+            StringFormat::EndsWith => "ends_with",
+            StringFormat::Includes => "includes",
+            StringFormat::Regex => "regex",
+            StringFormat::Custom(format) => format,
+        };
+
+        write!(f, "{value}")?;
+        Ok(())
+    }
+}
+
+// Origins, types and formats serialize as their display names rather than
+// as tagged enums, e.g. `"expected": "string"` and `"format": "email"`.
+#[cfg(feature = "serde")]
+macro_rules! serialize_as_display {
+    ($($ty:ty),*) => {
+        $(
+            impl serde::Serialize for $ty {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+serialize_as_display!(ValidationOrigin, ValidationType, StringFormat);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum NumberConstraint {
     Finite,
     Positive,
     Negative,
     NonNegative,
     NonPositive,
+    MultipleOf(String),
+    /// The number is not `start` plus a whole number of `step`s.
+    Step {
+        start: String,
+        step: String,
+    },
+    /// The number is outside JavaScript's safe integer range, or not an integer.
+    SafeInt,
+    /// The number is too large to be represented without rounding.
+    Exact,
+}
+
+impl NumberConstraint {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Finite => "invalid_number:finite",
+            Self::Positive => "invalid_number:positive",
+            Self::Negative => "invalid_number:negative",
+            Self::NonNegative => "invalid_number:non_negative",
+            Self::NonPositive => "invalid_number:non_positive",
+            Self::MultipleOf(_) => "invalid_number:multiple_of",
+            Self::Step { .. } => "invalid_number:step",
+            Self::SafeInt => "invalid_number:safe_int",
+            Self::Exact => "invalid_number:exact",
+        }
+    }
 }
 
 /// Error type for parsing operations that can fail due to JSON parsing or validation.
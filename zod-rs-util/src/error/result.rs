@@ -1,10 +1,12 @@
-use crate::error::issue::ValidationIssue;
+use crate::error::issue::{PathFormat, ValidationIssue};
 use crate::locales::Locale;
 use crate::ValidationError;
+use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ValidationResult {
     pub issues: Vec<ValidationIssue>,
 }
@@ -31,10 +33,7 @@ impl ValidationResult {
 
     pub fn with_error(error: ValidationError) -> Self {
         Self {
-            issues: vec![ValidationIssue {
-                path: Vec::new(),
-                error,
-            }],
+            issues: vec![ValidationIssue::new(Vec::new(), error)],
         }
     }
 
@@ -45,10 +44,7 @@ impl ValidationResult {
     }
 
     pub fn add_error(&mut self, error: ValidationError) {
-        self.issues.push(ValidationIssue {
-            path: Vec::new(),
-            error,
-        });
+        self.issues.push(ValidationIssue::new(Vec::new(), error));
     }
 
     pub fn add_issue(&mut self, issue: ValidationIssue) {
@@ -56,7 +52,7 @@ impl ValidationResult {
     }
 
     pub fn add_error_at_path(&mut self, path: Vec<String>, error: ValidationError) {
-        self.issues.push(ValidationIssue { path, error });
+        self.issues.push(ValidationIssue::new(path, error));
     }
 
     pub fn merge(&mut self, other: ValidationResult) {
@@ -67,6 +63,11 @@ impl ValidationResult {
         self.issues.is_empty()
     }
 
+    /// Iterates over the issues, in the order they were reported.
+    pub fn iter(&self) -> std::slice::Iter<'_, ValidationIssue> {
+        self.issues.iter()
+    }
+
     pub fn len(&self) -> usize {
         self.issues.len()
     }
@@ -77,6 +78,16 @@ impl ValidationResult {
         }
     }
 
+    /// Stamps every issue that has no locale yet with `locale`, which `Display`
+    /// then uses instead of [`default_locale`](crate::default_locale). Issues
+    /// already stamped, e.g. by a nested schema, keep their locale.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        for issue in &mut self.issues {
+            issue.locale.get_or_insert(locale);
+        }
+        self
+    }
+
     /// Converts this ValidationResult into a Result, returning Ok(()) if there are no errors,
     /// or Err(self) if there are validation errors.
     ///
@@ -111,14 +122,109 @@ impl ValidationResult {
     }
 
     pub fn local(&self, lang: Locale) -> String {
+        self.local_with_path(lang, PathFormat::Dotted)
+    }
+
+    /// Like [`local`](Self::local), with paths rendered in `format`.
+    ///
+    /// ```
+    /// use zod_rs_util::{Locale, PathFormat, ValidationError, ValidationResult};
+    ///
+    /// let mut result = ValidationResult::new();
+    /// result.add_error_at_path(
+    ///     vec!["items".into(), "1".into(), "a/b".into()],
+    ///     ValidationError::required(),
+    /// );
+    /// assert_eq!(
+    ///     result.local_with_path(Locale::En, PathFormat::JsonPointer),
+    ///     "/items/1/a~1b: Value is required but was not provided"
+    /// );
+    /// ```
+    pub fn local_with_path(&self, lang: Locale, format: PathFormat) -> String {
+        let issues = self
+            .issues
+            .iter()
+            .map(|issue| issue.local_with_path(lang, format))
+            .collect::<Vec<_>>();
+
+        issues.join("\n")
+    }
+
+    /// Like [`local`](Self::local), but lists the issues of every union variant instead
+    /// of only the one-line summary.
+    pub fn local_verbose(&self, lang: Locale) -> String {
         let issues = self
             .issues
             .iter()
-            .map(|issue| issue.local(lang))
+            .map(|issue| issue.local_verbose(lang))
             .collect::<Vec<_>>();
 
         issues.join("\n")
     }
+
+    /// Returns the first issue, if any. Objects report their fields in no fixed
+    /// order, so with several failing fields this is not necessarily the first
+    /// field of the input.
+    pub fn first_error(&self) -> Option<&ValidationIssue> {
+        self.issues.first()
+    }
+
+    /// Returns the issues reported exactly at `path`, e.g. `&["email"]` for a
+    /// field or `&["items", "1", "name"]` for a field of the second array element.
+    /// Array indexes are given as strings, as they appear in
+    /// [`ValidationIssue::path`]. Issues nested deeper under `path` are not
+    /// included; `&[]` selects issues on the root value.
+    ///
+    /// ```
+    /// use zod_rs_util::{ValidationError, ValidationResult};
+    ///
+    /// let mut result = ValidationResult::new();
+    /// result.add_error_at_path(
+    ///     vec!["items".into(), "1".into(), "name".into()],
+    ///     ValidationError::required(),
+    /// );
+    /// assert_eq!(result.errors_for_path(&["items", "1", "name"]).len(), 1);
+    /// assert!(result.errors_for_path(&["items", "1"]).is_empty());
+    /// ```
+    pub fn errors_for_path(&self, path: &[&str]) -> Vec<&ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.path == path)
+            .collect()
+    }
+
+    /// Returns the per-variant issues of the union that failed at `path`, e.g. `&[]`
+    /// for a top-level union or `&["payment"]` for a union field. Paths inside the
+    /// branches are relative to the union.
+    pub fn union_branches(&self, path: &[&str]) -> Option<&[Vec<ValidationIssue>]> {
+        self.issues.iter().find_map(|issue| match &issue.error {
+            ValidationError::InvalidUnion { branches, .. } if issue.path == path => {
+                Some(branches.as_slice())
+            }
+            _ => None,
+        })
+    }
+
+    /// Groups localized messages by their dotted path, e.g.
+    /// `{"user.email": ["Invalid email address"]}`.
+    ///
+    /// Issues with an empty path (such as unrecognized keys on the root object)
+    /// are collected under the `"_root"` key.
+    pub fn flatten(&self, lang: Locale) -> HashMap<String, Vec<String>> {
+        let mut fields: HashMap<String, Vec<String>> = HashMap::new();
+
+        for issue in &self.issues {
+            let key = if issue.path.is_empty() {
+                "_root".to_string()
+            } else {
+                issue.path.join(".")
+            };
+
+            fields.entry(key).or_default().push(issue.error.local(lang));
+        }
+
+        fields
+    }
 }
 
 impl Default for ValidationResult {
@@ -127,6 +233,24 @@ impl Default for ValidationResult {
     }
 }
 
+impl IntoIterator for ValidationResult {
+    type Item = ValidationIssue;
+    type IntoIter = std::vec::IntoIter<ValidationIssue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationResult {
+    type Item = &'a ValidationIssue;
+    type IntoIter = std::slice::Iter<'a, ValidationIssue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.iter()
+    }
+}
+
 impl From<ValidationError> for ValidationResult {
     fn from(error: ValidationError) -> Self {
         Self::with_error(error)
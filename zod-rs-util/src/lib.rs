@@ -2,7 +2,7 @@ mod error;
 mod locales;
 
 pub use error::{
-    issue::ValidationIssue,
+    issue::{PathFormat, ValidationIssue},
     result::{ValidateResult, ValidationResult},
     NumberConstraint, ParseError, StringFormat, ValidationError, ValidationOrigin, ValidationType,
 };
@@ -56,6 +56,7 @@ mod tests {
         let issue = ValidationIssue {
             path: vec!["user".to_string(), "name".to_string()],
             error: ValidationError::required(),
+            locale: None,
         };
 
         assert_eq!(
@@ -100,4 +101,533 @@ mod tests {
         assert!(display.contains("user.email: بريد إلكتروني غير مقبول"));
         assert!(display.contains("user.age: أصغر من اللازم: يفترض لـ number أن يكون >= 15"));
     }
+
+    #[test]
+    fn test_validation_result_display_fr() {
+        let mut result = ValidationResult::new();
+
+        result.add_error_at_path(
+            vec!["user".to_string(), "email".to_string()],
+            ValidationError::invalid_format(StringFormat::custom("email"), None),
+        );
+        result.add_error_at_path(
+            vec!["user".to_string(), "name".to_string()],
+            ValidationError::too_small(ValidationOrigin::String, "3", true),
+        );
+
+        let display = result.local(Locale::Fr);
+
+        assert!(display.contains("user.email: adresse e-mail invalide"));
+        assert!(display.contains("user.name: Trop petit : string doit avoir >= 3 caractères"));
+    }
+
+    fn union_error() -> ValidationError {
+        ValidationError::invalid_union(vec![
+            vec![ValidationIssue {
+                path: vec![],
+                error: ValidationError::invalid_type(ValidationType::String, ValidationType::Null),
+                locale: None,
+            }],
+            vec![
+                ValidationIssue {
+                    path: vec!["name".to_string()],
+                    error: ValidationError::required(),
+                    locale: None,
+                },
+                ValidationIssue {
+                    path: vec!["age".to_string()],
+                    error: ValidationError::required(),
+                    locale: None,
+                },
+            ],
+        ])
+    }
+
+    #[test]
+    fn test_union_summary() {
+        let error = union_error();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid input; none of the 2 options matched"
+        );
+        assert_eq!(
+            error.local(Locale::Ar),
+            "مدخل غير مقبول؛ لم يطابق أي من الخيارات الـ 2"
+        );
+        assert_eq!(
+            error.local(Locale::Fr),
+            "Entrée invalide ; aucune des 2 options ne correspond"
+        );
+    }
+
+    #[test]
+    fn test_union_verbose() {
+        let result = ValidationResult::from(ValidationIssue {
+            path: vec!["contact".to_string()],
+            error: union_error(),
+            locale: None,
+        });
+
+        assert_eq!(
+            result.local_verbose(Locale::En),
+            "contact: Invalid input; none of the 2 options matched:
+  1) Invalid input: expected string, received null
+  2) name: Value is required but was not provided
+     age: Value is required but was not provided"
+        );
+
+        // Without unions, verbose rendering is the same as `local`
+        let result = ValidationResult::with_error(ValidationError::required());
+        assert_eq!(result.local_verbose(Locale::En), result.local(Locale::En));
+    }
+
+    #[test]
+    fn test_nested_union_verbose() {
+        let error = ValidationError::invalid_union(vec![vec![ValidationIssue {
+            path: vec![],
+            error: ValidationError::invalid_union(vec![vec![ValidationIssue {
+                path: vec![],
+                error: ValidationError::required(),
+                locale: None,
+            }]]),
+            locale: None,
+        }]]);
+
+        assert_eq!(
+            error.local_verbose(Locale::En),
+            "Invalid input; none of the 1 options matched:
+  1) Invalid input; none of the 1 options matched:
+       1) Value is required but was not provided"
+        );
+    }
+
+    #[test]
+    fn test_step_messages() {
+        let error = ValidationError::invalid_number(NumberConstraint::Step {
+            start: "9".into(),
+            step: "0.25".into(),
+        });
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid number: must be 9 plus a multiple of 0.25"
+        );
+        assert_eq!(
+            error.local(Locale::Fr),
+            "Nombre invalide : doit être 9 plus un multiple de 0.25"
+        );
+        assert!(error.local(Locale::Ar).contains("0.25"));
+    }
+
+    #[test]
+    fn test_invalid_key_messages() {
+        let error = ValidationError::invalid_key(
+            ValidationOrigin::Object,
+            ValidationError::invalid_format(StringFormat::Regex, Some("^[a-z]+$".into()))
+                .with_received("Bad-Key"),
+        );
+
+        assert_eq!(error.code(), "invalid_key");
+        assert_eq!(error.received(), None);
+        assert_eq!(
+            error.to_string(),
+            "Invalid key in object: Invalid value: must match pattern ^[a-z]+$, received \"Bad-Key\""
+        );
+        assert_eq!(
+            error.local(Locale::Fr),
+            "Clé invalide dans object : Chaîne invalide : doit correspondre au modèle ^[a-z]+$, \"Bad-Key\" reçu"
+        );
+        assert!(error.local(Locale::Ar).contains("Bad-Key"));
+    }
+
+    #[test]
+    fn test_iterates_over_issues() {
+        let mut result = ValidationResult::new();
+        result.add_error_at_path(vec!["a".to_string()], ValidationError::required());
+        result.add_error_at_path(vec!["b".to_string()], ValidationError::custom("Taken"));
+
+        let paths: Vec<_> = result.iter().map(|issue| issue.path.join(".")).collect();
+        assert_eq!(paths, ["a", "b"]);
+
+        let mut count = 0;
+        for issue in &result {
+            assert!(!issue.path.is_empty());
+            count += 1;
+        }
+        assert_eq!(count, 2);
+
+        let owned: Vec<ValidationIssue> = result.into_iter().collect();
+        assert_eq!(owned[1].error, ValidationError::custom("Taken"));
+    }
+
+    #[test]
+    fn test_errors_for_path() {
+        let mut result = ValidationResult::new();
+        result.add_error_at_path(vec!["email".to_string()], ValidationError::required());
+        result.add_error_at_path(
+            vec!["tags".to_string(), "0".to_string()],
+            ValidationError::custom("Empty tag"),
+        );
+        result.add_error_at_path(
+            vec!["tags".to_string(), "2".to_string()],
+            ValidationError::custom("Duplicate tag"),
+        );
+        result.add_error_at_path(vec![], ValidationError::unrecognized_keys(vec!["x".into()]));
+
+        assert_eq!(
+            result.first_error().unwrap().error,
+            ValidationError::required()
+        );
+        assert_eq!(result.errors_for_path(&["email"]).len(), 1);
+
+        let tag = result.errors_for_path(&["tags", "2"]);
+        assert_eq!(tag.len(), 1);
+        assert_eq!(tag[0].error.to_string(), "Duplicate tag");
+
+        // Only exact matches: nothing sits at `tags` itself
+        assert!(result.errors_for_path(&["tags"]).is_empty());
+        assert!(result.errors_for_path(&["tags", "1"]).is_empty());
+        assert_eq!(result.errors_for_path(&[]).len(), 1);
+
+        assert!(ValidationResult::new().first_error().is_none());
+    }
+
+    #[test]
+    fn test_union_branches() {
+        let mut result = ValidationResult::new();
+        result.add_error_at_path(vec!["contact".to_string()], union_error());
+
+        let branches = result.union_branches(&["contact"]).unwrap();
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branches[1][0].path, vec!["name"]);
+
+        assert!(result.union_branches(&[]).is_none());
+    }
+
+    #[test]
+    fn test_bytes_origin_sizing() {
+        let error = ValidationError::too_big(ValidationOrigin::Bytes, "1024", true);
+
+        assert_eq!(
+            error.to_string(),
+            "Too big: expected data to have <= 1024 bytes"
+        );
+        assert!(error
+            .local(Locale::Ar)
+            .contains("data أن يحوي <= 1024 بايت"));
+        assert_eq!(
+            error.local(Locale::Fr),
+            "Trop grand : data doit avoir <= 1024 octets"
+        );
+    }
+
+    #[test]
+    fn test_received_value_in_messages() {
+        let error = ValidationError::too_small(ValidationOrigin::String, "3", true);
+        assert_eq!(error.received(), None);
+
+        let error = error.with_received("1");
+        assert_eq!(
+            error.to_string(),
+            "Too small: expected string to have >= 3 characters, received 1"
+        );
+        assert_eq!(
+            error.local(Locale::Fr),
+            "Trop petit : string doit avoir >= 3 caractères, 1 reçu"
+        );
+        assert!(error.local(Locale::Ar).ends_with("، ولكن تم إدخال 1"));
+
+        let error = ValidationError::invalid_format(StringFormat::custom("email"), None)
+            .with_received("say \"hi\"");
+        assert_eq!(
+            error.to_string(),
+            r#"Invalid email address, received "say \"hi\"""#
+        );
+        assert_eq!(error.code(), "invalid_format:email");
+
+        // Only sizes and formats carry a value
+        let error = ValidationError::required().with_received("x");
+        assert_eq!(error.received(), None);
+        assert_eq!(error.to_string(), "Value is required but was not provided");
+    }
+
+    #[test]
+    fn test_validation_result_flatten() {
+        let mut result = ValidationResult::new();
+        result.add_error_at_path(
+            vec!["user".to_string(), "email".to_string()],
+            ValidationError::invalid_format(StringFormat::custom("email"), None),
+        );
+        result.add_error_at_path(
+            vec!["user".to_string(), "email".to_string()],
+            ValidationError::custom("Email is taken"),
+        );
+        result.add_error_at_path(
+            vec!["user".to_string(), "age".to_string()],
+            ValidationError::too_small(ValidationOrigin::Number, "15", true),
+        );
+        result.add_error(ValidationError::unrecognized_keys(vec!["extra".into()]));
+
+        let flat = result.flatten(Locale::En);
+
+        assert_eq!(flat.len(), 3);
+        assert_eq!(
+            flat["user.email"],
+            vec!["Invalid email address", "Email is taken"]
+        );
+        assert_eq!(
+            flat["user.age"],
+            vec!["Too small: expected number to have >= 15"]
+        );
+        assert_eq!(flat["_root"], vec!["Unrecognized key: extra"]);
+    }
+
+    #[test]
+    fn test_validation_result_flatten_localized() {
+        let result = ValidationResult::with_error(ValidationError::required());
+        let flat = result.flatten(Locale::Ar);
+        assert_eq!(flat["_root"], vec!["القيمة مطلوبة ولكن لم يتم تقديمها"]);
+
+        assert!(ValidationResult::new().flatten(Locale::En).is_empty());
+    }
+
+    #[test]
+    fn test_validation_result_with_locale() {
+        let mut result = ValidationResult::with_error(ValidationError::required());
+        result.add_issue(ValidationIssue {
+            path: vec!["name".to_string()],
+            error: ValidationError::required(),
+            locale: Some(Locale::Ar),
+        });
+
+        let result = result.with_locale(Locale::Fr);
+        assert_eq!(result.issues[0].locale, Some(Locale::Fr));
+        // An issue stamped earlier keeps its locale
+        assert_eq!(result.issues[1].locale, Some(Locale::Ar));
+
+        assert_eq!(
+            result.issues[0].to_string(),
+            ValidationError::required().local(Locale::Fr)
+        );
+        assert_eq!(
+            result.issues[1].to_string(),
+            format!("name: {}", ValidationError::required().local(Locale::Ar))
+        );
+        // Explicit localization ignores the stamp
+        assert_eq!(
+            result.local(Locale::En),
+            "Value is required but was not provided\nname: Value is required but was not provided"
+        );
+    }
+
+    #[test]
+    fn test_issue_json_pointer() {
+        let issue = |path: &[&str]| ValidationIssue {
+            path: path.iter().map(|key| key.to_string()).collect(),
+            error: ValidationError::required(),
+            locale: None,
+        };
+
+        assert_eq!(issue(&["user", "email"]).json_pointer(), "/user/email");
+        assert_eq!(issue(&["items", "1"]).json_pointer(), "/items/1");
+        assert_eq!(issue(&["a/b", "m~n"]).json_pointer(), "/a~1b/m~0n");
+        // "~1" in a key must not read back as "/"
+        assert_eq!(issue(&["~1"]).json_pointer(), "/~01");
+        assert_eq!(issue(&["user.email"]).json_pointer(), "/user.email");
+        assert_eq!(issue(&[""]).json_pointer(), "/");
+        assert_eq!(issue(&[]).json_pointer(), "");
+    }
+
+    #[test]
+    fn test_local_with_path_format() {
+        let mut result = ValidationResult::with_error(ValidationError::required());
+        result.add_error_at_path(
+            vec!["user".into(), "email".into()],
+            ValidationError::required(),
+        );
+
+        assert_eq!(
+            result.local_with_path(Locale::En, PathFormat::JsonPointer),
+            "Value is required but was not provided\n/user/email: Value is required but was not provided"
+        );
+        assert_eq!(
+            result.local_with_path(Locale::En, PathFormat::Dotted),
+            result.local(Locale::En)
+        );
+    }
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(ValidationError::required().code(), "required");
+        assert_eq!(
+            ValidationError::too_small(ValidationOrigin::String, "3", true).code(),
+            "too_small"
+        );
+        assert_eq!(
+            ValidationError::invalid_type(ValidationType::String, ValidationType::Null).code(),
+            "invalid_type"
+        );
+        assert_eq!(
+            ValidationError::invalid_format(StringFormat::custom("email"), None).code(),
+            "invalid_format:email"
+        );
+        assert_eq!(
+            ValidationError::invalid_format(StringFormat::StartsWith, Some("a".into())).code(),
+            "invalid_format:starts_with"
+        );
+        assert_eq!(
+            ValidationError::invalid_number(NumberConstraint::MultipleOf("5".into())).code(),
+            "invalid_number:multiple_of"
+        );
+        assert_eq!(
+            ValidationError::invalid_number(NumberConstraint::Step {
+                start: "9".into(),
+                step: "15".into(),
+            })
+            .code(),
+            "invalid_number:step"
+        );
+        assert_eq!(
+            ValidationError::invalid_number(NumberConstraint::SafeInt).code(),
+            "invalid_number:safe_int"
+        );
+        assert_eq!(
+            ValidationError::invalid_number(NumberConstraint::Exact).code(),
+            "invalid_number:exact"
+        );
+        assert_eq!(ValidationError::custom("Email is taken").code(), "custom");
+
+        let issue = ValidationIssue {
+            path: vec!["age".to_string()],
+            error: ValidationError::invalid_number(NumberConstraint::Positive),
+            locale: Some(Locale::Ar),
+        };
+        assert_eq!(issue.code(), "invalid_number:positive");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_validation_result_serialize() {
+        let mut result = ValidationResult::new();
+        result.add_error_at_path(
+            vec!["user".to_string(), "age".to_string()],
+            ValidationError::too_small(ValidationOrigin::Number, "15", true),
+        );
+        result.add_error_at_path(
+            vec!["user".to_string(), "name".to_string()],
+            ValidationError::invalid_type(ValidationType::String, ValidationType::Null),
+        );
+
+        let json = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "issues": [
+                    {
+                        "path": ["user", "age"],
+                        "code": "too_small",
+                        "origin": "number",
+                        "minimum": "15",
+                        "inclusive": true
+                    },
+                    {
+                        "path": ["user", "name"],
+                        "code": "invalid_type",
+                        "expected": "string",
+                        "received": "null"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_validation_error_codes() {
+        let code = |error: ValidationError| serde_json::to_value(error).unwrap()["code"].clone();
+
+        assert_eq!(code(ValidationError::required()), "required");
+        assert_eq!(
+            code(ValidationError::invalid_format(
+                StringFormat::custom("email"),
+                None
+            )),
+            "invalid_format"
+        );
+        assert_eq!(
+            code(ValidationError::unrecognized_keys(vec!["x".into()])),
+            "unrecognized_keys"
+        );
+        assert_eq!(code(ValidationError::custom("nope")), "custom");
+
+        // `code()` refines the serialized code without contradicting it
+        for error in [
+            ValidationError::invalid_format(StringFormat::Regex, None),
+            ValidationError::invalid_number(NumberConstraint::NonNegative),
+            ValidationError::duplicate_set_element(0),
+            ValidationError::too_few_matches(1, 0),
+            ValidationError::invalid_key(ValidationOrigin::Object, ValidationError::required()),
+        ] {
+            let full = error.code();
+            let base = full.split(':').next().unwrap().to_string();
+            assert_eq!(code(error), base.as_str());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_nested_error_fields_serialize() {
+        let error = ValidationError::invalid_format(StringFormat::custom("email"), None);
+        assert_eq!(
+            serde_json::to_value(error).unwrap(),
+            serde_json::json!({"code": "invalid_format", "format": "email", "detail": null})
+        );
+
+        let error = ValidationError::too_big(ValidationOrigin::Number, "100", true);
+        assert_eq!(
+            serde_json::to_value(error.clone()).unwrap(),
+            serde_json::json!({"code": "too_big", "origin": "number", "maximum": "100", "inclusive": true})
+        );
+        assert_eq!(
+            serde_json::to_value(error.with_received("150")).unwrap()["received"],
+            "150"
+        );
+
+        let error = ValidationError::invalid_number(NumberConstraint::MultipleOf("5".into()));
+        assert_eq!(
+            serde_json::to_value(error).unwrap(),
+            serde_json::json!({"code": "invalid_number", "constraint": {"multiple_of": "5"}})
+        );
+
+        let error = ValidationError::invalid_union(vec![
+            vec![ValidationIssue {
+                path: vec![],
+                error: ValidationError::required(),
+                locale: None,
+            }],
+            vec![],
+        ]);
+        assert_eq!(
+            serde_json::to_value(error).unwrap(),
+            serde_json::json!({
+                "code": "invalid_union",
+                "branches": [[{"path": [], "code": "required"}], []]
+            })
+        );
+
+        let error = ValidationError::invalid_labeled_union(
+            vec![vec![], vec![]],
+            vec![Some("Id".into()), None],
+        );
+        assert_eq!(
+            serde_json::to_value(error).unwrap(),
+            serde_json::json!({
+                "code": "invalid_union",
+                "branches": [[], []],
+                "labels": ["Id", null]
+            })
+        );
+    }
 }
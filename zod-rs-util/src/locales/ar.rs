@@ -2,7 +2,7 @@ use std::{collections::HashMap, sync::LazyLock};
 
 use crate::{
     error::{NumberConstraint, StringFormat, ValidationError, ValidationOrigin},
-    locales::{Localizer, Sizable},
+    locales::{rendered_received, union_labels, Localizer, Sizable},
 };
 
 static NOUNS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
@@ -46,6 +46,9 @@ static SIZABLES: LazyLock<HashMap<ValidationOrigin, Sizable>> = LazyLock::new(||
     HashMap::from([
         (ValidationOrigin::String, Sizable::new("حرف", "أن يحوي")),
         (ValidationOrigin::Array, Sizable::new("عنصر", "أن يحوي")),
+        (ValidationOrigin::Set, Sizable::new("عنصر", "أن يحوي")),
+        (ValidationOrigin::Bytes, Sizable::new("بايت", "أن يحوي")),
+        (ValidationOrigin::Object, Sizable::new("مدخل", "أن يحوي")),
     ])
 });
 
@@ -58,7 +61,7 @@ pub struct Ar;
 
 impl Localizer for Ar {
     fn localize(&self, error: &ValidationError) -> String {
-        match error {
+        let message = match error {
             ValidationError::InvalidType { expected, input } => {
                 format!("مدخلات غير مقبولة: يفترض إدخال {expected}، ولكن تم إدخال {input}")
             }
@@ -75,35 +78,37 @@ impl Localizer for Ar {
                 origin,
                 maximum,
                 inclusive,
+                ..
             } => {
                 let adj = if *inclusive { "<=" } else { "<" };
 
                 if let Some(sizing) = get_sizable(origin) {
-                    return format!(
+                    format!(
                         " أكبر من اللازم: يفترض {} {} {} {} {}",
                         origin, sizing.verb, adj, maximum, sizing.unit
-                    );
+                    )
+                } else {
+                    format!("أكبر من اللازم: يفترض أن تكون {origin} {adj} {maximum}")
                 }
-
-                format!("أكبر من اللازم: يفترض أن تكون {origin} {adj} {maximum}")
             }
             ValidationError::TooSmall {
                 origin,
                 minimum,
                 inclusive,
+                ..
             } => {
                 let adj = if *inclusive { ">=" } else { ">" };
 
                 if let Some(sizing) = get_sizable(origin) {
-                    return format!(
+                    format!(
                         "أصغر من اللازم: يفترض لـ {} {} {} {} {}",
                         origin, sizing.verb, adj, minimum, sizing.unit
-                    );
+                    )
+                } else {
+                    format!("أصغر من اللازم: يفترض لـ {origin} أن يكون {adj} {minimum}")
                 }
-
-                format!("أصغر من اللازم: يفترض لـ {origin} أن يكون {adj} {minimum}")
             }
-            ValidationError::InvalidFormat { format, detail } => match format {
+            ValidationError::InvalidFormat { format, detail, .. } => match format {
                 StringFormat::StartsWith => format!(
                     "نَص غير مقبول: يجب أن يبدأ بـ \"{}\"",
                     detail.clone().unwrap_or_default()
@@ -123,7 +128,10 @@ impl Localizer for Ar {
                 StringFormat::Custom(format) => {
                     let format = get_noun(format);
 
-                    format!("{format} غير مقبول")
+                    match detail {
+                        Some(detail) => format!("{format} غير مقبول: {detail}"),
+                        None => format!("{format} غير مقبول"),
+                    }
                 }
             },
             ValidationError::InvalidNumber { constraint } => match constraint {
@@ -132,6 +140,14 @@ impl Localizer for Ar {
                 NumberConstraint::Negative => "رقم غير صالح: يجب أن يكون سالبًا".into(),
                 NumberConstraint::NonNegative => "رقم غير صالح: يجب ألا يكون سالبًا".into(),
                 NumberConstraint::NonPositive => "رقم غير صالح: يجب ألا يكون موجبًا".into(),
+                NumberConstraint::MultipleOf(divisor) => {
+                    format!("رقم غير صالح: يجب أن يكون من مضاعفات {divisor}")
+                }
+                NumberConstraint::Step { start, step } => {
+                    format!("رقم غير صالح: يجب أن يكون {start} مضافًا إليه أحد مضاعفات {step}")
+                }
+                NumberConstraint::SafeInt => "رقم غير صالح: يجب أن يكون عددًا صحيحًا آمنًا".into(),
+                NumberConstraint::Exact => "رقم غير صالح: لا يمكن تمثيله بدقة".into(),
             },
             ValidationError::UnrecognizedKeys { keys } => {
                 format!(
@@ -141,9 +157,38 @@ impl Localizer for Ar {
                     keys.join("، ")
                 )
             }
-            ValidationError::InvalidUnion { .. } => "مدخل غير مقبول".into(),
+            ValidationError::InvalidUnion { branches, labels } => match union_labels(labels) {
+                Some(names) => format!("مدخل غير مقبول؛ لم يطابق أيًا من: {}", names.join("، ")),
+                None => format!(
+                    "مدخل غير مقبول؛ لم يطابق أي من الخيارات الـ {}",
+                    branches.len()
+                ),
+            },
+            ValidationError::Empty { origin } => {
+                format!("أصغر من اللازم: يفترض لـ {origin} ألا يكون فارغًا")
+            }
+            ValidationError::NotUnique { duplicate_of } => {
+                format!("عنصر مكرر: مطابق للعنصر في الموضع {duplicate_of}")
+            }
+            ValidationError::TooFewMatches { minimum: 1, .. } => {
+                "مصفوفة غير مقبولة: يجب أن تحتوي على عنصر مطابق".into()
+            }
+            ValidationError::TooFewMatches { minimum, found } => {
+                format!("مصفوفة غير مقبولة: يجب أن تحتوي على {minimum} عناصر مطابقة على الأقل، وُجد {found}")
+            }
+            ValidationError::DuplicateSetElement { duplicate_of } => {
+                format!("عنصر مكرر في المجموعة: مطابق للعنصر في الموضع {duplicate_of}")
+            }
+            ValidationError::InvalidKey { origin, detail } => {
+                format!("مفتاح غير مقبول في {origin}: {}", self.localize(detail))
+            }
             ValidationError::Required => "القيمة مطلوبة ولكن لم يتم تقديمها".into(),
             ValidationError::Custom { message } => message.into(),
+        };
+
+        match rendered_received(error) {
+            Some(received) => format!("{message}، ولكن تم إدخال {received}"),
+            None => message,
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     error::{NumberConstraint, StringFormat, ValidationError, ValidationOrigin},
-    locales::{Localizer, Sizable},
+    locales::{rendered_received, union_labels, Localizer, Sizable},
 };
 use std::{collections::HashMap, sync::LazyLock};
 
@@ -48,6 +48,9 @@ static SIZABLES: LazyLock<HashMap<ValidationOrigin, Sizable>> = LazyLock::new(||
             Sizable::new("characters", "to have"),
         ),
         (ValidationOrigin::Array, Sizable::new("items", "to have")),
+        (ValidationOrigin::Set, Sizable::new("elements", "to have")),
+        (ValidationOrigin::Bytes, Sizable::new("bytes", "to have")),
+        (ValidationOrigin::Object, Sizable::new("entries", "to have")),
     ])
 });
 
@@ -60,7 +63,7 @@ pub struct En;
 
 impl Localizer for En {
     fn localize(&self, error: &ValidationError) -> String {
-        match error {
+        let message = match error {
             ValidationError::InvalidType { expected, input } => {
                 format!("Invalid input: expected {expected}, received {input}")
             }
@@ -74,35 +77,37 @@ impl Localizer for En {
                 origin,
                 maximum,
                 inclusive,
+                ..
             } => {
                 let adj = if *inclusive { "<=" } else { "<" };
 
                 if let Some(sizing) = get_sizable(origin) {
-                    return format!(
+                    format!(
                         "Too big: expected {} {} {} {} {}",
                         origin, sizing.verb, adj, maximum, sizing.unit
-                    );
+                    )
+                } else {
+                    format!("Too big: expected {origin} to have {adj} {maximum}")
                 }
-
-                format!("Too big: expected {origin} to have {adj} {maximum}")
             }
             ValidationError::TooSmall {
                 origin,
                 minimum,
                 inclusive,
+                ..
             } => {
                 let adj = if *inclusive { ">=" } else { ">" };
 
                 if let Some(sizing) = get_sizable(origin) {
-                    return format!(
+                    format!(
                         "Too small: expected {} {} {} {} {}",
                         origin, sizing.verb, adj, minimum, sizing.unit
-                    );
+                    )
+                } else {
+                    format!("Too small: expected {origin} to have {adj} {minimum}")
                 }
-
-                format!("Too small: expected {origin} to have {adj} {minimum}")
             }
-            ValidationError::InvalidFormat { format, detail } => match format {
+            ValidationError::InvalidFormat { format, detail, .. } => match format {
                 StringFormat::StartsWith => format!(
                     "Invalid value: must start with \"{}\"",
                     detail.clone().unwrap_or_default()
@@ -122,7 +127,10 @@ impl Localizer for En {
                 StringFormat::Custom(format) => {
                     let format = get_noun(format);
 
-                    format!("Invalid {format}")
+                    match detail {
+                        Some(detail) => format!("Invalid {format}: {detail}"),
+                        None => format!("Invalid {format}"),
+                    }
                 }
             },
             ValidationError::InvalidNumber { constraint } => match constraint {
@@ -131,6 +139,14 @@ impl Localizer for En {
                 NumberConstraint::Negative => "Invalid number: must be negative".into(),
                 NumberConstraint::NonNegative => "Invalid number: must be non-negative".into(),
                 NumberConstraint::NonPositive => "Invalid number: must be non-positive".into(),
+                NumberConstraint::MultipleOf(divisor) => {
+                    format!("Invalid number: must be a multiple of {divisor}")
+                }
+                NumberConstraint::Step { start, step } => {
+                    format!("Invalid number: must be {start} plus a multiple of {step}")
+                }
+                NumberConstraint::SafeInt => "Invalid number: must be a safe integer".into(),
+                NumberConstraint::Exact => "Invalid number: cannot be represented exactly".into(),
             },
             ValidationError::UnrecognizedKeys { keys } => {
                 format!(
@@ -139,9 +155,40 @@ impl Localizer for En {
                     keys.join(", ")
                 )
             }
-            ValidationError::InvalidUnion { .. } => "Invalid input".into(),
+            ValidationError::InvalidUnion { branches, labels } => match union_labels(labels) {
+                Some(names) => format!("Invalid input; did not match any of: {}", names.join(", ")),
+                None => format!(
+                    "Invalid input; none of the {} options matched",
+                    branches.len()
+                ),
+            },
+            ValidationError::Empty { origin } => {
+                format!("Too small: expected {origin} to be non-empty")
+            }
+            ValidationError::NotUnique { duplicate_of } => {
+                format!("Duplicate item: same as item at index {duplicate_of}")
+            }
+            ValidationError::TooFewMatches { minimum: 1, .. } => {
+                "Invalid array: must contain a matching item".into()
+            }
+            ValidationError::TooFewMatches { minimum, found } => {
+                format!(
+                    "Invalid array: must contain at least {minimum} matching items, found {found}"
+                )
+            }
+            ValidationError::DuplicateSetElement { duplicate_of } => {
+                format!("Duplicate set element: same as element at index {duplicate_of}")
+            }
+            ValidationError::InvalidKey { origin, detail } => {
+                format!("Invalid key in {origin}: {}", self.localize(detail))
+            }
             ValidationError::Required => "Value is required but was not provided".into(),
             ValidationError::Custom { message } => message.into(),
+        };
+
+        match rendered_received(error) {
+            Some(received) => format!("{message}, received {received}"),
+            None => message,
         }
     }
 }
@@ -0,0 +1,203 @@
+use crate::{
+    error::{NumberConstraint, StringFormat, ValidationError, ValidationOrigin},
+    locales::{rendered_received, union_labels, Localizer, Sizable},
+};
+use std::{collections::HashMap, sync::LazyLock};
+
+static NOUNS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    HashMap::from([
+        ("regex", "entrée"),
+        ("email", "adresse e-mail"),
+        ("url", "URL"),
+        ("emoji", "emoji"),
+        ("uuid", "UUID"),
+        ("uuidv4", "UUIDv4"),
+        ("uuidv6", "UUIDv6"),
+        ("nanoid", "nanoid"),
+        ("guid", "GUID"),
+        ("cuid", "cuid"),
+        ("cuid2", "cuid2"),
+        ("ulid", "ULID"),
+        ("xid", "XID"),
+        ("ksuid", "KSUID"),
+        ("datetime", "date et heure ISO"),
+        ("date", "date ISO"),
+        ("time", "heure ISO"),
+        ("duration", "durée ISO"),
+        ("ipv4", "adresse IPv4"),
+        ("ipv6", "adresse IPv6"),
+        ("cidrv4", "plage IPv4"),
+        ("cidrv6", "plage IPv6"),
+        ("base64", "chaîne encodée en base64"),
+        ("base64url", "chaîne encodée en base64url"),
+        ("json_string", "chaîne JSON"),
+        ("e164", "numéro E.164"),
+        ("jwt", "JWT"),
+        ("template_literal", "entrée"),
+    ])
+});
+
+fn get_noun(key: &str) -> &str {
+    NOUNS.get(key).copied().unwrap_or(key)
+}
+
+static SIZABLES: LazyLock<HashMap<ValidationOrigin, Sizable>> = LazyLock::new(|| {
+    HashMap::from([
+        (
+            ValidationOrigin::String,
+            Sizable::new("caractères", "avoir"),
+        ),
+        (ValidationOrigin::Array, Sizable::new("éléments", "avoir")),
+        (ValidationOrigin::Set, Sizable::new("éléments", "avoir")),
+        (ValidationOrigin::Bytes, Sizable::new("octets", "avoir")),
+        (ValidationOrigin::Object, Sizable::new("entrées", "avoir")),
+    ])
+});
+
+fn get_sizable(key: &ValidationOrigin) -> Option<&'static Sizable> {
+    SIZABLES.get(key)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Fr;
+
+impl Localizer for Fr {
+    fn localize(&self, error: &ValidationError) -> String {
+        let message = match error {
+            ValidationError::InvalidType { expected, input } => {
+                format!("Entrée invalide : {expected} attendu, {input} reçu")
+            }
+            ValidationError::InvalidValue { value } => {
+                format!("Entrée invalide : {value} attendu")
+            }
+            ValidationError::InvalidValues { values } => {
+                format!(
+                    "Option invalide : une valeur parmi {} attendue",
+                    values.join(" | ")
+                )
+            }
+            ValidationError::TooBig {
+                origin,
+                maximum,
+                inclusive,
+                ..
+            } => {
+                let adj = if *inclusive { "<=" } else { "<" };
+
+                if let Some(sizing) = get_sizable(origin) {
+                    format!(
+                        "Trop grand : {} doit {} {} {} {}",
+                        origin, sizing.verb, adj, maximum, sizing.unit
+                    )
+                } else {
+                    format!("Trop grand : {origin} doit être {adj} {maximum}")
+                }
+            }
+            ValidationError::TooSmall {
+                origin,
+                minimum,
+                inclusive,
+                ..
+            } => {
+                let adj = if *inclusive { ">=" } else { ">" };
+
+                if let Some(sizing) = get_sizable(origin) {
+                    format!(
+                        "Trop petit : {} doit {} {} {} {}",
+                        origin, sizing.verb, adj, minimum, sizing.unit
+                    )
+                } else {
+                    format!("Trop petit : {origin} doit être {adj} {minimum}")
+                }
+            }
+            ValidationError::InvalidFormat { format, detail, .. } => match format {
+                StringFormat::StartsWith => format!(
+                    "Chaîne invalide : doit commencer par \"{}\"",
+                    detail.clone().unwrap_or_default()
+                ),
+                StringFormat::EndsWith => format!(
+                    "Chaîne invalide : doit se terminer par \"{}\"",
+                    detail.clone().unwrap_or_default()
+                ),
+                StringFormat::Includes => format!(
+                    "Chaîne invalide : doit inclure \"{}\"",
+                    detail.clone().unwrap_or_default()
+                ),
+                StringFormat::Regex => format!(
+                    "Chaîne invalide : doit correspondre au modèle {}",
+                    detail.clone().unwrap_or_default()
+                ),
+                StringFormat::Custom(format) => {
+                    let format = get_noun(format);
+
+                    match detail {
+                        Some(detail) => format!("{format} invalide : {detail}"),
+                        None => format!("{format} invalide"),
+                    }
+                }
+            },
+            ValidationError::InvalidNumber { constraint } => match constraint {
+                NumberConstraint::Finite => "Nombre invalide : doit être fini".into(),
+                NumberConstraint::Positive => "Nombre invalide : doit être positif".into(),
+                NumberConstraint::Negative => "Nombre invalide : doit être négatif".into(),
+                NumberConstraint::NonNegative => {
+                    "Nombre invalide : doit être positif ou nul".into()
+                }
+                NumberConstraint::NonPositive => {
+                    "Nombre invalide : doit être négatif ou nul".into()
+                }
+                NumberConstraint::MultipleOf(divisor) => {
+                    format!("Nombre invalide : doit être un multiple de {divisor}")
+                }
+                NumberConstraint::Step { start, step } => {
+                    format!("Nombre invalide : doit être {start} plus un multiple de {step}")
+                }
+                NumberConstraint::SafeInt => "Nombre invalide : doit être un entier sûr".into(),
+                NumberConstraint::Exact => {
+                    "Nombre invalide : ne peut pas être représenté exactement".into()
+                }
+            },
+            ValidationError::UnrecognizedKeys { keys } => {
+                let plural = if keys.len() > 1 { "s" } else { "" };
+                format!("Clé{plural} non reconnue{plural} : {}", keys.join(", "))
+            }
+            ValidationError::InvalidUnion { branches, labels } => match union_labels(labels) {
+                Some(names) => format!(
+                    "Entrée invalide ; ne correspond à aucune des options : {}",
+                    names.join(", ")
+                ),
+                None => format!(
+                    "Entrée invalide ; aucune des {} options ne correspond",
+                    branches.len()
+                ),
+            },
+            ValidationError::Empty { origin } => {
+                format!("Trop petit : {origin} ne doit pas être vide")
+            }
+            ValidationError::NotUnique { duplicate_of } => {
+                format!("Élément en double : identique à l'élément à l'index {duplicate_of}")
+            }
+            ValidationError::TooFewMatches { minimum: 1, .. } => {
+                "Tableau invalide : doit contenir un élément correspondant".into()
+            }
+            ValidationError::TooFewMatches { minimum, found } => {
+                format!(
+                    "Tableau invalide : doit contenir au moins {minimum} éléments correspondants, {found} trouvé(s)"
+                )
+            }
+            ValidationError::DuplicateSetElement { duplicate_of } => {
+                format!("Élément en double dans l'ensemble : identique à l'élément à l'index {duplicate_of}")
+            }
+            ValidationError::InvalidKey { origin, detail } => {
+                format!("Clé invalide dans {origin} : {}", self.localize(detail))
+            }
+            ValidationError::Required => "Valeur requise mais non fournie".into(),
+            ValidationError::Custom { message } => message.into(),
+        };
+
+        match rendered_received(error) {
+            Some(received) => format!("{message}, {received} reçu"),
+            None => message,
+        }
+    }
+}
@@ -1,25 +1,74 @@
 pub mod ar;
 pub mod en;
+pub mod fr;
 
 use crate::{
-    locales::{ar::Ar, en::En},
+    locales::{ar::Ar, en::En, fr::Fr},
     ValidationError,
 };
+use std::sync::atomic::{AtomicU8, Ordering};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Locale {
     En,
     Ar,
+    Fr,
+}
+
+// Indexed by discriminant, in declaration order
+const LOCALES: [Locale; 3] = [Locale::En, Locale::Ar, Locale::Fr];
+
+static DEFAULT_LOCALE: AtomicU8 = AtomicU8::new(Locale::En as u8);
+
+/// Sets the process-wide locale used by `Display` (and so `to_string()`) on
+/// errors, issues and results that were not stamped with a locale of their own.
+/// Defaults to [`Locale::En`].
+///
+/// Explicit calls such as [`ValidationResult::local`](crate::ValidationResult::local)
+/// are not affected.
+pub fn set_default_locale(locale: Locale) {
+    DEFAULT_LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// Returns the locale set by [`set_default_locale`].
+pub fn default_locale() -> Locale {
+    LOCALES[DEFAULT_LOCALE.load(Ordering::Relaxed) as usize]
 }
 
 pub trait Localizer {
     fn localize(&self, error: &ValidationError) -> String;
 }
 
+/// The value attached to an error, as appended to its message: strings are
+/// quoted, numbers and sizes are not.
+fn rendered_received(error: &ValidationError) -> Option<String> {
+    let received = error.received()?;
+    Some(match error {
+        ValidationError::InvalidFormat { .. } => format!("{received:?}"),
+        _ => received.to_string(),
+    })
+}
+
+/// The names of a union's variants when at least one is labeled, with `#n` standing
+/// in for unlabeled ones.
+pub(crate) fn union_labels(labels: &[Option<String>]) -> Option<Vec<String>> {
+    if labels.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let names = labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| label.clone().unwrap_or_else(|| format!("#{}", index + 1)))
+        .collect();
+    Some(names)
+}
+
 pub fn localizer(locale: Locale) -> Box<dyn Localizer> {
     match locale {
         Locale::En => Box::new(En),
         Locale::Ar => Box::new(Ar),
+        Locale::Fr => Box::new(Fr),
     }
 }
 
@@ -0,0 +1,154 @@
+use crate::schema::HasSchema;
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use zod_rs_util::ValidationResult;
+
+/// JSON body extractor that validates the body against `T::schema()` before
+/// deserializing it.
+///
+/// ```no_run
+/// use axum::{routing::post, Router};
+/// use serde::Deserialize;
+/// use zod_rs::prelude::*;
+///
+/// #[derive(Deserialize, ZodSchema)]
+/// struct CreateUser {
+///     #[zod(min_length(2))]
+///     name: String,
+/// }
+///
+/// async fn create_user(ZodJson(user): ZodJson<CreateUser>) -> String {
+///     user.name
+/// }
+///
+/// let app: Router = Router::new().route("/users", post(create_user));
+/// ```
+///
+/// A body that fails validation is rejected with `400 Bad Request` and the
+/// serialized [`ValidationResult`] as JSON.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZodJson<T>(pub T);
+
+/// Rejection used by [`ZodJson`].
+#[derive(Debug)]
+pub enum ZodJsonRejection {
+    /// The body is not JSON; responds like axum's own `Json` extractor.
+    Json(JsonRejection),
+    /// The body does not match the schema; responds with `400 Bad Request`.
+    Validation(ValidationResult),
+}
+
+impl IntoResponse for ZodJsonRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Json(rejection) => rejection.into_response(),
+            Self::Validation(errors) => (StatusCode::BAD_REQUEST, Json(errors)).into_response(),
+        }
+    }
+}
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ZodJson<T>
+where
+    T: HasSchema + DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ZodJsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<Value>::from_request(req, state)
+            .await
+            .map_err(ZodJsonRejection::Json)?;
+
+        T::validate_and_parse(&value)
+            .map(ZodJson)
+            .map_err(ZodJsonRejection::Validation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string, Schema};
+    use axum::body::{to_bytes, Body};
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Signup {
+        name: String,
+        age: u8,
+    }
+
+    impl HasSchema for Signup {
+        fn schema() -> impl Schema<Value> + Send + Sync {
+            object()
+                .field("name", string().min(2))
+                .field("age", number().int().min(18.0))
+        }
+    }
+
+    fn json_request(body: &str) -> Request {
+        Request::builder()
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn extract(body: &str) -> Result<ZodJson<Signup>, ZodJsonRejection> {
+        ZodJson::<Signup>::from_request(json_request(body), &()).await
+    }
+
+    #[tokio::test]
+    async fn test_valid_body_is_deserialized() {
+        let ZodJson(signup) = extract(r#"{"name": "Ada", "age": 36}"#).await.unwrap();
+        assert_eq!(signup.name, "Ada");
+        assert_eq!(signup.age, 36);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_body_responds_with_issues() {
+        let rejection = extract(r#"{"name": "A", "age": 12}"#).await.unwrap_err();
+        let ZodJsonRejection::Validation(errors) = &rejection else {
+            panic!("expected a validation rejection, got {rejection:?}");
+        };
+        assert_eq!(errors.issues.len(), 2);
+
+        let response = rejection.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["issues"].as_array().unwrap().len(), 2);
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[tokio::test]
+    async fn test_malformed_json_is_rejected_like_json() {
+        let rejection = extract("{not json").await.unwrap_err();
+        assert!(matches!(rejection, ZodJsonRejection::Json(_)));
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_missing_content_type_is_rejected() {
+        let request = Request::builder()
+            .method("POST")
+            .body(Body::from("{}"))
+            .unwrap();
+        let rejection = ZodJson::<Signup>::from_request(request, &())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            rejection.into_response().status(),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+}
@@ -1,16 +1,196 @@
+#[cfg(feature = "axum")]
+mod extract;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 mod schema;
+#[cfg(feature = "stream")]
+pub mod stream;
 
+#[cfg(feature = "axum")]
+pub use extract::{ZodJson, ZodJsonRejection};
 pub use schema::*;
-pub use zod_rs_util::Locale;
+pub use zod_rs_util::{default_locale, set_default_locale, Locale, PathFormat};
+
+#[doc(hidden)]
+pub mod __private {
+    //! Support code for the derive macros; not part of the public API.
+
+    use crate::schema::{
+        deserialize_validated, into_json_value, HasSchema, Schema, ValidateOptions,
+    };
+    use serde_json::{Number, Value};
+    use std::fmt::{self, Debug};
+    use zod_rs_util::{ValidateResult, ValidationError};
+
+    /// Adapts a schema with a typed output to `Schema<Value>`, as required by
+    /// the `schema()` of derived newtype structs.
+    #[derive(Debug, Clone)]
+    pub struct JsonOutput<S, T> {
+        inner: S,
+        _phantom: std::marker::PhantomData<T>,
+    }
+
+    pub fn json_output<S, T>(inner: S) -> JsonOutput<S, T> {
+        JsonOutput {
+            inner,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    impl<S, T> Schema<Value> for JsonOutput<S, T>
+    where
+        S: Schema<T>,
+        T: serde::Serialize + Debug + 'static,
+    {
+        fn validate(&self, value: &Value) -> ValidateResult<Value> {
+            self.validate_with(value, ValidateOptions::default())
+        }
+
+        fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+            into_json_value(self.inner.validate_with(value, opts)?)
+        }
+
+        fn to_json_schema(&self) -> Value {
+            self.inner.to_json_schema()
+        }
+    }
+
+    /// Runs a user function on the validated value, as emitted for
+    /// `#[zod(custom = "path::to::check")]`. The value is deserialized into the
+    /// field's type `V` first, so that e.g. a `u32` field gets a `&u32` rather than
+    /// the `f64` the number schema produces. `Err(message)` becomes a custom issue.
+    pub struct Custom<S, T, V, F> {
+        inner: S,
+        check: F,
+        _phantom: std::marker::PhantomData<fn() -> (T, V)>,
+    }
+
+    pub fn custom<V, S, T, F>(inner: S, check: F) -> Custom<S, T, V, F> {
+        Custom {
+            inner,
+            check,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    impl<S: Debug, T, V, F> Debug for Custom<S, T, V, F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Custom")
+                .field("inner", &self.inner)
+                .finish()
+        }
+    }
+
+    impl<S, T, V, F> Schema<T> for Custom<S, T, V, F>
+    where
+        S: Schema<T>,
+        T: serde::Serialize + Debug,
+        V: serde::de::DeserializeOwned,
+        F: Fn(&V) -> Result<(), String>,
+    {
+        fn validate(&self, value: &Value) -> ValidateResult<T> {
+            self.validate_with(value, ValidateOptions::default())
+        }
+
+        fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+            let validated = self.inner.validate_with(value, opts)?;
+            let json = serde_json::to_value(&validated).map_err(|e| {
+                ValidationError::custom(format!("Failed to serialize validated value: {}", e))
+            })?;
+            let typed: V = deserialize_validated(json, value)?;
+
+            (self.check)(&typed).map_err(ValidationError::custom)?;
+            Ok(validated)
+        }
+
+        fn to_json_schema(&self) -> Value {
+            self.inner.to_json_schema()
+        }
+    }
+
+    /// Validates against `T::schema()`, reported under `T::schema_name()` when the
+    /// `metrics` feature is enabled.
+    #[inline]
+    pub fn validate_top_level<T: HasSchema + ?Sized>(value: &Value) -> ValidateResult<Value> {
+        #[cfg(feature = "metrics")]
+        return T::schema().validate_instrumented(T::schema_name(), value);
+        #[cfg(not(feature = "metrics"))]
+        T::schema().validate(value)
+    }
+
+    /// Gives the numbers in `validated` a representation serde can deserialize
+    /// into integer fields.
+    ///
+    /// `NumberSchema` produces `f64`, so an input of `1` is validated as `1.0`,
+    /// which serde refuses to deserialize into integer fields. A number equal to
+    /// the one at the same place in `original` takes its representation, which
+    /// keeps integers beyond 2^53 exact. Any other integral number, such as a
+    /// default, a fallback or a coerced string, becomes an integer.
+    pub fn restore_number_repr(validated: Value, original: &Value) -> Value {
+        match validated {
+            Value::Number(n) => match original {
+                Value::Number(o) if n.as_f64() == o.as_f64() => Value::Number(o.clone()),
+                _ => Value::Number(integral_number(n)),
+            },
+            Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        restore_number_repr(item, original.get(i).unwrap_or(&Value::Null))
+                    })
+                    .collect(),
+            ),
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, item)| {
+                        let item =
+                            restore_number_repr(item, original.get(&key).unwrap_or(&Value::Null));
+                        (key, item)
+                    })
+                    .collect(),
+            ),
+            validated => validated,
+        }
+    }
+
+    /// Converts a float with no fractional part to an integer `Number` when it
+    /// fits in `i64` or `u64`.
+    fn integral_number(n: Number) -> Number {
+        const I64_BOUND: f64 = 9_223_372_036_854_775_808.0; // 2^63
+        const U64_BOUND: f64 = 18_446_744_073_709_551_616.0; // 2^64
+
+        match n.as_f64() {
+            Some(f) if n.is_f64() && f.fract() == 0.0 => {
+                if (-I64_BOUND..I64_BOUND).contains(&f) {
+                    Number::from(f as i64)
+                } else if (0.0..U64_BOUND).contains(&f) {
+                    Number::from(f as u64)
+                } else {
+                    n
+                }
+            }
+            _ => n,
+        }
+    }
+}
 
 pub mod prelude {
+    #[cfg(feature = "axum")]
+    pub use crate::extract::ZodJson;
     pub use crate::schema::{
-        array, boolean, literal, null, number, object, optional, string, tuple, union,
-        ArraySchema, BooleanSchema, LiteralSchema, NullSchema, NumberSchema, ObjectSchema,
-        OptionalSchema, Schema, StringSchema, TupleSchema, UnionSchema,
+        any, array, big_int, boolean, discriminated_union, enum_schema, enum_values, intersection,
+        lazy, literal, null, nullable, nullish, number, object, optional, record, set,
+        set_default_object_mode, string, tuple, union, unknown, AnySchema, ArraySchema,
+        BigIntSchema, BooleanSchema, CatchSchema, DescribedSchema, DiscriminatedUnionSchema,
+        EmailMode, EnumSchema, EnumValuesSchema, HasSchema, IntersectionSchema, LabeledSchema,
+        LazySchema, LiteralSchema, NullSchema, NullableSchema, NullishSchema, NumberSchema,
+        ObjectMode, ObjectSchema, OptionalSchema, PipeSchema, RecordSchema, RefinedSchema,
+        RegexFlags, Schema, SetSchema, StringSchema, TransformSchema, TupleSchema, UnionSchema,
+        UnknownSchema, ValidateOptions,
     };
     pub use serde_json::Value;
     #[cfg(feature = "macros")]
     pub use zod_rs_macros::ZodSchema;
-    pub use zod_rs_util::Locale;
+    pub use zod_rs_util::{set_default_locale, Locale};
 }
@@ -0,0 +1,98 @@
+//! Timing hook for top-level validations, enabled by the `metrics` feature.
+//!
+//! Register a hook with [`set_validation_hook`] and it is called after every
+//! [`validate_instrumented`](crate::Schema::validate_instrumented) call, which
+//! includes `validate_and_parse`, `from_json`, ... of derived types. Without the
+//! feature, none of this is compiled and validation is not timed.
+//!
+//! ```
+//! use zod_rs::metrics::{set_validation_hook, ValidationEvent};
+//!
+//! set_validation_hook(|event: &ValidationEvent| {
+//!     println!(
+//!         "{} took {:?} ({} errors)",
+//!         event.schema, event.duration, event.error_count
+//!     );
+//! });
+//! ```
+
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+type Hook = Arc<dyn Fn(&ValidationEvent) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// One finished top-level validation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ValidationEvent<'a> {
+    /// The name passed to `validate_instrumented`; the type name for derived types.
+    pub schema: &'a str,
+    /// Time spent validating, excluding deserialization into the Rust type.
+    pub duration: Duration,
+    /// Number of issues reported, 0 when validation passed.
+    pub error_count: usize,
+}
+
+/// Registers the hook called after each instrumented validation, replacing any
+/// previous one. The hook runs on the validating thread, so keep it cheap.
+pub fn set_validation_hook(hook: impl Fn(&ValidationEvent) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Removes the hook set by [`set_validation_hook`].
+pub fn clear_validation_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+pub(crate) fn report(event: &ValidationEvent) {
+    // Cloned so that a hook may itself validate, or replace the hook
+    let hook = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        hook(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string, Schema};
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    // The hook is global, so everything that sets it lives in one test
+    #[test]
+    fn test_validation_hook() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        set_validation_hook(move |event| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((event.schema.to_string(), event.error_count));
+        });
+
+        let schema = object()
+            .field("name", string().min(2))
+            .field("age", number());
+        assert!(schema
+            .validate_instrumented("User", &json!({"name": "Al", "age": 3}))
+            .is_ok());
+        assert!(schema
+            .validate_instrumented("User", &json!({"name": "A"}))
+            .is_err());
+        // Plain validation is not reported
+        assert!(schema.validate(&json!({})).is_err());
+
+        clear_validation_hook();
+        assert!(schema.validate_instrumented("User", &json!({})).is_err());
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [("User".to_string(), 0), ("User".to_string(), 2)]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::schema::Schema;
+use serde_json::{json, Value};
+use zod_rs_util::ValidateResult;
+
+/// Accepts any JSON value, including `null`, and returns it unchanged.
+///
+/// Useful for freeform fields such as a `metadata` blob: inside an object the
+/// field must still be present, unless wrapped in [`optional`](crate::optional).
+#[derive(Debug, Clone)]
+pub struct AnySchema;
+
+impl AnySchema {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for AnySchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Schema<Value> for AnySchema {
+    fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        Ok(value.clone())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({})
+    }
+}
+
+pub fn any() -> AnySchema {
+    AnySchema::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::object;
+    use serde_json::json;
+
+    #[test]
+    fn test_any_validation() {
+        let schema = any();
+
+        for value in [
+            json!(null),
+            json!(true),
+            json!(42),
+            json!("text"),
+            json!([1, "two"]),
+            json!({"nested": {"deep": [null]}}),
+        ] {
+            assert_eq!(schema.validate(&value).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_any_field_is_still_required() {
+        let schema = object().field("meta", any());
+
+        assert!(schema.validate(&json!({"meta": [1, 2]})).is_ok());
+        assert!(schema.validate(&json!({"meta": null})).is_ok());
+        assert!(schema.validate(&json!({})).is_err());
+    }
+
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(any().to_json_schema(), json!({}));
+    }
+}
@@ -1,15 +1,55 @@
-use crate::schema::Schema;
-use serde_json::Value;
-use std::fmt::Debug;
+use crate::schema::{messages::CheckMessages, Schema, ValidateOptions};
+use serde_json::{Map, Value};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 use zod_rs_util::{
     ValidateResult, ValidationError, ValidationOrigin, ValidationResult, ValidationType,
 };
 
+type DuplicateFinder<T> = fn(&[T]) -> Option<(usize, usize)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ArrayCheck {
+    MinLength,
+    MaxLength,
+    NonEmpty,
+}
+
+/// The schema of a `contains` check, with its output type erased.
+trait ElementMatcher: Debug + Send + Sync {
+    fn matches(&self, value: &Value) -> bool;
+    fn json_schema(&self) -> Value;
+}
+
+#[derive(Debug)]
+struct Matcher<S, U> {
+    schema: S,
+    _phantom: std::marker::PhantomData<fn() -> U>,
+}
+
+impl<S, U> ElementMatcher for Matcher<S, U>
+where
+    S: Schema<U> + Send + Sync,
+    U: Debug,
+{
+    fn matches(&self, value: &Value) -> bool {
+        self.schema.validate(value).is_ok()
+    }
+
+    fn json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ArraySchema<S, T> {
     element_schema: S,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    nonempty: bool,
+    max_errors: Option<usize>,
+    find_duplicate: Option<DuplicateFinder<T>>,
+    contains: Option<(usize, Arc<dyn ElementMatcher>)>,
+    messages: CheckMessages<ArrayCheck>,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -19,31 +59,200 @@ impl<S, T> ArraySchema<S, T> {
             element_schema,
             min_length: None,
             max_length: None,
+            nonempty: false,
+            max_errors: None,
+            find_duplicate: None,
+            contains: None,
+            messages: CheckMessages::new(),
             _phantom: std::marker::PhantomData,
         }
     }
 
     pub fn min(mut self, min: usize) -> Self {
         self.min_length = Some(min);
+        self.messages.track(&[ArrayCheck::MinLength]);
         self
     }
 
     pub fn max(mut self, max: usize) -> Self {
         self.max_length = Some(max);
+        self.messages.track(&[ArrayCheck::MaxLength]);
         self
     }
 
     pub fn length(self, len: usize) -> Self {
-        self.min(len).max(len)
+        let mut schema = self.min(len).max(len);
+        schema
+            .messages
+            .track(&[ArrayCheck::MinLength, ArrayCheck::MaxLength]);
+        schema
+    }
+
+    /// Like `min(1)`, but reports an empty array with a dedicated message.
+    pub fn nonempty(mut self) -> Self {
+        self.nonempty = true;
+        self.messages.track(&[ArrayCheck::NonEmpty]);
+        self
+    }
+
+    /// Replaces the error message of every failing length check on this schema.
+    /// Type errors and the errors of individual elements are not affected.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.messages.set_all(message.into());
+        self
+    }
+
+    /// Replaces the error message of the length check added just before it, e.g.
+    /// `array(string()).min(1).with_message("Add at least one tag")`.
+    ///
+    /// Takes precedence over [`message`](Self::message). Called before any
+    /// length check, it behaves like `message`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.messages.set_last(message.into());
+        self
+    }
+
+    /// Stops validating elements once `max` issues have been collected, so a
+    /// large, entirely invalid array reports at most `max` issues. Issues are
+    /// counted, not failed elements: an element failing with several issues
+    /// counts once per issue, and the last one reported may be cut short. This
+    /// applies to [`validate_streaming`](Self::validate_streaming) too. A `max` of
+    /// 0 is treated as 1, so a failure is never hidden.
+    pub fn max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max.max(1));
+        self
+    }
+
+    /// Requires at least one element to match `schema`, e.g. roles that must
+    /// include `"admin"`. Unlike the element schema, which every element must
+    /// pass, `schema` only has to match one. It sees the raw elements, and
+    /// replaces any earlier `contains` or `contains_at_least`.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use zod_rs::prelude::*;
+    ///
+    /// let roles = array(string()).contains(literal("admin"));
+    /// assert!(roles.validate(&json!(["user", "admin"])).is_ok());
+    /// assert!(roles.validate(&json!(["user"])).is_err());
+    /// ```
+    pub fn contains<S2, U>(self, schema: S2) -> Self
+    where
+        S2: Schema<U> + Send + Sync + 'static,
+        U: Debug + 'static,
+    {
+        self.contains_at_least(1, schema)
+    }
+
+    /// Like [`contains`](Self::contains), but requires at least `count` matching
+    /// elements.
+    pub fn contains_at_least<S2, U>(mut self, count: usize, schema: S2) -> Self
+    where
+        S2: Schema<U> + Send + Sync + 'static,
+        U: Debug + 'static,
+    {
+        let matcher = Matcher {
+            schema,
+            _phantom: std::marker::PhantomData,
+        };
+        self.contains = Some((count, Arc::new(matcher)));
+        self
     }
 }
 
-impl<S, T> Schema<Vec<T>> for ArraySchema<S, T>
+impl<S, T: serde::Serialize> ArraySchema<S, T> {
+    /// Rejects arrays where two validated elements serialize to the same JSON.
+    /// The error is reported at the index of the first repeated element.
+    pub fn unique(mut self) -> Self {
+        self.find_duplicate = Some(find_duplicate::<T>);
+        self
+    }
+}
+
+/// Returns `(first, duplicate)` indices of the first element equal to an earlier one.
+fn find_duplicate<T: serde::Serialize>(items: &[T]) -> Option<(usize, usize)> {
+    duplicates(items).next()
+}
+
+/// Yields `(first, duplicate)` indices of every element equal to an earlier one,
+/// comparing the elements by their serialized JSON.
+pub(crate) fn duplicates<T: serde::Serialize>(
+    items: &[T],
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut seen = HashMap::new();
+
+    items.iter().enumerate().filter_map(move |(index, item)| {
+        // Map keys serialize in sorted order, so equal values yield equal strings
+        let key = serde_json::to_string(item).ok()?;
+
+        match seen.get(&key) {
+            Some(&first) => Some((first, index)),
+            None => {
+                seen.insert(key, index);
+                None
+            }
+        }
+    })
+}
+
+impl<S, T> ArraySchema<S, T>
 where
     S: Schema<T>,
     T: Debug,
 {
-    fn validate(&self, value: &Value) -> ValidateResult<Vec<T>> {
+    /// Validates the array itself, then returns an iterator that validates one
+    /// element per step, so elements can be processed and dropped without
+    /// collecting a `Vec<T>`. Errors are reported at the element's index.
+    ///
+    /// The iterator ends once [`max_errors`](Self::max_errors) issues have been
+    /// yielded. [`unique`](Self::unique) and [`contains`](Self::contains) are not
+    /// checked, since they need every element.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use zod_rs::prelude::*;
+    ///
+    /// let schema = array(number()).max_errors(1);
+    /// let mut sum = 0.0;
+    /// let mut failures = 0;
+    ///
+    /// for result in schema.validate_streaming(&json!([1, "a", 2, "b"])).unwrap() {
+    ///     match result {
+    ///         Ok(n) => sum += n,
+    ///         Err(err) => failures += err.len(),
+    ///     }
+    /// }
+    /// assert_eq!((sum, failures), (1.0, 1));
+    /// ```
+    pub fn validate_streaming<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> ValidateResult<impl Iterator<Item = ValidateResult<T>> + 'a> {
+        let array = self.validate_length(value)?;
+        let max_errors = self.max_errors;
+
+        Ok(array
+            .iter()
+            .enumerate()
+            .scan(0, move |issues, (index, item)| {
+                if max_errors.is_some_and(|max| *issues >= max) {
+                    return None;
+                }
+
+                let result = self.element_schema.validate(item).map_err(|mut errors| {
+                    errors.prefix_path(index.to_string());
+                    if let Some(max) = max_errors {
+                        errors.issues.truncate(max - *issues);
+                    }
+                    *issues += errors.len();
+                    errors
+                });
+                Some(result)
+            }))
+    }
+
+    /// Checks the type and length of the array.
+    fn validate_length<'a>(&self, value: &'a Value) -> ValidateResult<&'a Vec<Value>> {
         let array = match value.as_array() {
             Some(arr) => arr,
             None => {
@@ -55,46 +264,126 @@ where
             }
         };
 
+        if self.nonempty && array.is_empty() {
+            return Err(self.messages.error(
+                ArrayCheck::NonEmpty,
+                ValidationError::empty(ValidationOrigin::Array),
+            ));
+        }
+
         if let Some(min) = self.min_length {
             if array.len() < min {
-                return Err(ValidationError::too_small(
-                    ValidationOrigin::Array,
-                    min.to_string(),
-                    true,
-                )
-                .into());
+                return Err(self.messages.error(
+                    ArrayCheck::MinLength,
+                    ValidationError::too_small(ValidationOrigin::Array, min.to_string(), true)
+                        .with_received(array.len().to_string()),
+                ));
             }
         }
 
         if let Some(max) = self.max_length {
             if array.len() > max {
-                return Err(ValidationError::too_big(
-                    ValidationOrigin::Array,
-                    max.to_string(),
-                    true,
-                )
-                .into());
+                return Err(self.messages.error(
+                    ArrayCheck::MaxLength,
+                    ValidationError::too_big(ValidationOrigin::Array, max.to_string(), true)
+                        .with_received(array.len().to_string()),
+                ));
             }
         }
 
+        Ok(array)
+    }
+}
+
+impl<S, T> Schema<Vec<T>> for ArraySchema<S, T>
+where
+    S: Schema<T>,
+    T: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<Vec<T>> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Vec<T>> {
+        let array = self.validate_length(value)?;
+
         let mut results = Vec::new();
         let mut validation_result = ValidationResult::new();
 
         for (index, item) in array.iter().enumerate() {
-            match self.element_schema.validate(item) {
+            match self.element_schema.validate_with(item, opts) {
                 Ok(validated_item) => results.push(validated_item),
                 Err(mut errors) => {
                     errors.prefix_path(index.to_string());
                     validation_result.merge(errors);
+
+                    if let Some(max) = self.max_errors {
+                        if validation_result.len() >= max {
+                            validation_result.issues.truncate(max);
+                            break;
+                        }
+                    }
+                    if opts.abort_early {
+                        break;
+                    }
                 }
             }
         }
 
-        if validation_result.is_empty() {
-            Ok(results)
-        } else {
-            Err(validation_result)
+        if !validation_result.is_empty() {
+            return Err(validation_result);
+        }
+
+        if let Some(find_duplicate) = self.find_duplicate {
+            if let Some((first, duplicate)) = find_duplicate(&results) {
+                let mut errors = ValidationResult::from(ValidationError::not_unique(first));
+                errors.prefix_path(duplicate.to_string());
+                return Err(errors);
+            }
+        }
+
+        if let Some((minimum, matcher)) = &self.contains {
+            let found = array
+                .iter()
+                .filter(|item| matcher.matches(item))
+                .take(*minimum)
+                .count();
+            if found < *minimum {
+                return Err(ValidationError::too_few_matches(*minimum, found).into());
+            }
         }
+
+        Ok(results)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "array".into());
+        schema.insert("items".into(), self.element_schema.to_json_schema());
+
+        let min = match (self.min_length, self.nonempty) {
+            (Some(min), nonempty) => Some(if nonempty { min.max(1) } else { min }),
+            (None, true) => Some(1),
+            (None, false) => None,
+        };
+        if let Some(min) = min {
+            schema.insert("minItems".into(), min.into());
+        }
+        if let Some(max) = self.max_length {
+            schema.insert("maxItems".into(), max.into());
+        }
+        if self.find_duplicate.is_some() {
+            schema.insert("uniqueItems".into(), true.into());
+        }
+        if let Some((minimum, matcher)) = &self.contains {
+            schema.insert("contains".into(), matcher.json_schema());
+            // 1 is the default
+            if *minimum != 1 {
+                schema.insert("minContains".into(), (*minimum).into());
+            }
+        }
+
+        Value::Object(schema)
     }
 }
 
@@ -105,7 +394,7 @@ pub fn array<S, T>(element_schema: S) -> ArraySchema<S, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::{number, string};
+    use crate::schema::{literal, number, string};
     use serde_json::json;
 
     #[test]
@@ -308,4 +597,325 @@ mod tests {
         let json_val = serde_json::to_value(large_array).unwrap();
         assert!(schema.validate(&json_val).is_ok());
     }
+
+    // Streaming
+    #[test]
+    fn test_validate_streaming_yields_per_element() {
+        let schema = array(number());
+        let results: Vec<_> = schema
+            .validate_streaming(&json!([1, "a", 3]))
+            .unwrap()
+            .collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], Ok(1.0));
+        assert_eq!(results[1].as_ref().unwrap_err().issues[0].path, vec!["1"]);
+        assert_eq!(results[2], Ok(3.0));
+    }
+
+    #[test]
+    fn test_validate_streaming_checks_array_first() {
+        let schema = array(number()).max(2);
+        assert!(schema.validate_streaming(&json!("nope")).is_err());
+        assert!(schema.validate_streaming(&json!([1, 2, 3])).is_err());
+    }
+
+    #[test]
+    fn test_validate_streaming_large_array() {
+        let schema = array(number());
+        let large_array: Vec<i32> = (0..100_000).collect();
+        let json_val = serde_json::to_value(large_array).unwrap();
+
+        // Each element is consumed as it is validated; nothing is collected
+        let sum: f64 = schema
+            .validate_streaming(&json_val)
+            .unwrap()
+            .map(|result| result.unwrap())
+            .sum();
+        assert_eq!(sum, 4_999_950_000.0);
+    }
+
+    #[test]
+    fn test_validate_streaming_stops_at_max_errors() {
+        let schema = array(number()).max_errors(2);
+        let input = json!(["a", 1, "b", "c", "d"]);
+
+        let results: Vec<_> = schema.validate_streaming(&input).unwrap().collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 2);
+    }
+
+    #[test]
+    fn test_validate_streaming_counts_issues_not_elements() {
+        // Each element fails with two issues
+        let schema = array(
+            crate::schema::object()
+                .field("a", string())
+                .field("b", string()),
+        )
+        .max_errors(3);
+
+        let results: Vec<_> = schema
+            .validate_streaming(&json!([{}, {}, {}]))
+            .unwrap()
+            .collect();
+        let issues: Vec<usize> = results
+            .iter()
+            .map(|result| result.as_ref().unwrap_err().len())
+            .collect();
+        assert_eq!(issues, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_max_errors_caps_issues() {
+        let schema = array(number()).max_errors(10);
+        let input = serde_json::to_value(vec!["x"; 100_000]).unwrap();
+
+        let err = schema.validate(&input).unwrap_err();
+        assert_eq!(err.len(), 10);
+        assert_eq!(err.issues[9].path, vec!["9"]);
+    }
+
+    #[test]
+    fn test_max_errors_zero_still_fails() {
+        let schema = array(number()).max_errors(0);
+        assert_eq!(schema.validate(&json!(["a", "b"])).unwrap_err().len(), 1);
+    }
+
+    #[test]
+    fn test_max_errors_counts_issues_not_elements() {
+        // Each element fails with two issues
+        let schema = array(
+            crate::schema::object()
+                .field("a", string())
+                .field("b", string()),
+        )
+        .max_errors(3);
+        let err = schema.validate(&json!([{}, {}, {}])).unwrap_err();
+        assert_eq!(err.len(), 3);
+    }
+
+    // NonEmpty
+    #[test]
+    fn test_nonempty() {
+        let schema = array(string()).nonempty();
+        assert!(schema.validate(&json!(["a"])).is_ok());
+
+        let err = schema.validate(&json!([])).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Too small: expected array to be non-empty"
+        );
+    }
+
+    // Custom Messages
+    #[test]
+    fn test_with_message_applies_to_previous_check() {
+        let schema = array(string())
+            .min(1)
+            .with_message("Add at least one tag")
+            .max(2);
+
+        let err = schema.validate(&json!([])).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "Add at least one tag");
+
+        let err = schema.validate(&json!(["a", "b", "c"])).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Too big: expected array to have <= 2 items, received 3"
+        );
+    }
+
+    #[test]
+    fn test_length_message_covers_both_bounds() {
+        let schema = array(number()).length(2).with_message("Expected a pair");
+        assert_eq!(
+            schema.validate(&json!([1])).unwrap_err().issues[0].to_string(),
+            "Expected a pair"
+        );
+        assert_eq!(
+            schema.validate(&json!([1, 2, 3])).unwrap_err().issues[0].to_string(),
+            "Expected a pair"
+        );
+    }
+
+    #[test]
+    fn test_message_leaves_element_and_type_errors() {
+        let schema = array(string()).nonempty().message("No tags");
+        assert_eq!(
+            schema.validate(&json!([])).unwrap_err().issues[0].to_string(),
+            "No tags"
+        );
+
+        let err = schema.validate(&json!([1])).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["0"]);
+        assert_ne!(err.issues[0].to_string(), "No tags");
+        assert_ne!(
+            schema.validate(&json!("a")).unwrap_err().issues[0].to_string(),
+            "No tags"
+        );
+    }
+
+    // Unique
+    #[test]
+    fn test_unique() {
+        let schema = array(number()).unique();
+        assert!(schema.validate(&json!([1, 2, 3])).is_ok());
+        assert!(schema.validate(&json!([])).is_ok());
+
+        let err = schema.validate(&json!([1, 2, 2])).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["2"]);
+        assert_eq!(
+            err.to_string(),
+            "\n  - 2: Duplicate item: same as item at index 1"
+        );
+    }
+
+    #[test]
+    fn test_unique_reports_first_duplicate_only() {
+        let schema = array(string()).unique();
+        let err = schema.validate(&json!(["a", "b", "a", "b"])).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["2"]);
+    }
+
+    #[test]
+    fn test_unique_compares_validated_values() {
+        // 1 and 1.0 both validate to 1.0
+        let schema = array(number()).unique();
+        assert!(schema.validate(&json!([1, 1.0])).is_err());
+
+        let schema = array(string().trim()).unique();
+        assert!(schema.validate(&json!(["a", " a "])).is_err());
+    }
+
+    #[test]
+    fn test_unique_objects_ignore_key_order() {
+        use crate::schema::object;
+
+        let schema = array(object().field("a", number()).field("b", number())).unique();
+        let err = schema
+            .validate(&json!([{"a": 1, "b": 2}, {"b": 2, "a": 1}]))
+            .unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["1"]);
+    }
+
+    #[test]
+    fn test_unique_skipped_when_elements_invalid() {
+        let schema = array(number()).unique();
+        let err = schema.validate(&json!([1, 1, "x"])).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["2"]);
+    }
+
+    // Contains
+    #[test]
+    fn test_contains() {
+        let schema = array(string()).contains(literal("admin"));
+
+        assert_eq!(
+            schema.validate(&json!(["user", "admin"])).unwrap(),
+            vec!["user", "admin"]
+        );
+        let err = schema.validate(&json!(["user"])).unwrap_err();
+        assert_eq!(err.issues[0].path, Vec::<String>::new());
+        assert_eq!(err.issues[0].code(), "too_few_matches");
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid array: must contain a matching item"
+        );
+        assert!(schema.validate(&json!([])).is_err());
+    }
+
+    #[test]
+    fn test_contains_at_least() {
+        let schema = array(number()).contains_at_least(2, number().gt(10.0));
+
+        assert!(schema.validate(&json!([11, 1, 12])).is_ok());
+        let err = schema.validate(&json!([11, 1, 2])).unwrap_err();
+        assert_eq!(err.issues[0].error, ValidationError::too_few_matches(2, 1));
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid array: must contain at least 2 matching items, found 1"
+        );
+
+        assert!(array(number())
+            .contains_at_least(0, literal(1.0))
+            .validate(&json!([]))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_contains_checked_after_elements() {
+        let schema = array(string().min(2)).contains(literal("admin"));
+
+        // Element errors come first
+        let err = schema.validate(&json!(["a"])).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["0"]);
+    }
+
+    #[test]
+    fn test_contains_matches_raw_elements() {
+        let schema = array(string().trim()).contains(literal("admin"));
+
+        assert!(schema.validate(&json!(["admin"])).is_ok());
+        assert!(schema.validate(&json!([" admin "])).is_err());
+    }
+
+    // Validation Modes
+    #[test]
+    fn test_abort_early_stops_at_first_failing_element() {
+        let schema = array(number());
+        let input = json!([1, "a", 2, "b"]);
+
+        assert_eq!(schema.validate(&input).unwrap_err().issues.len(), 2);
+
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+        let err = schema.validate_with(&input, opts).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["1"]);
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = array(string()).min(1).max(3).unique();
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "array",
+                "items": {"type": "string"},
+                "minItems": 1,
+                "maxItems": 3,
+                "uniqueItems": true
+            })
+        );
+        assert_eq!(
+            array(number()).nonempty().to_json_schema(),
+            json!({"type": "array", "items": {"type": "number"}, "minItems": 1})
+        );
+    }
+
+    #[test]
+    fn test_contains_json_schema() {
+        assert_eq!(
+            array(string()).contains(literal("admin")).to_json_schema(),
+            json!({"type": "array", "items": {"type": "string"}, "contains": {"const": "admin"}})
+        );
+        assert_eq!(
+            array(number())
+                .contains_at_least(2, number().positive())
+                .to_json_schema(),
+            json!({
+                "type": "array",
+                "items": {"type": "number"},
+                "contains": {"type": "number", "exclusiveMinimum": 0},
+                "minContains": 2
+            })
+        );
+    }
 }
@@ -0,0 +1,348 @@
+use crate::schema::{messages::CheckMessages, Schema, ValidateOptions};
+use serde_json::{Map, Number, Value};
+use zod_rs_util::{
+    NumberConstraint, ValidateResult, ValidationError, ValidationOrigin, ValidationType,
+};
+
+/// 2^53, the first float that several integers round to: 2^53 + 1 is stored as
+/// 2^53, so a float of this size or larger may no longer be the integer sent.
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum BigIntCheck {
+    Min,
+    Max,
+}
+
+/// Schema for whole numbers that keeps full 64-bit precision, e.g. Snowflake IDs.
+///
+/// [`NumberSchema`](crate::NumberSchema) reads every number as an `f64`, which
+/// rounds integers above 2^53. This schema reads JSON integers exactly and outputs
+/// an `i128`, so any `i64` or `u64` fits. Fractions are rejected, and an integer
+/// that JSON parsing already stored as a float is rejected once it is too large
+/// to be exact, instead of being accepted rounded.
+#[derive(Debug, Clone)]
+pub struct BigIntSchema {
+    min: Option<i128>,
+    max: Option<i128>,
+    coerce: bool,
+    messages: CheckMessages<BigIntCheck>,
+}
+
+impl BigIntSchema {
+    pub fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            coerce: false,
+            messages: CheckMessages::new(),
+        }
+    }
+
+    /// Inclusive lower bound.
+    pub fn min(mut self, min: i128) -> Self {
+        self.min = Some(min);
+        self.messages.track(&[BigIntCheck::Min]);
+        self
+    }
+
+    /// Inclusive upper bound.
+    pub fn max(mut self, max: i128) -> Self {
+        self.max = Some(max);
+        self.messages.track(&[BigIntCheck::Max]);
+        self
+    }
+
+    /// Also accepts integers sent as strings, the usual way to send 64-bit IDs to
+    /// JavaScript clients. The trimmed string must be a decimal integer such as
+    /// `"1234567890123456789"` or `"-42"`.
+    pub fn coerce(mut self) -> Self {
+        self.coerce = true;
+        self
+    }
+
+    /// Replaces the error message of every failing bound on this schema. Type and
+    /// precision errors are not affected.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.messages.set_all(message.into());
+        self
+    }
+
+    /// Replaces the error message of the bound added just before it.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.messages.set_last(message.into());
+        self
+    }
+}
+
+impl Default for BigIntSchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Schema<i128> for BigIntSchema {
+    fn validate(&self, value: &Value) -> ValidateResult<i128> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<i128> {
+        let int = match value {
+            Value::Number(n) => read_integer(n)?,
+            Value::String(s) if self.coerce || opts.coerce => {
+                s.trim().parse::<i128>().map_err(|_| {
+                    ValidationError::invalid_type(
+                        ValidationType::custom("integer"),
+                        ValidationType::String,
+                    )
+                })?
+            }
+            _ => {
+                return Err(ValidationError::invalid_type(
+                    ValidationType::custom("integer"),
+                    ValidationType::from(value),
+                )
+                .into());
+            }
+        };
+
+        if let Some(min) = self.min {
+            if int < min {
+                return Err(self.messages.error(
+                    BigIntCheck::Min,
+                    ValidationError::too_small(ValidationOrigin::Number, min.to_string(), true)
+                        .with_received(int.to_string()),
+                ));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if int > max {
+                return Err(self.messages.error(
+                    BigIntCheck::Max,
+                    ValidationError::too_big(ValidationOrigin::Number, max.to_string(), true)
+                        .with_received(int.to_string()),
+                ));
+            }
+        }
+
+        Ok(int)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "integer".into());
+        if let Some(min) = self.min {
+            schema.insert("minimum".into(), json_integer(min));
+        }
+        if let Some(max) = self.max {
+            schema.insert("maximum".into(), json_integer(max));
+        }
+        Value::Object(schema)
+    }
+}
+
+/// Reads a JSON number as an exact integer.
+fn read_integer(n: &Number) -> ValidateResult<i128> {
+    if let Some(int) = n.as_i64() {
+        return Ok(int.into());
+    }
+    if let Some(int) = n.as_u64() {
+        return Ok(int.into());
+    }
+    // With serde_json's `arbitrary_precision`, the original digits survive
+    if let Ok(int) = n.to_string().parse::<i128>() {
+        return Ok(int);
+    }
+
+    let float = n.as_f64().unwrap_or(f64::NAN);
+    if float.fract() != 0.0 || !float.is_finite() {
+        return Err(ValidationError::invalid_type(
+            ValidationType::custom("integer"),
+            ValidationType::custom("float"),
+        )
+        .into());
+    }
+    if float.abs() >= MAX_EXACT_F64 {
+        return Err(ValidationError::invalid_number(NumberConstraint::Exact).into());
+    }
+
+    Ok(float as i128)
+}
+
+/// Writes a bound as a JSON integer when it fits in 64 bits.
+fn json_integer(int: i128) -> Value {
+    if let Ok(int) = i64::try_from(int) {
+        int.into()
+    } else if let Ok(int) = u64::try_from(int) {
+        int.into()
+    } else {
+        (int as f64).into()
+    }
+}
+
+pub fn big_int() -> BigIntSchema {
+    BigIntSchema::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::object;
+    use serde_json::json;
+
+    #[test]
+    fn test_big_int_validation() {
+        let schema = big_int();
+
+        assert_eq!(schema.validate(&json!(42)).unwrap(), 42);
+        assert_eq!(schema.validate(&json!(-7)).unwrap(), -7);
+        assert!(schema.validate(&json!(1.5)).is_err());
+        assert!(schema.validate(&json!("42")).is_err());
+        assert!(schema.validate(&json!(null)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Precision
+    #[test]
+    fn test_keeps_full_64_bit_precision() {
+        let schema = big_int();
+        // 2^53 + 1 rounds to 2^53 as an f64
+        let input: Value = serde_json::from_str("9007199254740993").unwrap();
+        assert_eq!(schema.validate(&input).unwrap(), 9_007_199_254_740_993);
+
+        assert_eq!(
+            schema.validate(&json!(u64::MAX)).unwrap(),
+            i128::from(u64::MAX)
+        );
+        assert_eq!(
+            schema.validate(&json!(i64::MIN)).unwrap(),
+            i128::from(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn test_snowflake_id_bounds_are_exact() {
+        let schema = big_int().min(1_000_000_000_000_000_001);
+        let at_min: Value = serde_json::from_str("1000000000000000001").unwrap();
+        let below: Value = serde_json::from_str("1000000000000000000").unwrap();
+
+        assert!(schema.validate(&at_min).is_ok());
+        assert_eq!(
+            schema.validate(&below).unwrap_err().issues[0].to_string(),
+            "Too small: expected number to have >= 1000000000000000001, received 1000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_whole_floats() {
+        let schema = big_int();
+        assert_eq!(schema.validate(&json!(5.0)).unwrap(), 5);
+
+        let err = schema.validate(&json!(2.5)).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input: expected integer, received float"
+        );
+    }
+
+    #[test]
+    fn test_precision_loss_is_reported() {
+        let schema = big_int();
+        // Parsed as floats, so the exact digits are already gone
+        for input in ["1e17", "12345678901234567890123"] {
+            let value: Value = serde_json::from_str(input).unwrap();
+            let err = schema.validate(&value).unwrap_err();
+            assert_eq!(
+                err.issues[0].error,
+                ValidationError::invalid_number(NumberConstraint::Exact),
+                "{input}"
+            );
+        }
+        // 2^53 + 1 is parsed as the float 2^53, so 2^53 itself is ambiguous
+        for input in [
+            "9007199254740992.0",
+            "9007199254740993.0",
+            "-9.007199254740992e15",
+        ] {
+            let value: Value = serde_json::from_str(input).unwrap();
+            assert!(value.is_f64(), "{input}");
+            assert_eq!(
+                schema.validate(&value).unwrap_err().issues[0].error,
+                ValidationError::invalid_number(NumberConstraint::Exact),
+                "{input}"
+            );
+        }
+        // Below 2^53 a float is still exact
+        assert_eq!(
+            schema.validate(&json!(1e15)).unwrap(),
+            1_000_000_000_000_000
+        );
+        assert_eq!(
+            schema.validate(&json!(9_007_199_254_740_991.0)).unwrap(),
+            9_007_199_254_740_991
+        );
+    }
+
+    // Coercion
+    #[test]
+    fn test_coerce_from_string() {
+        let schema = big_int().coerce();
+        assert_eq!(
+            schema.validate(&json!(" 1234567890123456789 ")).unwrap(),
+            1_234_567_890_123_456_789
+        );
+        assert_eq!(schema.validate(&json!("-42")).unwrap(), -42);
+        assert!(schema.validate(&json!("1.5")).is_err());
+        assert!(schema.validate(&json!("12abc")).is_err());
+        assert!(schema.validate(&json!("")).is_err());
+    }
+
+    // Bounds
+    #[test]
+    fn test_min_max() {
+        let schema = big_int().min(-1).max(i128::from(u64::MAX) - 1);
+        assert!(schema.validate(&json!(-1)).is_ok());
+        assert!(schema.validate(&json!(-2)).is_err());
+        assert!(schema.validate(&json!(u64::MAX - 1)).is_ok());
+        assert!(schema.validate(&json!(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_custom_messages() {
+        let schema = big_int()
+            .min(1)
+            .with_message("IDs start at 1")
+            .max(10)
+            .message("Unknown ID");
+        assert_eq!(
+            schema.validate(&json!(0)).unwrap_err().issues[0].to_string(),
+            "IDs start at 1"
+        );
+        assert_eq!(
+            schema.validate(&json!(11)).unwrap_err().issues[0].to_string(),
+            "Unknown ID"
+        );
+    }
+
+    #[test]
+    fn test_in_object_field() {
+        let schema = object().field("id", big_int());
+        let input: Value = serde_json::from_str(r#"{"id": 18446744073709551615}"#).unwrap();
+        assert_eq!(
+            schema.validate(&input).unwrap(),
+            json!({"id": 18446744073709551615u64})
+        );
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(big_int().to_json_schema(), json!({"type": "integer"}));
+        assert_eq!(
+            big_int().min(0).max(i128::from(u64::MAX)).to_json_schema(),
+            json!({"type": "integer", "minimum": 0, "maximum": u64::MAX})
+        );
+    }
+}
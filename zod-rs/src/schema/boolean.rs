@@ -1,13 +1,23 @@
-use crate::schema::Schema;
-use serde_json::Value;
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::{json, Value};
 use zod_rs_util::{ValidateResult, ValidationError, ValidationType};
 
 #[derive(Debug, Clone)]
-pub struct BooleanSchema;
+pub struct BooleanSchema {
+    coerce: bool,
+}
 
 impl BooleanSchema {
     pub fn new() -> Self {
-        Self
+        Self { coerce: false }
+    }
+
+    /// Also accepts the strings `"true"` and `"false"`, as sent by query strings
+    /// and form bodies. Matching is exact: `"TRUE"`, `"1"` or `" true"` are still
+    /// rejected.
+    pub fn coerce(mut self) -> Self {
+        self.coerce = true;
+        self
     }
 }
 
@@ -19,15 +29,30 @@ impl Default for BooleanSchema {
 
 impl Schema<bool> for BooleanSchema {
     fn validate(&self, value: &Value) -> ValidateResult<bool> {
-        match value.as_bool() {
-            Some(b) => Ok(b),
-            None => Err(ValidationError::invalid_type(
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<bool> {
+        let coerce = self.coerce || opts.coerce;
+        match value {
+            Value::Bool(b) => Ok(*b),
+            Value::String(s) if coerce && s == "true" => Ok(true),
+            Value::String(s) if coerce && s == "false" => Ok(false),
+            _ => Err(ValidationError::invalid_type(
                 ValidationType::Bool,
                 ValidationType::from(value),
             )
             .into()),
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        if self.coerce {
+            json!({ "anyOf": [{ "type": "boolean" }, { "enum": ["true", "false"] }] })
+        } else {
+            json!({ "type": "boolean" })
+        }
+    }
 }
 
 pub fn boolean() -> BooleanSchema {
@@ -52,6 +77,7 @@ mod tests {
 
     // True/False Return Values
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_true_returns_true() {
         let schema = boolean();
         let result = schema.validate(&json!(true));
@@ -60,6 +86,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_false_returns_false() {
         let schema = boolean();
         let result = schema.validate(&json!(false));
@@ -146,4 +173,36 @@ mod tests {
         let schema = boolean();
         assert!(schema.validate(&json!(-1)).is_err());
     }
+
+    // Coercion
+    #[test]
+    fn test_coerce_accepts_boolean_strings() {
+        let schema = boolean().coerce();
+        assert!(schema.validate(&json!("true")).unwrap());
+        assert!(!schema.validate(&json!("false")).unwrap());
+        assert!(schema.validate(&json!(true)).unwrap());
+    }
+
+    #[test]
+    fn test_coerce_rejects_other_strings() {
+        let schema = boolean().coerce();
+        for input in ["TRUE", "False", "1", "0", "yes", "", " true", "abc"] {
+            let err = schema.validate(&json!(input)).unwrap_err();
+            assert_eq!(
+                err.issues[0].to_string(),
+                "Invalid input: expected bool, received string",
+                "{input:?}"
+            );
+        }
+        assert!(schema.validate(&json!(1)).is_err());
+        assert!(schema.validate(&json!(null)).is_err());
+    }
+
+    #[test]
+    fn test_coerce_json_schema() {
+        assert_eq!(
+            boolean().coerce().to_json_schema(),
+            json!({"anyOf": [{"type": "boolean"}, {"enum": ["true", "false"]}]})
+        );
+    }
 }
@@ -0,0 +1,114 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::Debug;
+use zod_rs_util::ValidateResult;
+
+#[derive(Debug, Clone)]
+pub struct CatchSchema<S, T> {
+    inner: S,
+    fallback: T,
+}
+
+impl<S, T> CatchSchema<S, T> {
+    pub fn new(inner: S, fallback: T) -> Self {
+        Self { inner, fallback }
+    }
+}
+
+impl<S, T> Schema<T> for CatchSchema<S, T>
+where
+    S: Schema<T>,
+    T: Clone + Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        Ok(self
+            .inner
+            .validate_with(value, opts)
+            .unwrap_or_else(|_| self.fallback.clone()))
+    }
+
+    /// Describes the input the inner schema expects, although any value passes.
+    fn to_json_schema(&self) -> Value {
+        self.inner.to_json_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{array, number, object, string, Schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_catch_validation() {
+        let schema = number().min(1.0).catch(10.0);
+
+        assert_eq!(schema.validate(&json!(5)).unwrap(), 5.0);
+        assert_eq!(schema.validate(&json!(0)).unwrap(), 10.0);
+        assert_eq!(schema.validate(&json!("five")).unwrap(), 10.0);
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[test]
+    fn test_catch_missing_field_still_required() {
+        // A missing field fails in the object before the field schema runs
+        let schema = object().field("port", number().catch(8080.0));
+
+        assert_eq!(
+            schema.validate(&json!({"port": "x"})).unwrap(),
+            json!({"port": 8080.0})
+        );
+        assert!(schema.validate(&json!({})).is_err());
+    }
+
+    #[test]
+    fn test_catch_keeps_sibling_errors() {
+        let schema = object()
+            .field("name", string().min(1))
+            .field("retries", number().int().catch(3.0));
+
+        let err = schema
+            .validate(&json!({"name": "", "retries": 1.5}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["name"]);
+    }
+
+    #[test]
+    fn test_catch_per_element() {
+        let schema = array(number().catch(0.0));
+        assert_eq!(
+            schema.validate(&json!([1, "two", 3])).unwrap(),
+            vec![1.0, 0.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_catch_whole_array() {
+        let schema = array(number()).catch(vec![]);
+        assert_eq!(
+            schema.validate(&json!([1, "two"])).unwrap(),
+            Vec::<f64>::new()
+        );
+    }
+
+    #[test]
+    fn test_catch_after_transform() {
+        let schema = string().transform(|s| s.len()).catch(0);
+        assert_eq!(schema.validate(&json!("abc")).unwrap(), 3);
+        assert_eq!(schema.validate(&json!(null)).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_catch_json_schema_describes_inner() {
+        let schema = string().email().catch("unknown".to_string());
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"type": "string", "format": "email"})
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::Debug;
+use zod_rs_util::{ValidateResult, ValidationError};
+
+#[derive(Debug, Clone)]
+pub struct DescribedSchema<S, T> {
+    inner: S,
+    description: String,
+    prefix_errors: bool,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T> DescribedSchema<S, T> {
+    pub fn new(inner: S, description: &str) -> Self {
+        Self {
+            inner,
+            description: description.to_string(),
+            prefix_errors: false,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Prefixes the message of top-level errors with the description, e.g.
+    /// `"Port: Too small: expected number to have >= 1, received 0"`. Errors with a path are left
+    /// alone, since the path already tells where they come from.
+    pub fn prefix_errors(mut self) -> Self {
+        self.prefix_errors = true;
+        self
+    }
+}
+
+impl<S, T> Schema<T> for DescribedSchema<S, T>
+where
+    S: Schema<T>,
+    T: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        self.inner.validate_with(value, opts).map_err(|mut errors| {
+            if self.prefix_errors {
+                for issue in errors.issues.iter_mut().filter(|i| i.path.is_empty()) {
+                    issue.error =
+                        ValidationError::custom(format!("{}: {}", self.description, issue.error));
+                }
+            }
+            errors
+        })
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = self.inner.to_json_schema();
+        if let Value::Object(map) = &mut schema {
+            map.insert(
+                "description".to_string(),
+                Value::String(self.description.clone()),
+            );
+        }
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{number, object, string, Schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_describe_validation() {
+        let schema = string().email().describe("Contact email");
+
+        assert_eq!(schema.description(), "Contact email");
+        assert_eq!(
+            schema.validate(&json!("a@b.co")).unwrap(),
+            "a@b.co".to_string()
+        );
+        assert!(schema.validate(&json!("nope")).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Error Messages
+    #[test]
+    fn test_errors_unchanged_by_default() {
+        let plain = number().min(1.0).validate(&json!(0)).unwrap_err();
+        let described = number()
+            .min(1.0)
+            .describe("Port")
+            .validate(&json!(0))
+            .unwrap_err();
+
+        assert_eq!(described, plain);
+    }
+
+    #[test]
+    fn test_prefix_errors() {
+        let schema = number().min(1.0).describe("Port").prefix_errors();
+        let err = schema.validate(&json!(0)).unwrap_err();
+
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Port: Too small: expected number to have >= 1, received 0"
+        );
+    }
+
+    #[test]
+    fn test_prefix_errors_skips_nested_paths() {
+        let schema = object()
+            .field("port", number().min(1.0))
+            .describe("Server config")
+            .prefix_errors();
+        let err = schema.validate(&json!({"port": 0})).unwrap_err();
+
+        assert_eq!(err.issues[0].path, vec!["port"]);
+        assert!(!err.issues[0].to_string().contains("Server config"));
+
+        let err = schema.validate(&json!("config")).unwrap_err();
+        assert!(err.issues[0].to_string().starts_with("Server config: "));
+    }
+
+    // Composition
+    #[test]
+    fn test_described_field_in_object() {
+        let schema = object().field("name", string().min(1).describe("Display name"));
+
+        assert!(schema.validate(&json!({"name": "Ada"})).is_ok());
+        let err = schema.validate(&json!({"name": ""})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["name"]);
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema_description() {
+        let schema = object()
+            .field("port", number().int().describe("Listening port"))
+            .describe("Server config");
+
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "object",
+                "description": "Server config",
+                "properties": {
+                    "port": {"type": "integer", "description": "Listening port"}
+                },
+                "required": ["port"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_description_through_refine() {
+        let schema = string()
+            .refine(|s: &String| !s.is_empty(), "Required")
+            .describe("Nickname");
+
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"type": "string", "description": "Nickname"})
+        );
+    }
+}
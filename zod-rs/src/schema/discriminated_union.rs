@@ -0,0 +1,407 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::{json, Value};
+use std::{any::Any, fmt::Debug, sync::Arc};
+use zod_rs_util::{ValidateResult, ValidationError, ValidationResult, ValidationType};
+
+/// A union of object schemas told apart by the value of one key, e.g.
+/// `{"type": "circle", ...}`. Only the variant selected by that key is validated,
+/// so errors come from that branch alone instead of from every variant.
+#[derive(Debug, Clone)]
+pub struct DiscriminatedUnionSchema<T>
+where
+    T: Debug,
+{
+    discriminator: String,
+    variants: Vec<Variant<T>>,
+}
+
+#[derive(Debug, Clone)]
+struct Variant<T>
+where
+    T: Debug,
+{
+    tag: Value,
+    schema: Arc<dyn Schema<T> + Send + Sync>,
+    /// Whether the schema has a property for the discriminator, in which case it
+    /// validates the discriminator itself.
+    declares_tag: bool,
+}
+
+impl<T> DiscriminatedUnionSchema<T>
+where
+    T: Debug,
+{
+    pub fn new(discriminator: &str) -> Self {
+        Self {
+            discriminator: discriminator.to_string(),
+            variants: Vec::new(),
+        }
+    }
+
+    /// Adds the schema used when the discriminator equals `tag`. If two variants
+    /// share a tag, the first one wins.
+    ///
+    /// The discriminator is a known key whether or not `schema` declares it: a
+    /// variant that does not is validated without it, so a strict or stripping
+    /// object accepts it, and the tag is then added back to the object output. A
+    /// variant that declares it, e.g. with a `literal` field, sees the whole object.
+    pub fn variant<S>(mut self, tag: impl Into<Value>, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + Debug + 'static,
+    {
+        let declares_tag = schema
+            .to_json_schema()
+            .get("properties")
+            .and_then(|properties| properties.get(&self.discriminator))
+            .is_some();
+
+        self.variants.push(Variant {
+            tag: tag.into(),
+            schema: Arc::new(schema),
+            declares_tag,
+        });
+        self
+    }
+
+    fn invalid_tag(&self) -> ValidationResult {
+        let tags = self
+            .variants
+            .iter()
+            .map(|variant| match &variant.tag {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+
+        let mut errors = ValidationResult::from(ValidationError::invalid_values(tags));
+        errors.prefix_path(self.discriminator.clone());
+        errors
+    }
+}
+
+impl<T> Schema<T> for DiscriminatedUnionSchema<T>
+where
+    T: Debug + 'static,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        let obj = value.as_object().ok_or_else(|| {
+            ValidationResult::from(ValidationError::invalid_type(
+                ValidationType::Object,
+                ValidationType::from(value),
+            ))
+        })?;
+
+        let Some(tag) = obj.get(&self.discriminator) else {
+            let mut errors = ValidationResult::from(ValidationError::required());
+            errors.prefix_path(self.discriminator.clone());
+            return Err(errors);
+        };
+
+        let Some(variant) = self.variants.iter().find(|variant| &variant.tag == tag) else {
+            return Err(self.invalid_tag());
+        };
+
+        if variant.declares_tag {
+            return variant.schema.validate_with(value, opts);
+        }
+
+        let mut rest = obj.clone();
+        rest.remove(&self.discriminator);
+        let mut output = variant.schema.validate_with(&Value::Object(rest), opts)?;
+
+        if let Some(Value::Object(map)) = (&mut output as &mut dyn Any).downcast_mut::<Value>() {
+            map.insert(self.discriminator.clone(), tag.clone());
+        }
+        Ok(output)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let schemas: Vec<Value> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let mut schema = variant.schema.to_json_schema();
+                if !variant.declares_tag {
+                    add_tag_property(&mut schema, &self.discriminator, &variant.tag);
+                }
+                schema
+            })
+            .collect();
+
+        json!({
+            "oneOf": schemas,
+            "discriminator": { "propertyName": self.discriminator },
+        })
+    }
+}
+
+/// Adds the discriminator to an object JSON Schema that does not declare it, so
+/// that a variant with `additionalProperties: false` still allows it.
+fn add_tag_property(schema: &mut Value, discriminator: &str, tag: &Value) {
+    let Some(properties) = schema.get_mut("properties").and_then(Value::as_object_mut) else {
+        return;
+    };
+    properties.insert(discriminator.to_string(), json!({ "const": tag }));
+
+    if let Some(required) = schema.get_mut("required").and_then(Value::as_array_mut) {
+        required.push(json!(discriminator));
+    }
+}
+
+pub fn discriminated_union<T>(discriminator: &str) -> DiscriminatedUnionSchema<T>
+where
+    T: Debug,
+{
+    DiscriminatedUnionSchema::new(discriminator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{literal, number, object, string};
+    use serde_json::json;
+
+    fn shape_schema() -> DiscriminatedUnionSchema<Value> {
+        discriminated_union("type")
+            .variant("circle", object().field("radius", number().positive()))
+            .variant(
+                "rect",
+                object()
+                    .field("width", number().positive())
+                    .field("height", number().positive()),
+            )
+    }
+
+    #[test]
+    fn test_discriminated_union_validation() {
+        let schema = shape_schema();
+
+        assert!(schema
+            .validate(&json!({"type": "circle", "radius": 1}))
+            .is_ok());
+        assert!(schema
+            .validate(&json!({"type": "rect", "width": 1, "height": 2}))
+            .is_ok());
+        assert!(schema
+            .validate(&json!({"type": "circle", "width": 1}))
+            .is_err());
+        assert!(schema.validate(&json!({"type": "triangle"})).is_err());
+    }
+
+    #[test]
+    fn test_discriminator_kept_in_output() {
+        let schema = shape_schema();
+        let result = schema
+            .validate(&json!({"type": "circle", "radius": 2}))
+            .unwrap();
+        assert_eq!(result, json!({"type": "circle", "radius": 2.0}));
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Error Reporting
+    #[test]
+    fn test_errors_only_from_selected_variant() {
+        let schema = shape_schema();
+        let err = schema
+            .validate(&json!({"type": "rect", "width": -1}))
+            .unwrap_err();
+
+        let mut messages: Vec<_> = err.issues.iter().map(|i| i.to_string()).collect();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                "height: Value is required but was not provided",
+                "width: Invalid number: must be positive"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_discriminator() {
+        let schema = shape_schema();
+        let err = schema.validate(&json!({"radius": 1})).unwrap_err();
+
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["type"]);
+        assert_eq!(
+            err.issues[0].to_string(),
+            "type: Value is required but was not provided"
+        );
+    }
+
+    #[test]
+    fn test_unknown_discriminator_lists_tags() {
+        let schema = shape_schema();
+        let err = schema.validate(&json!({"type": "triangle"})).unwrap_err();
+
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(
+            err.issues[0].to_string(),
+            "type: Invalid option: expected one of circle | rect"
+        );
+    }
+
+    #[test]
+    fn test_discriminator_type_must_match() {
+        let schema = shape_schema();
+        assert!(schema
+            .validate(&json!({"type": null, "radius": 1}))
+            .is_err());
+        assert!(schema.validate(&json!({"type": 1, "radius": 1})).is_err());
+    }
+
+    // Tag Types
+    #[test]
+    fn test_integer_and_boolean_tags() {
+        let schema = discriminated_union("version")
+            .variant(1, object().field("name", string()))
+            .variant(2, object().field("full_name", string()));
+
+        assert!(schema
+            .validate(&json!({"version": 2, "full_name": "Ada"}))
+            .is_ok());
+        assert!(schema
+            .validate(&json!({"version": 1, "full_name": "Ada"}))
+            .is_err());
+
+        let err = schema.validate(&json!({"version": 3})).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "version: Invalid option: expected one of 1 | 2"
+        );
+
+        let schema = discriminated_union("ok")
+            .variant(true, object().field("value", number()))
+            .variant(false, object().field("error", string()));
+        assert!(schema.validate(&json!({"ok": false, "error": "x"})).is_ok());
+    }
+
+    #[test]
+    fn test_variant_can_declare_discriminator() {
+        let schema = discriminated_union("type").variant(
+            "user",
+            object()
+                .field("type", literal("user"))
+                .field("name", string())
+                .strict(),
+        );
+        assert!(schema
+            .validate(&json!({"type": "user", "name": "Ada"}))
+            .is_ok());
+    }
+
+    // Unknown Key Modes
+    #[test]
+    fn test_strict_variant_accepts_discriminator() {
+        let schema = discriminated_union("type")
+            .variant("circle", object().field("radius", number()).strict());
+
+        assert_eq!(
+            schema
+                .validate(&json!({"type": "circle", "radius": 1}))
+                .unwrap(),
+            json!({"type": "circle", "radius": 1.0})
+        );
+
+        let err = schema
+            .validate(&json!({"type": "circle", "radius": 1, "color": "red"}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].to_string(), "Unrecognized key: color");
+    }
+
+    #[test]
+    fn test_strip_variant_keeps_discriminator() {
+        let schema = discriminated_union("type")
+            .variant("circle", object().field("radius", number()).strip());
+
+        assert_eq!(
+            schema
+                .validate(&json!({"type": "circle", "radius": 1, "color": "red"}))
+                .unwrap(),
+            json!({"type": "circle", "radius": 1.0})
+        );
+    }
+
+    #[test]
+    fn test_declared_discriminator_is_validated_by_variant() {
+        let schema = discriminated_union("type").variant(
+            "user",
+            object()
+                .field("type", literal("user").transform(|t| t.to_uppercase()))
+                .strip(),
+        );
+
+        assert_eq!(
+            schema.validate(&json!({"type": "user"})).unwrap(),
+            json!({"type": "USER"})
+        );
+    }
+
+    #[test]
+    fn test_abort_early_passed_to_variant() {
+        let schema = shape_schema();
+        let err = schema
+            .validate_with(
+                &json!({"type": "rect", "width": -1, "height": -1}),
+                ValidateOptions {
+                    abort_early: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
+
+    // Type Rejection
+    #[test]
+    fn test_rejects_non_object() {
+        let schema = shape_schema();
+        let err = schema.validate(&json!("circle")).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input: expected object, received string"
+        );
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema_keeps_declared_discriminator() {
+        let schema = discriminated_union("type")
+            .variant("user", object().field("type", literal("user")).strict());
+
+        let json_schema = schema.to_json_schema();
+        assert_eq!(json_schema["oneOf"][0]["required"], json!(["type"]));
+        assert_eq!(
+            json_schema["oneOf"][0]["additionalProperties"],
+            json!(false)
+        );
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let schema =
+            discriminated_union("type").variant("circle", object().field("radius", number()));
+
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        "radius": {"type": "number"},
+                        "type": {"const": "circle"}
+                    },
+                    "required": ["radius", "type"]
+                }],
+                "discriminator": {"propertyName": "type"}
+            })
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::schema::Schema;
+use serde_json::{json, Value};
+use zod_rs_util::{ValidateResult, ValidationError, ValidationType};
+
+/// Schema for a string restricted to a fixed set of values, like Zod's `z.enum([...])`.
+/// Unlike a union of literals, a mismatch reports every allowed option at once.
+#[derive(Debug, Clone)]
+pub struct EnumValuesSchema {
+    values: Vec<String>,
+    message: Option<String>,
+}
+
+impl EnumValuesSchema {
+    pub fn new(values: &[&str]) -> Self {
+        Self {
+            values: values.iter().map(|value| value.to_string()).collect(),
+            message: None,
+        }
+    }
+
+    /// The allowed values, in declaration order.
+    pub fn options(&self) -> &[String] {
+        &self.values
+    }
+
+    /// Replaces the error message for a value outside the set. Type errors are not
+    /// affected.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+impl Schema<String> for EnumValuesSchema {
+    fn validate(&self, value: &Value) -> ValidateResult<String> {
+        let s = value.as_str().ok_or_else(|| {
+            ValidationError::invalid_type(ValidationType::String, ValidationType::from(value))
+        })?;
+
+        if self.values.iter().any(|allowed| allowed == s) {
+            return Ok(s.to_string());
+        }
+
+        Err(match &self.message {
+            Some(message) => ValidationError::custom(message.clone()).into(),
+            None => ValidationError::invalid_values(self.values.clone()).into(),
+        })
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "type": "string", "enum": self.values })
+    }
+}
+
+pub fn enum_values(values: &[&str]) -> EnumValuesSchema {
+    EnumValuesSchema::new(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::object;
+    use serde_json::json;
+
+    #[test]
+    fn test_enum_values_validation() {
+        let schema = enum_values(&["draft", "published", "archived"]);
+
+        assert_eq!(schema.validate(&json!("draft")).unwrap(), "draft");
+        assert!(schema.validate(&json!("archived")).is_ok());
+        assert!(schema.validate(&json!("deleted")).is_err());
+        assert!(schema.validate(&json!(1)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Error Messages
+    #[test]
+    fn test_error_lists_options() {
+        let schema = enum_values(&["draft", "published", "archived"]);
+        let err = schema.validate(&json!("deleted")).unwrap_err();
+
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(
+            err.issues[0].error,
+            ValidationError::invalid_values(vec![
+                "draft".into(),
+                "published".into(),
+                "archived".into()
+            ])
+        );
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid option: expected one of draft | published | archived"
+        );
+    }
+
+    #[test]
+    fn test_custom_message() {
+        let schema = enum_values(&["a", "b"]).message("Pick a or b");
+
+        let err = schema.validate(&json!("c")).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "Pick a or b");
+
+        let err = schema.validate(&json!(null)).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input: expected string, received null"
+        );
+    }
+
+    #[test]
+    fn test_error_path_in_object() {
+        let schema = object().field("status", enum_values(&["on", "off"]));
+        let err = schema.validate(&json!({"status": "maybe"})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["status"]);
+    }
+
+    // Matching
+    #[test]
+    fn test_case_sensitive() {
+        let schema = enum_values(&["Draft"]);
+        assert!(schema.validate(&json!("draft")).is_err());
+    }
+
+    #[test]
+    fn test_empty_set_rejects_everything() {
+        let schema = enum_values(&[]);
+        assert!(schema.validate(&json!("")).is_err());
+    }
+
+    #[test]
+    fn test_options_keep_order() {
+        let schema = enum_values(&["b", "a"]);
+        assert_eq!(schema.options(), ["b", "a"]);
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(
+            enum_values(&["draft", "published"]).to_json_schema(),
+            json!({"type": "string", "enum": ["draft", "published"]})
+        );
+    }
+}
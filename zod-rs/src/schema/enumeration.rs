@@ -0,0 +1,301 @@
+use crate::schema::{into_json_value, Schema, ValidateOptions};
+use serde_json::{json, Value};
+use std::{fmt::Debug, sync::Arc};
+use zod_rs_util::{ValidateResult, ValidationError, ValidationResult, ValidationType};
+
+/// Schema for serde's externally-tagged enum representation, e.g.
+/// `{"Click": {"x": 1, "y": 2}}`.
+#[derive(Debug, Clone)]
+pub struct EnumSchema {
+    variants: Vec<(String, Arc<dyn EnumVariantValidator>)>,
+}
+
+impl EnumSchema {
+    pub fn new() -> Self {
+        Self {
+            variants: Vec::new(),
+        }
+    }
+
+    pub fn variant<S, T>(mut self, name: &str, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        T: serde::Serialize + Send + Sync + Debug + 'static,
+    {
+        self.variants.push((
+            name.to_string(),
+            Arc::new(EnumVariantValidatorImpl::new(schema)),
+        ));
+        self
+    }
+
+    /// Validates the value and returns the matched variant tag together with
+    /// its validated body.
+    pub fn validate_variant(&self, value: &Value) -> ValidateResult<(String, Value)> {
+        self.validate_variant_with(value, ValidateOptions::default())
+    }
+
+    fn validate_variant_with(
+        &self,
+        value: &Value,
+        opts: ValidateOptions,
+    ) -> ValidateResult<(String, Value)> {
+        let obj = value.as_object().ok_or_else(|| {
+            ValidationResult::from(ValidationError::invalid_type(
+                ValidationType::Object,
+                ValidationType::from(value),
+            ))
+        })?;
+
+        if obj.len() > 1 {
+            return Err(ValidationError::custom(format!(
+                "Expected a single variant key, got {}",
+                obj.len()
+            ))
+            .into());
+        }
+
+        let (tag, body) = match obj.iter().next() {
+            Some(entry) => entry,
+            None => return Err(self.invalid_tag()),
+        };
+
+        let validator = match self.variants.iter().find(|(name, _)| name == tag) {
+            Some((_, validator)) => validator,
+            None => return Err(self.invalid_tag()),
+        };
+
+        match validator.validate_variant(body, opts) {
+            Ok(validated) => Ok((tag.clone(), validated)),
+            Err(mut errors) => {
+                errors.prefix_path(tag.clone());
+                Err(errors)
+            }
+        }
+    }
+
+    fn invalid_tag(&self) -> ValidationResult {
+        ValidationError::invalid_values(
+            self.variants.iter().map(|(name, _)| name.clone()).collect(),
+        )
+        .into()
+    }
+}
+
+impl Default for EnumSchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+trait EnumVariantValidator: Send + Sync + Debug {
+    fn validate_variant(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value>;
+    fn json_schema(&self) -> Value;
+}
+
+#[derive(Debug)]
+struct EnumVariantValidatorImpl<S, T> {
+    schema: S,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T> EnumVariantValidatorImpl<S, T> {
+    fn new(schema: S) -> Self {
+        Self {
+            schema,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T> EnumVariantValidator for EnumVariantValidatorImpl<S, T>
+where
+    S: Schema<T> + Send + Sync + Debug,
+    T: serde::Serialize + Send + Sync + Debug + 'static,
+{
+    fn validate_variant(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let validated = self.schema.validate_with(value, opts)?;
+        into_json_value(validated)
+    }
+
+    fn json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
+}
+
+impl Schema<Value> for EnumSchema {
+    fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let (tag, body) = self.validate_variant_with(value, opts)?;
+
+        let mut result = serde_json::Map::new();
+        result.insert(tag, body);
+        Ok(Value::Object(result))
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let variants: Vec<Value> = self
+            .variants
+            .iter()
+            .map(|(name, validator)| {
+                json!({
+                    "type": "object",
+                    "properties": { name: validator.json_schema() },
+                    "required": [name],
+                    "additionalProperties": false,
+                })
+            })
+            .collect();
+
+        json!({ "oneOf": variants })
+    }
+}
+
+pub fn enum_schema() -> EnumSchema {
+    EnumSchema::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{null, number, object, string, tuple};
+    use serde_json::json;
+
+    fn event_schema() -> EnumSchema {
+        enum_schema()
+            .variant("Click", object().field("x", number()).field("y", number()))
+            .variant("Scroll", object().field("delta", number()))
+    }
+
+    #[test]
+    fn test_enum_validation() {
+        let schema = event_schema();
+
+        assert!(schema.validate(&json!({"Click": {"x": 1, "y": 2}})).is_ok());
+        assert!(schema.validate(&json!({"Scroll": {"delta": 1.5}})).is_ok());
+        assert!(schema.validate(&json!({"Resize": {}})).is_err());
+        assert!(schema.validate(&json!("Click")).is_err());
+    }
+
+    #[test]
+    fn test_validate_variant_returns_tag_and_body() {
+        let schema = event_schema();
+        let (tag, body) = schema
+            .validate_variant(&json!({"Scroll": {"delta": 2}}))
+            .unwrap();
+
+        assert_eq!(tag, "Scroll");
+        assert_eq!(body, json!({"delta": 2.0}));
+    }
+
+    #[test]
+    fn test_validate_returns_tagged_object() {
+        let schema = event_schema();
+        let result = schema.validate(&json!({"Click": {"x": 1, "y": 2}}));
+        assert_eq!(result.unwrap(), json!({"Click": {"x": 1.0, "y": 2.0}}));
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Tag Errors
+    #[test]
+    fn test_unknown_tag_lists_options() {
+        let schema = event_schema();
+        let err = schema.validate(&json!({"Resize": {}})).unwrap_err();
+
+        assert_eq!(err.issues.len(), 1);
+        assert!(err.issues[0].path.is_empty());
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid option: expected one of Click | Scroll"
+        );
+    }
+
+    #[test]
+    fn test_empty_object_rejected() {
+        let schema = event_schema();
+        assert!(schema.validate(&json!({})).is_err());
+    }
+
+    #[test]
+    fn test_multiple_keys_rejected() {
+        let schema = event_schema();
+        let result = schema.validate(&json!({
+            "Click": {"x": 1, "y": 2},
+            "Scroll": {"delta": 1}
+        }));
+        assert!(result.is_err());
+    }
+
+    // Error Paths
+    #[test]
+    fn test_inner_error_prefixed_with_variant() {
+        let schema = event_schema();
+        let err = schema
+            .validate(&json!({"Click": {"x": 1, "y": "two"}}))
+            .unwrap_err();
+
+        assert_eq!(err.issues[0].path, vec!["Click", "y"]);
+    }
+
+    // Variant Shapes
+    #[test]
+    fn test_unit_newtype_and_tuple_variants() {
+        let schema = enum_schema()
+            .variant("Success", null())
+            .variant("Data", string())
+            .variant("Pair", tuple().element(number()).element(number()));
+
+        assert!(schema.validate(&json!({"Success": null})).is_ok());
+        assert!(schema.validate(&json!({"Data": "payload"})).is_ok());
+        assert!(schema.validate(&json!({"Pair": [1, 2]})).is_ok());
+        assert!(schema.validate(&json!({"Data": 1})).is_err());
+        assert!(schema.validate(&json!({"Pair": [1]})).is_err());
+    }
+
+    // Type Rejection
+    #[test]
+    fn test_rejects_non_object() {
+        let schema = event_schema();
+        assert!(schema.validate(&json!(null)).is_err());
+        assert!(schema.validate(&json!([])).is_err());
+        assert!(schema.validate(&json!(1)).is_err());
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = enum_schema()
+            .variant("Scroll", object().field("delta", number()))
+            .variant("Close", null());
+
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Scroll": {
+                                "type": "object",
+                                "properties": {"delta": {"type": "number"}},
+                                "required": ["delta"]
+                            }
+                        },
+                        "required": ["Scroll"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {"Close": {"type": "null"}},
+                        "required": ["Close"],
+                        "additionalProperties": false
+                    }
+                ]
+            })
+        );
+    }
+}
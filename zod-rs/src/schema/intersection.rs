@@ -0,0 +1,290 @@
+use crate::schema::{into_json_value, Schema, ValidateOptions};
+use serde_json::{json, Value};
+use std::fmt::Debug;
+use zod_rs_util::{ValidateResult, ValidationError, ValidationResult};
+
+/// Requires a value to satisfy both schemas and merges their outputs.
+///
+/// Objects merge key by key and arrays of equal length element by element.
+/// Where the two outputs disagree, a side that merely passed the input through
+/// (such as a non-strict object keeping a key it does not know) gives way to
+/// the side that validated it; any other disagreement is an error.
+#[derive(Debug, Clone)]
+pub struct IntersectionSchema<L, R, A, B> {
+    left: L,
+    right: R,
+    _phantom: std::marker::PhantomData<(A, B)>,
+}
+
+impl<L, R, A, B> IntersectionSchema<L, R, A, B> {
+    pub fn new(left: L, right: R) -> Self {
+        Self {
+            left,
+            right,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<L, R, A, B> Schema<Value> for IntersectionSchema<L, R, A, B>
+where
+    L: Schema<A>,
+    R: Schema<B>,
+    A: serde::Serialize + Debug + 'static,
+    B: serde::Serialize + Debug + 'static,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let left = self.left.validate_with(value, opts);
+
+        if opts.abort_early {
+            if let Err(errors) = left {
+                return Err(errors);
+            }
+        }
+
+        let right = self.right.validate_with(value, opts);
+
+        let (left, right) = match (left, right) {
+            (Ok(left), Ok(right)) => (into_json_value(left)?, into_json_value(right)?),
+            (left, right) => {
+                let mut errors = ValidationResult::new();
+                for result in [left.err(), right.err()].into_iter().flatten() {
+                    errors.merge(result);
+                }
+                return Err(errors);
+            }
+        };
+
+        let mut path = Vec::new();
+        merge_values(left, right, value, &mut path).ok_or_else(|| {
+            let mut errors = ValidationResult::new();
+            errors.add_error_at_path(
+                path,
+                ValidationError::custom("Intersection results could not be merged"),
+            );
+            errors
+        })
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "allOf": [self.left.to_json_schema(), self.right.to_json_schema()] })
+    }
+}
+
+/// Merges two validated outputs of `input`. On conflict, returns `None` with
+/// `path` pointing at the conflicting value.
+fn merge_values(left: Value, right: Value, input: &Value, path: &mut Vec<String>) -> Option<Value> {
+    match (left, right) {
+        (Value::Object(mut left), Value::Object(right)) => {
+            for (key, right_value) in right {
+                let merged = match left.remove(&key) {
+                    Some(left_value) => {
+                        path.push(key.clone());
+                        let input = input.get(&key).unwrap_or(&Value::Null);
+                        let merged = merge_values(left_value, right_value, input, path)?;
+                        path.pop();
+                        merged
+                    }
+                    None => right_value,
+                };
+                left.insert(key, merged);
+            }
+            Some(Value::Object(left))
+        }
+        (Value::Array(left), Value::Array(right)) if left.len() == right.len() => {
+            let mut merged = Vec::with_capacity(left.len());
+            for (index, (left, right)) in left.into_iter().zip(right).enumerate() {
+                path.push(index.to_string());
+                let input = input.get(index).unwrap_or(&Value::Null);
+                merged.push(merge_values(left, right, input, path)?);
+                path.pop();
+            }
+            Some(Value::Array(merged))
+        }
+        (left, right) => {
+            if right == *input {
+                Some(left)
+            } else if left == *input {
+                Some(right)
+            } else if same_value(&left, &right) {
+                Some(left)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// JSON equality, except that numbers compare by value (`1` equals `1.0`).
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+pub fn intersection<L, R, A, B>(left: L, right: R) -> IntersectionSchema<L, R, A, B> {
+    IntersectionSchema::new(left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{array, number, object, string, ObjectSchema};
+    use serde_json::json;
+
+    fn has_id() -> ObjectSchema {
+        object().field("id", number().int())
+    }
+
+    fn has_timestamps() -> ObjectSchema {
+        object()
+            .field("created_at", string().datetime())
+            .field("updated_at", string().datetime())
+    }
+
+    #[test]
+    fn test_intersection_validation() {
+        let schema = intersection(has_id(), has_timestamps());
+
+        let value = json!({
+            "id": 1,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z"
+        });
+        assert_eq!(
+            schema.validate(&value).unwrap(),
+            json!({
+                "id": 1.0,
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-02T00:00:00Z"
+            })
+        );
+
+        assert!(schema.validate(&json!({"id": 1})).is_err());
+        assert!(schema.validate(&json!("not an object")).is_err());
+    }
+
+    #[test]
+    fn test_accumulates_errors_from_both_sides() {
+        let schema = intersection(has_id(), has_timestamps());
+        let err = schema
+            .validate(&json!({"id": 1.5, "created_at": "yesterday"}))
+            .unwrap_err();
+
+        let mut paths: Vec<_> = err
+            .issues
+            .iter()
+            .map(|issue| issue.path.join("."))
+            .collect();
+        paths.sort();
+        assert_eq!(paths, vec!["created_at", "id", "updated_at"]);
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[test]
+    fn test_abort_early_skips_right_side() {
+        let schema = intersection(has_id(), has_timestamps());
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+
+        let err = schema.validate_with(&json!({}), opts).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["id"]);
+    }
+
+    #[test]
+    fn test_validated_value_wins_over_passed_through_input() {
+        let schema = intersection(
+            object().field("name", string().trim()),
+            object().field("age", number()),
+        );
+
+        let result = schema
+            .validate(&json!({"name": "  Ada ", "age": 36}))
+            .unwrap();
+        assert_eq!(result, json!({"name": "Ada", "age": 36.0}));
+    }
+
+    #[test]
+    fn test_conflicting_outputs_error_at_path() {
+        let schema = intersection(
+            object().field("name", string().to_lowercase()),
+            object().field("name", string().to_uppercase()),
+        );
+
+        let err = schema.validate(&json!({"name": "Ada"})).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["name"]);
+        assert_eq!(
+            err.issues[0].to_string(),
+            "name: Intersection results could not be merged"
+        );
+    }
+
+    #[test]
+    fn test_primitive_intersection() {
+        let schema = intersection(number().min(0.0), number().max(10.0));
+
+        assert_eq!(schema.validate(&json!(5)).unwrap(), json!(5.0));
+        assert!(schema.validate(&json!(-1)).is_err());
+        assert!(schema.validate(&json!(11)).is_err());
+    }
+
+    #[test]
+    fn test_array_intersection_merges_elements() {
+        let schema = intersection(
+            array(object().field("a", number())),
+            array(object().field("b", string())),
+        );
+
+        let result = schema.validate(&json!([{"a": 1, "b": "x"}])).unwrap();
+        assert_eq!(result, json!([{"a": 1.0, "b": "x"}]));
+    }
+
+    // Fluent Combinator
+    #[test]
+    fn test_and_matches_intersection() {
+        let schema = object()
+            .field("a", number())
+            .and(object().field("b", string()));
+
+        let result = schema.validate(&json!({"a": 1, "b": "x"})).unwrap();
+        assert_eq!(result, json!({"a": 1.0, "b": "x"}));
+        assert!(schema.validate(&json!({"a": 1})).is_err());
+        assert_eq!(
+            schema.to_json_schema(),
+            intersection(object().field("a", number()), object().field("b", string()))
+                .to_json_schema()
+        );
+    }
+
+    #[test]
+    fn test_and_on_strings() {
+        let schema = string().min(3).and(string().starts_with("#"));
+        assert_eq!(schema.validate(&json!("#abc")).unwrap(), json!("#abc"));
+        assert_eq!(schema.validate(&json!("ab")).unwrap_err().issues.len(), 2);
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = intersection(object().field("a", number()), object().field("b", string()));
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "allOf": [
+                    {"type": "object", "properties": {"a": {"type": "number"}}, "required": ["a"]},
+                    {"type": "object", "properties": {"b": {"type": "string"}}, "required": ["b"]}
+                ]
+            })
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::Debug;
+use zod_rs_util::ValidateResult;
+
+#[derive(Debug, Clone)]
+pub struct LabeledSchema<S, T> {
+    inner: S,
+    label: String,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T> LabeledSchema<S, T> {
+    pub fn new(inner: S, label: &str) -> Self {
+        Self {
+            inner,
+            label: label.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T> Schema<T> for LabeledSchema<S, T>
+where
+    S: Schema<T>,
+    T: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        self.inner.validate_with(value, opts).map_err(|mut errors| {
+            errors.prefix_path(self.label.clone());
+            errors
+        })
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.inner.to_json_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{array, number, object, string, Schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_label_validation() {
+        let schema = string().email().label("email");
+
+        assert_eq!(
+            schema.validate(&json!("a@b.co")).unwrap(),
+            "a@b.co".to_string()
+        );
+
+        let err = schema.validate(&json!("x")).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["email"]);
+        assert_eq!(err.issues[0].to_string(), "email: Invalid email address");
+        assert_eq!(err.issues[0].code(), "invalid_format:email");
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Paths
+    #[test]
+    fn test_label_prefixes_nested_paths() {
+        let schema = array(number().positive()).label("scores");
+        let err = schema.validate(&json!([1, -2])).unwrap_err();
+
+        assert_eq!(err.issues[0].path, vec!["scores", "1"]);
+    }
+
+    #[test]
+    fn test_labeled_field_adds_to_field_path() {
+        let schema = object().field("port", number().min(1.0).label("Port"));
+        let err = schema.validate(&json!({"port": 0})).unwrap_err();
+
+        assert_eq!(err.issues[0].path, vec!["port", "Port"]);
+    }
+
+    // Loops
+    #[test]
+    fn test_label_per_value() {
+        let inputs = [("home", json!("a@b.co")), ("work", json!("nope"))];
+
+        let errors: Vec<String> = inputs
+            .iter()
+            .filter_map(|(name, value)| string().email().label(name).validate(value).err())
+            .map(|err| err.issues[0].to_string())
+            .collect();
+        assert_eq!(errors, vec!["work: Invalid email address"]);
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema_unchanged() {
+        let schema = number().int().label("count");
+        assert_eq!(schema.to_json_schema(), json!({"type": "integer"}));
+    }
+}
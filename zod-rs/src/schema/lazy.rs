@@ -0,0 +1,208 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::{json, Value};
+use std::{
+    any::TypeId,
+    cell::RefCell,
+    fmt::{self, Debug},
+    sync::{Arc, OnceLock},
+};
+use zod_rs_util::ValidateResult;
+
+type SchemaFactory<T> = dyn Fn() -> Arc<dyn Schema<T> + Send + Sync> + Send + Sync;
+
+thread_local! {
+    /// Factories of the lazy schemas whose JSON Schema is being built on this thread.
+    static DESCRIBING: RefCell<Vec<TypeId>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the factory pushed onto [`DESCRIBING`], also when describing panics.
+struct DescribingGuard;
+
+impl Drop for DescribingGuard {
+    fn drop(&mut self) {
+        DESCRIBING.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+/// Schema built on first use, for recursive types whose schema would otherwise
+/// have to contain itself. The built schema is cached and shared between clones.
+#[derive(Clone)]
+pub struct LazySchema<T> {
+    init: Arc<SchemaFactory<T>>,
+    init_id: TypeId,
+    schema: Arc<OnceLock<Arc<dyn Schema<T> + Send + Sync>>>,
+}
+
+impl<T> LazySchema<T>
+where
+    T: Debug,
+{
+    pub fn new<S, F>(init: F) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        F: Fn() -> S + Send + Sync + 'static,
+    {
+        Self {
+            init: Arc::new(move || Arc::new(init()) as Arc<dyn Schema<T> + Send + Sync>),
+            init_id: TypeId::of::<F>(),
+            schema: Arc::new(OnceLock::new()),
+        }
+    }
+
+    fn schema(&self) -> &Arc<dyn Schema<T> + Send + Sync> {
+        self.schema.get_or_init(|| (self.init)())
+    }
+}
+
+impl<T> Debug for LazySchema<T> {
+    // The inner schema is not printed: for a recursive type it contains this schema
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazySchema")
+            .field("initialized", &self.schema.get().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Schema<T> for LazySchema<T>
+where
+    T: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        self.schema().validate_with(value, opts)
+    }
+
+    /// Describes the built schema. Where a recursive schema reaches itself again,
+    /// the nested occurrence is described as `{}` (any value) instead of being
+    /// expanded forever.
+    fn to_json_schema(&self) -> Value {
+        let recursive = DESCRIBING.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            if stack.contains(&self.init_id) {
+                return true;
+            }
+            stack.push(self.init_id);
+            false
+        });
+        if recursive {
+            return json!({});
+        }
+
+        let _guard = DescribingGuard;
+        self.schema().to_json_schema()
+    }
+}
+
+pub fn lazy<S, T, F>(init: F) -> LazySchema<T>
+where
+    S: Schema<T> + Send + Sync + 'static,
+    T: Debug,
+    F: Fn() -> S + Send + Sync + 'static,
+{
+    LazySchema::new(init)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{array, object, string, ObjectSchema};
+    use serde_json::json;
+
+    fn category() -> ObjectSchema {
+        object()
+            .field("name", string().min(1))
+            .field("children", array(lazy(category)))
+    }
+
+    #[test]
+    fn test_lazy_validation() {
+        let schema = lazy(|| string().min(2));
+
+        assert!(schema.validate(&json!("ok")).is_ok());
+        assert!(schema.validate(&json!("x")).is_err());
+    }
+
+    #[test]
+    fn test_recursive_tree() {
+        let tree = json!({
+            "name": "root",
+            "children": [
+                {"name": "a", "children": [{"name": "a1", "children": []}]},
+                {"name": "b", "children": []}
+            ]
+        });
+
+        assert!(category().validate(&tree).is_ok());
+    }
+
+    #[test]
+    fn test_recursive_error_path() {
+        let tree = json!({
+            "name": "root",
+            "children": [
+                {"name": "a", "children": [{"name": "", "children": []}]}
+            ]
+        });
+
+        let err = category().validate(&tree).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(
+            err.issues[0].path,
+            vec!["children", "0", "children", "0", "name"]
+        );
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[test]
+    fn test_builds_once_and_shares_between_clones() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+        let schema = lazy(|| {
+            BUILDS.fetch_add(1, Ordering::SeqCst);
+            string()
+        });
+        let clone = schema.clone();
+
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 0);
+        assert!(schema.validate(&json!("a")).is_ok());
+        assert!(clone.validate(&json!("b")).is_ok());
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_debug_does_not_recurse() {
+        let schema = category();
+        assert!(schema
+            .validate(&json!({"name": "x", "children": []}))
+            .is_ok());
+        assert!(format!("{schema:?}").contains("LazySchema"));
+    }
+
+    #[test]
+    fn test_json_schema_stops_at_recursion() {
+        let schema = array(lazy(category));
+        let category_schema = |children: Value| {
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string", "minLength": 1},
+                    "children": {"type": "array", "items": children}
+                },
+                "required": ["children", "name"]
+            })
+        };
+
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"type": "array", "items": category_schema(json!({}))})
+        );
+        // The recursion guard is released afterwards
+        assert_eq!(lazy(category).to_json_schema(), category_schema(json!({})));
+    }
+}
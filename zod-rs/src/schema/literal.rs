@@ -1,5 +1,5 @@
 use crate::schema::Schema;
-use serde_json::Value;
+use serde_json::{json, Value};
 use zod_rs_util::{ValidateResult, ValidationError, ValidationType};
 
 #[derive(Debug, Clone)]
@@ -13,6 +13,13 @@ impl<T: Clone + PartialEq + std::fmt::Debug> LiteralSchema<T> {
     }
 }
 
+/// Matches `T::default()`, e.g. `0`, `false` or the empty string.
+impl<T: Clone + PartialEq + std::fmt::Debug + Default> Default for LiteralSchema<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
 impl Schema<String> for LiteralSchema<&'static str> {
     fn validate(&self, value: &Value) -> ValidateResult<String> {
         match value.as_str() {
@@ -25,6 +32,10 @@ impl Schema<String> for LiteralSchema<&'static str> {
             .into()),
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "const": self.expected })
+    }
 }
 
 impl Schema<String> for LiteralSchema<String> {
@@ -39,6 +50,10 @@ impl Schema<String> for LiteralSchema<String> {
             .into()),
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "const": self.expected })
+    }
 }
 
 impl Schema<f64> for LiteralSchema<f64> {
@@ -53,8 +68,69 @@ impl Schema<f64> for LiteralSchema<f64> {
             .into()),
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "const": self.expected })
+    }
 }
 
+impl Schema<bool> for LiteralSchema<bool> {
+    fn validate(&self, value: &Value) -> ValidateResult<bool> {
+        match value.as_bool() {
+            Some(b) if b == self.expected => Ok(b),
+            Some(_) => Err(ValidationError::invalid_value(self.expected.to_string()).into()),
+            None => Err(ValidationError::invalid_type(
+                ValidationType::Bool,
+                ValidationType::from(value),
+            )
+            .into()),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "const": self.expected })
+    }
+}
+
+/// Integer literals compare exactly, so `literal(1)` accepts `1` and `1.0` but not `1.5`.
+macro_rules! impl_integer_literal {
+    ($($ty:ty),*) => {
+        $(
+            impl Schema<$ty> for LiteralSchema<$ty> {
+                fn validate(&self, value: &Value) -> ValidateResult<$ty> {
+                    let Value::Number(n) = value else {
+                        return Err(ValidationError::invalid_type(
+                            ValidationType::Number,
+                            ValidationType::from(value),
+                        )
+                        .into());
+                    };
+
+                    let expected = self.expected as i128;
+                    let matches = match (n.as_i64(), n.as_u64()) {
+                        (Some(i), _) => i128::from(i) == expected,
+                        (None, Some(u)) => i128::from(u) == expected,
+                        // Only reached for floats, where `1.0` still matches `1`
+                        (None, None) => n.as_f64() == Some(expected as f64),
+                    };
+
+                    if matches {
+                        Ok(self.expected)
+                    } else {
+                        Err(ValidationError::invalid_value(self.expected.to_string()).into())
+                    }
+                }
+
+                fn to_json_schema(&self) -> Value {
+                    json!({ "const": self.expected })
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_literal!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
 pub fn literal<T: Clone + PartialEq + std::fmt::Debug>(value: T) -> LiteralSchema<T> {
     LiteralSchema::new(value)
 }
@@ -168,6 +244,66 @@ mod tests {
         assert!(schema.validate(&json!(0.002)).is_err());
     }
 
+    // Boolean Literal Edge Cases
+    #[test]
+    fn test_bool_literal() {
+        let schema = literal(true);
+        assert!(schema.validate(&json!(true)).unwrap());
+
+        let err = schema.validate(&json!(false)).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "Invalid input: expected true");
+    }
+
+    #[test]
+    fn test_bool_literal_rejects_string() {
+        let schema = literal(false);
+        assert!(schema.validate(&json!("false")).is_err());
+        assert!(schema.validate(&json!(0)).is_err());
+    }
+
+    // Integer Literal Edge Cases
+    #[test]
+    fn test_integer_literal() {
+        let schema = literal(1);
+        assert_eq!(schema.validate(&json!(1)).unwrap(), 1);
+        assert_eq!(schema.validate(&json!(1.0)).unwrap(), 1);
+
+        let err = schema.validate(&json!(1.5)).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "Invalid input: expected 1");
+        assert!(schema.validate(&json!(2)).is_err());
+    }
+
+    #[test]
+    fn test_integer_literal_rejects_string() {
+        let schema = literal(42i64);
+        assert!(schema.validate(&json!("42")).is_err());
+    }
+
+    #[test]
+    fn test_large_integer_literal_compared_exactly() {
+        let schema = literal(u64::MAX);
+        assert!(schema.validate(&json!(u64::MAX)).is_ok());
+        assert!(schema.validate(&json!(u64::MAX - 1)).is_err());
+
+        let schema = literal(i64::MIN);
+        assert!(schema.validate(&json!(i64::MIN)).is_ok());
+        assert!(schema.validate(&json!(i64::MIN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_negative_integer_literal() {
+        let schema = literal(-3i8);
+        assert!(schema.validate(&json!(-3)).is_ok());
+        assert!(schema.validate(&json!(3)).is_err());
+    }
+
+    #[test]
+    fn test_integer_literals_in_union() {
+        let schema = union().variant(literal(1u8)).variant(literal(2u8));
+        assert_eq!(schema.validate(&json!(2)).unwrap(), 2);
+        assert!(schema.validate(&json!(3)).is_err());
+    }
+
     // Type Rejection
     #[test]
     fn test_string_literal_rejects_number() {
@@ -227,4 +363,23 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 42.5);
     }
+
+    #[test]
+    fn test_default_matches_default_value() {
+        let schema = LiteralSchema::<bool>::default();
+        assert!(schema.validate(&json!(false)).is_ok());
+        assert!(schema.validate(&json!(true)).is_err());
+
+        let schema = LiteralSchema::<String>::default();
+        assert!(schema.validate(&json!("")).is_ok());
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(literal("admin").to_json_schema(), json!({"const": "admin"}));
+        assert_eq!(literal(1.5).to_json_schema(), json!({"const": 1.5}));
+        assert_eq!(literal(true).to_json_schema(), json!({"const": true}));
+        assert_eq!(literal(7u32).to_json_schema(), json!({"const": 7}));
+    }
 }
@@ -0,0 +1,56 @@
+use std::{collections::HashMap, hash::Hash};
+use zod_rs_util::{ValidationError, ValidationResult};
+
+/// Custom error messages for the constraints of a leaf schema, keyed by the
+/// schema's own check enum.
+#[derive(Debug, Clone)]
+pub(crate) struct CheckMessages<C: 'static> {
+    all: Option<String>,
+    by_check: HashMap<C, String>,
+    last: &'static [C],
+}
+
+impl<C: Copy + Eq + Hash> CheckMessages<C> {
+    pub(crate) fn new() -> Self {
+        Self {
+            all: None,
+            by_check: HashMap::new(),
+            last: &[],
+        }
+    }
+
+    /// Records the check(s) set by the most recent builder call.
+    pub(crate) fn track(&mut self, checks: &'static [C]) {
+        self.last = checks;
+    }
+
+    /// Returns the check(s) set by the most recent builder call.
+    pub(crate) fn last(&self) -> &'static [C] {
+        self.last
+    }
+
+    pub(crate) fn set_all(&mut self, message: String) {
+        self.all = Some(message);
+    }
+
+    /// Attaches `message` to the most recently tracked check(s), falling back to
+    /// every check when nothing has been tracked yet.
+    pub(crate) fn set_last(&mut self, message: String) {
+        if self.last.is_empty() {
+            self.all = Some(message);
+            return;
+        }
+
+        for check in self.last {
+            self.by_check.insert(*check, message.clone());
+        }
+    }
+
+    /// Returns `error`, or a custom error when a message overrides `check`.
+    pub(crate) fn error(&self, check: C, error: ValidationError) -> ValidationResult {
+        match self.by_check.get(&check).or(self.all.as_ref()) {
+            Some(message) => ValidationError::custom(message.clone()).into(),
+            None => error.into(),
+        }
+    }
+}
@@ -1,28 +1,146 @@
+mod any;
 mod array;
+mod big_int;
 mod boolean;
+mod catch;
+mod describe;
+mod discriminated_union;
+mod enum_values;
+mod enumeration;
+mod intersection;
+mod label;
+mod lazy;
 mod literal;
+mod messages;
 mod null;
+mod nullable;
+mod nullish;
 mod number;
 mod object;
 mod optional;
+mod pipe;
+mod record;
+mod refine;
+mod set;
 mod string;
+mod transform;
 mod tuple;
 mod union;
+mod unknown;
 
+pub use any::*;
 pub use array::*;
+pub use big_int::*;
 pub use boolean::*;
+pub use catch::*;
+pub use describe::*;
+pub use discriminated_union::*;
+pub use enum_values::*;
+pub use enumeration::*;
+pub use intersection::*;
+pub use label::*;
+pub use lazy::*;
 pub use literal::*;
 pub use null::*;
+pub use nullable::*;
+pub use nullish::*;
 pub use number::*;
 pub use object::*;
 pub use optional::*;
+pub use pipe::*;
+pub use record::*;
+pub use refine::*;
+pub use set::*;
 pub use string::*;
+pub use transform::*;
 pub use tuple::*;
 pub use union::*;
+pub use unknown::*;
 
-use serde_json::Value;
-use std::fmt::Debug;
-use zod_rs_util::ValidateResult;
+use serde_json::{json, Value};
+use std::{any::Any, fmt::Debug};
+use zod_rs_util::{ParseError, ValidateResult, ValidationError, ValidationResult};
+
+/// Converts a validated value to JSON for the type-erased containers (object fields,
+/// tuple elements, ...). Values that already are JSON, such as the output of a nested
+/// object schema or an array of them, are moved through without a serialize round-trip.
+pub(crate) fn into_json_value<T>(validated: T) -> ValidateResult<Value>
+where
+    T: serde::Serialize + 'static,
+{
+    let mut slot = Some(validated);
+    let any = &mut slot as &mut dyn Any;
+
+    if let Some(value) = any.downcast_mut::<Option<Value>>() {
+        return Ok(value.take().unwrap_or_default());
+    }
+
+    if let Some(items) = any.downcast_mut::<Option<Vec<Value>>>() {
+        return Ok(Value::Array(items.take().unwrap_or_default()));
+    }
+
+    // `Some(validated)` serializes exactly like `validated`
+    serde_json::to_value(slot).map_err(|e| {
+        ValidationError::custom(format!("Failed to serialize validated value: {}", e)).into()
+    })
+}
+
+/// Deserializes a validated value into `T`. A field that fails to deserialize is
+/// reported at its path, e.g. `id: Deserialization failed: invalid type: ...`.
+pub(crate) fn deserialize_validated<T>(validated: Value, original: &Value) -> ValidateResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    deserialize_restored(crate::__private::restore_number_repr(validated, original))
+}
+
+/// Like [`deserialize_validated`], for a value whose number representations
+/// have already been restored.
+fn deserialize_restored<T>(validated: Value) -> ValidateResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_path_to_error::deserialize(validated).map_err(|error| {
+        let path = error
+            .path()
+            .iter()
+            .filter_map(|segment| match segment {
+                serde_path_to_error::Segment::Seq { index } => Some(index.to_string()),
+                serde_path_to_error::Segment::Map { key } => Some(key.clone()),
+                serde_path_to_error::Segment::Enum { variant } => Some(variant.clone()),
+                serde_path_to_error::Segment::Unknown => None,
+            })
+            .collect();
+
+        let mut result = ValidationResult::new();
+        result.add_error_at_path(
+            path,
+            ValidationError::custom(format!("Deserialization failed: {}", error.inner())),
+        );
+        result
+    })
+}
+
+/// Wraps a JSON Schema so that it also accepts `null`, for optional values.
+pub(crate) fn nullable_json_schema(schema: Value) -> Value {
+    if schema.as_object().is_some_and(|o| o.is_empty()) {
+        return schema;
+    }
+
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+/// Options for [`Schema::validate_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidateOptions {
+    /// Stop at the first failing field, entry or element instead of collecting
+    /// every issue. Defaults to `false`.
+    pub abort_early: bool,
+    /// Coerce strings for every number, boolean and big-int schema, as if each
+    /// had been built with `.coerce()`. Set by [`ObjectSchema::coerce`] for its
+    /// fields. Defaults to `false`.
+    pub coerce: bool,
+}
 
 pub trait Schema<T>: Debug
 where
@@ -31,10 +149,95 @@ where
     /// Validates the value against this schema and returns the validated result.
     fn validate(&self, value: &Value) -> ValidateResult<T>;
 
+    /// Validates the value with the given options. Schemas that contain other
+    /// schemas (objects, arrays, records, ...) pass the options down; leaf
+    /// schemas behave exactly like [`validate`](Self::validate).
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = array(number());
+    /// let opts = ValidateOptions {
+    ///     abort_early: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let err = schema.validate_with(&json!(["a", "b"]), opts).unwrap_err();
+    /// assert_eq!(err.issues.len(), 1);
+    /// ```
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        let _ = opts;
+        self.validate(value)
+    }
+
+    /// Describes this schema as a JSON Schema (draft 2020-12), for tooling such as
+    /// OpenAPI generators or form builders.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = string().min(3).email();
+    /// assert_eq!(
+    ///     schema.to_json_schema(),
+    ///     json!({"type": "string", "minLength": 3, "format": "email"})
+    /// );
+    /// ```
+    ///
+    /// Refinements and transforms have no JSON Schema equivalent and are left out.
+    /// Schemas that do not override this method are described as `{}`, which
+    /// accepts any value.
+    fn to_json_schema(&self) -> Value {
+        json!({})
+    }
+
+    /// Validates the value and returns the typed output, or every issue found.
+    /// This is the recommended entry point; it never panics.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = number().int().min(0.0);
+    ///
+    /// let age: f64 = schema.try_parse(&json!(42)).unwrap();
+    /// assert_eq!(age, 42.0);
+    ///
+    /// let errors = schema.try_parse(&json!(-1.5)).unwrap_err();
+    /// assert_eq!(errors.issues.len(), 1);
+    /// ```
+    fn try_parse(&self, value: &Value) -> ValidateResult<T> {
+        self.validate(value)
+    }
+
+    /// Same as [`try_parse`](Self::try_parse), following the convention of
+    /// `str::parse`.
+    ///
+    /// **Breaking change in 0.5:** this used to panic on validation failure. Use
+    /// [`expect_parse`](Self::expect_parse) where a panic is really wanted.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = string().min(5);
+    /// assert_eq!(schema.parse(&json!("hello")).unwrap(), "hello");
+    /// assert!(schema.parse(&json!("hi")).is_err());
+    /// ```
+    fn parse(&self, value: &Value) -> ValidateResult<T> {
+        self.try_parse(value)
+    }
+
     /// Validates and returns the result, panicking on validation failure.
     ///
     /// # Panics
-    /// Panics if validation fails. Use `safe_parse()` or `validate()` for non-panicking alternatives.
+    /// Panics if validation fails. Use [`parse`](Self::parse) for the
+    /// non-panicking alternative.
     ///
     /// # Example
     /// ```should_panic
@@ -42,19 +245,68 @@ where
     /// use serde_json::json;
     ///
     /// let schema = string().min(5);
-    /// let result = schema.parse(&json!("hi")); // panics: string too short
+    /// let result = schema.expect_parse(&json!("hi")); // panics: string too short
     /// ```
-    fn parse(&self, value: &Value) -> T {
+    fn expect_parse(&self, value: &Value) -> T {
         match self.validate(value) {
             Ok(result) => result,
             Err(errors) => panic!("Validation failed: {errors}"),
         }
     }
 
-    /// Validates the value and returns a Result. This is the recommended method for handling
-    /// validation in production code.
+    /// Same as [`validate`](Self::validate), but times the validation and reports it
+    /// under `name` to the hook set with
+    /// [`set_validation_hook`](crate::metrics::set_validation_hook).
+    #[cfg(feature = "metrics")]
+    fn validate_instrumented(&self, name: &str, value: &Value) -> ValidateResult<T> {
+        let start = std::time::Instant::now();
+        let result = self.validate(value);
+        crate::metrics::report(&crate::metrics::ValidationEvent {
+            schema: name,
+            duration: start.elapsed(),
+            error_count: result
+                .as_ref()
+                .err()
+                .map_or(0, |errors| errors.issues.len()),
+        });
+        result
+    }
+
+    /// Same as [`try_parse`](Self::try_parse): `Ok` with the typed output, or
+    /// `Err` with the [`ValidationResult`](zod_rs_util::ValidationResult) listing
+    /// every issue.
     fn safe_parse(&self, value: &Value) -> ValidateResult<T> {
-        self.validate(value)
+        self.try_parse(value)
+    }
+
+    /// Serializes `value` to JSON and validates it, e.g. to re-check data loaded
+    /// from a database against the rules it was accepted with. A value that fails
+    /// to serialize is reported as a custom error at the root.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Row {
+    ///     name: String,
+    /// }
+    ///
+    /// let schema = object().field("name", string().min(2));
+    /// assert!(schema.check(&Row { name: "Al".into() }).is_ok());
+    /// assert!(schema.check(&Row { name: "A".into() }).is_err());
+    /// ```
+    fn check<V>(&self, value: &V) -> Result<(), ValidationResult>
+    where
+        V: serde::Serialize + ?Sized,
+        Self: Sized,
+    {
+        let value = serde_json::to_value(value).map_err(|error| {
+            ValidationResult::from(ValidationError::custom(format!(
+                "Serialization failed: {error}"
+            )))
+        })?;
+        self.validate(&value).map(|_| ())
     }
 
     fn optional(self) -> OptionalSchema<Self, T>
@@ -64,10 +316,320 @@ where
         OptionalSchema::new(self)
     }
 
+    /// Accepts `null` as `None`. In an object, the key must still be present.
+    fn nullable(self) -> NullableSchema<Self, T>
+    where
+        Self: Sized,
+    {
+        NullableSchema::new(self)
+    }
+
+    /// Accepts `null` as `None`, like [`optional`](Self::optional). Use
+    /// [`ObjectSchema::nullish_field`] for an object key that may be missing as well.
+    fn nullish(self) -> NullishSchema<Self, T>
+    where
+        Self: Sized,
+    {
+        NullishSchema::new(self)
+    }
+
+    /// Wraps this schema as the element schema of an array; same as
+    /// [`array(self)`](crate::array). The result takes the usual
+    /// [`ArraySchema`] builder methods.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = number().positive().array().nonempty().max(3);
+    /// assert_eq!(schema.validate(&json!([1, 2])).unwrap(), vec![1.0, 2.0]);
+    /// assert!(schema.validate(&json!([])).is_err());
+    /// assert!(schema.validate(&json!([1, -2])).is_err());
+    /// assert!(schema.validate(&json!([1, 2, 3, 4])).is_err());
+    ///
+    /// let tags = string().min(1).array().min(1).unique();
+    /// assert!(tags.validate(&json!(["rust", "rust"])).is_err());
+    /// ```
     fn array(self) -> ArraySchema<Self, T>
     where
         Self: Sized,
     {
         ArraySchema::new(self)
     }
+
+    /// Adds a custom predicate that runs against the parsed value after this schema
+    /// validates successfully. A `false` result produces a custom error with `message`.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = string().refine(
+    ///     |s: &String| s.chars().any(|c| c.is_ascii_digit()),
+    ///     "Password must contain a digit",
+    /// );
+    /// assert!(schema.validate(&json!("hunter2")).is_ok());
+    /// assert!(schema.validate(&json!("hunter")).is_err());
+    /// ```
+    fn refine<F>(self, predicate: F, message: &str) -> RefinedSchema<Self, T, F>
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        RefinedSchema::new(self, predicate, message)
+    }
+
+    /// Maps the parsed value into another type after this schema validates successfully.
+    ///
+    /// Transforms only affect the Rust-side output; they are not reflected in
+    /// generated TypeScript schemas.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = string().transform(|s| s.len());
+    /// assert_eq!(schema.validate(&json!("hello")).unwrap(), 5);
+    /// ```
+    fn transform<U, F>(self, f: F) -> TransformSchema<Self, T, U, F>
+    where
+        Self: Sized,
+        F: Fn(T) -> U,
+    {
+        TransformSchema::new(self, f)
+    }
+
+    /// Validates the output of this schema again with `next`, which then decides
+    /// the result: the value is converted back to JSON in between, so `next` may
+    /// check it further or coerce it into another type.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = string().trim().pipe(string().min(1));
+    /// assert_eq!(schema.validate(&json!(" hi ")).unwrap(), "hi");
+    /// assert!(schema.validate(&json!("   ")).is_err());
+    ///
+    /// // Parse a string to a number, then require it to be positive
+    /// let schema = number().coerce().pipe(number().positive());
+    /// assert_eq!(schema.validate(&json!("42")).unwrap(), 42.0);
+    /// assert!(schema.validate(&json!("-1")).is_err());
+    /// ```
+    fn pipe<S, U>(self, next: S) -> PipeSchema<Self, S, T, U>
+    where
+        Self: Sized,
+        S: Schema<U>,
+        T: serde::Serialize + 'static,
+        U: Debug,
+    {
+        PipeSchema::new(self, next)
+    }
+
+    /// Returns `fallback` whenever this schema fails, so validation never errors.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = number().int().min(1.0).catch(3.0);
+    /// assert_eq!(schema.validate(&json!(5)).unwrap(), 5.0);
+    /// assert_eq!(schema.validate(&json!("many")).unwrap(), 3.0);
+    /// ```
+    fn catch(self, fallback: T) -> CatchSchema<Self, T>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        CatchSchema::new(self, fallback)
+    }
+
+    /// Attaches a human-readable description, available through `description()` and
+    /// exported as the JSON Schema `description`. Validation is unchanged unless
+    /// `prefix_errors()` is set on the result.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = number().int().min(1.0).describe("Port");
+    /// assert_eq!(schema.description(), "Port");
+    /// assert_eq!(
+    ///     schema.to_json_schema(),
+    ///     json!({"type": "integer", "minimum": 1, "description": "Port"})
+    /// );
+    /// ```
+    fn describe(self, description: &str) -> DescribedSchema<Self, T>
+    where
+        Self: Sized,
+    {
+        DescribedSchema::new(self, description)
+    }
+
+    /// Names a value validated on its own, such as a bare string checked in a loop:
+    /// every issue path starts with `label`, so errors read
+    /// `email: Invalid email address` instead of just `Invalid email address`. The
+    /// error codes are unchanged. As an object field the label adds to the field
+    /// name, so it is meant for top-level schemas.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = string().email().label("email");
+    /// let err = schema.safe_parse(&json!("x")).unwrap_err();
+    /// assert_eq!(err.issues[0].path, vec!["email"]);
+    /// assert_eq!(err.issues[0].to_string(), "email: Invalid email address");
+    /// ```
+    fn label(self, label: &str) -> LabeledSchema<Self, T>
+    where
+        Self: Sized,
+    {
+        LabeledSchema::new(self, label)
+    }
+
+    /// Accepts values matching either schema; shorthand for a two-variant
+    /// [`union`]. For more options, `union()` keeps them in one flat union.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// // An email address, or nothing at all
+    /// let schema = string().email().or(string().length(0));
+    /// assert!(schema.validate(&json!("")).is_ok());
+    /// assert!(schema.validate(&json!("ada@example.com")).is_ok());
+    /// assert!(schema.validate(&json!("ada")).is_err());
+    /// ```
+    fn or<S>(self, other: S) -> UnionSchema<T>
+    where
+        Self: Sized + Send + Sync + 'static,
+        S: Schema<T> + Send + Sync + 'static,
+    {
+        UnionSchema::new().variant(self).variant(other)
+    }
+
+    /// Requires a value to match both schemas; shorthand for [`intersection`].
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let named = object().field("name", string());
+    /// let aged = object().field("age", number());
+    ///
+    /// let schema = named.and(aged);
+    /// assert!(schema.validate(&json!({"name": "Ada", "age": 36})).is_ok());
+    /// assert!(schema.validate(&json!({"name": "Ada"})).is_err());
+    /// ```
+    fn and<S>(self, other: S) -> IntersectionSchema<Self, S, T, T>
+    where
+        Self: Sized,
+        S: Schema<T>,
+        T: serde::Serialize + 'static,
+    {
+        IntersectionSchema::new(self, other)
+    }
+}
+
+/// A shared reference validates like the schema it points to, which lets a schema
+/// built once (e.g. in a `static`) be handed out without cloning.
+impl<T, S> Schema<T> for &S
+where
+    T: Debug,
+    S: Schema<T> + ?Sized,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        (**self).validate(value)
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        (**self).validate_with(value, opts)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        (**self).to_json_schema()
+    }
+}
+
+/// A type with a schema of its own. `#[derive(ZodSchema)]` implements it, so that
+/// generic code such as the `ZodJson` axum extractor can validate any derived type:
+///
+/// ```
+/// use serde::{de::DeserializeOwned, Deserialize};
+/// use serde_json::{json, Value};
+/// use zod_rs::prelude::*;
+///
+/// fn parse_all<T: HasSchema + DeserializeOwned>(values: &[Value]) -> Vec<T> {
+///     values.iter().filter_map(|v| T::validate_and_parse(v).ok()).collect()
+/// }
+///
+/// #[derive(Deserialize, ZodSchema)]
+/// struct Tag {
+///     #[zod(min_length(1))]
+///     name: String,
+/// }
+///
+/// let tags: Vec<Tag> = parse_all(&[json!({"name": "rust"}), json!({"name": ""})]);
+/// assert_eq!(tags.len(), 1);
+/// ```
+pub trait HasSchema {
+    fn schema() -> impl Schema<Value> + Send + Sync;
+
+    /// The name validations of this type are reported under with the `metrics`
+    /// feature. The derive uses the type's name.
+    fn schema_name() -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Validates the value against [`schema`](Self::schema) and deserializes the
+    /// validated output. The input is only borrowed; it is never cloned.
+    fn validate_and_parse(value: &Value) -> ValidateResult<Self>
+    where
+        Self: serde::de::DeserializeOwned + Sized,
+    {
+        let validated = crate::__private::validate_top_level::<Self>(value)?;
+        deserialize_validated(validated, value)
+    }
+
+    /// Like [`validate_and_parse`](Self::validate_and_parse), but consumes the
+    /// input and frees it before deserializing, so a large payload and its
+    /// validated copy are not both alive while `Self` is built.
+    fn validate_and_parse_owned(value: Value) -> ValidateResult<Self>
+    where
+        Self: serde::de::DeserializeOwned + Sized,
+    {
+        let validated = crate::__private::validate_top_level::<Self>(&value)?;
+        let validated = crate::__private::restore_number_repr(validated, &value);
+        drop(value);
+        deserialize_restored(validated)
+    }
+
+    /// Parses `json` and validates and deserializes it in one call. Malformed JSON
+    /// fails with [`ParseError::Json`](zod_rs_util::ParseError::Json).
+    fn validate_str(json: &str) -> Result<Self, ParseError>
+    where
+        Self: serde::de::DeserializeOwned + Sized,
+    {
+        Self::validate_slice(json.as_bytes())
+    }
+
+    /// Like [`validate_str`](Self::validate_str), for raw bytes such as a request
+    /// body.
+    fn validate_slice(json: &[u8]) -> Result<Self, ParseError>
+    where
+        Self: serde::de::DeserializeOwned + Sized,
+    {
+        let value: Value = serde_json::from_slice(json)?;
+        Ok(Self::validate_and_parse_owned(value)?)
+    }
 }
@@ -1,5 +1,5 @@
 use crate::schema::Schema;
-use serde_json::Value;
+use serde_json::{json, Value};
 use zod_rs_util::{ValidateResult, ValidationError, ValidationType};
 
 #[derive(Debug, Clone)]
@@ -29,6 +29,10 @@ impl Schema<()> for NullSchema {
             .into())
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "type": "null" })
+    }
 }
 
 pub fn null() -> NullSchema {
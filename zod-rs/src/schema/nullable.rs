@@ -0,0 +1,150 @@
+use crate::schema::{nullable_json_schema, Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::Debug;
+use zod_rs_util::ValidateResult;
+
+/// Accepts `null` as `None`. Unlike [`OptionalSchema`](crate::schema::OptionalSchema),
+/// a nullable object field must still be present: see
+/// [`ObjectSchema::nullable_field`](crate::schema::ObjectSchema::nullable_field).
+#[derive(Debug, Clone)]
+pub struct NullableSchema<S, T> {
+    inner: S,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T> NullableSchema<S, T> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T> Schema<Option<T>> for NullableSchema<S, T>
+where
+    S: Schema<T>,
+    T: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<Option<T>> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Option<T>> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            self.inner.validate_with(value, opts).map(Some)
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        nullable_json_schema(self.inner.to_json_schema())
+    }
+}
+
+pub fn nullable<S, T>(schema: S) -> NullableSchema<S, T> {
+    NullableSchema::new(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string};
+    use serde_json::json;
+
+    #[test]
+    fn test_nullable_validation() {
+        let schema = nullable(string());
+
+        assert_eq!(schema.validate(&json!(null)).unwrap(), None);
+        assert_eq!(
+            schema.validate(&json!("hello")).unwrap(),
+            Some("hello".to_string())
+        );
+        assert!(schema.validate(&json!(123)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Object Fields
+    #[test]
+    fn test_nullable_field_truth_table() {
+        let schema = object().nullable_field("parent", number());
+
+        let err = schema.validate(&json!({})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["parent"]);
+        assert_eq!(
+            err.issues[0].to_string(),
+            "parent: Value is required but was not provided"
+        );
+
+        assert_eq!(
+            schema.validate(&json!({"parent": null})).unwrap(),
+            json!({"parent": null})
+        );
+        assert_eq!(
+            schema.validate(&json!({"parent": 1})).unwrap(),
+            json!({"parent": 1.0})
+        );
+        assert!(schema.validate(&json!({"parent": "1"})).is_err());
+    }
+
+    #[test]
+    fn test_optional_field_truth_table() {
+        let schema = object().optional_field("parent", number());
+
+        assert_eq!(schema.validate(&json!({})).unwrap(), json!({}));
+        assert_eq!(
+            schema.validate(&json!({"parent": null})).unwrap(),
+            json!({})
+        );
+        assert_eq!(
+            schema.validate(&json!({"parent": 1})).unwrap(),
+            json!({"parent": 1.0})
+        );
+    }
+
+    #[test]
+    fn test_nullable_method_in_field() {
+        let schema = object().field("id", string().nullable());
+
+        assert!(schema.validate(&json!({})).is_err());
+        assert!(schema.validate(&json!({"id": null})).is_ok());
+    }
+
+    #[test]
+    fn test_partial_makes_nullable_field_omittable() {
+        let schema = object().nullable_field("parent", number()).partial();
+        assert_eq!(schema.validate(&json!({})).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn test_required_keeps_null_allowed() {
+        let schema = object().nullable_field("parent", number()).required();
+
+        assert!(schema.validate(&json!({})).is_err());
+        assert!(schema.validate(&json!({"parent": null})).is_ok());
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(
+            nullable(string()).to_json_schema(),
+            json!({"anyOf": [{"type": "string"}, {"type": "null"}]})
+        );
+
+        let schema = object().nullable_field("parent", number());
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "parent": {"anyOf": [{"type": "number"}, {"type": "null"}]}
+                },
+                "required": ["parent"]
+            })
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::schema::{nullable_json_schema, Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::Debug;
+use zod_rs_util::ValidateResult;
+
+/// Accepts `null` as `None`, like Zod's `.nullish()`. A bare JSON value can't be
+/// missing, so this behaves like [`OptionalSchema`](crate::schema::OptionalSchema);
+/// for an object field that may also be left out, use
+/// [`ObjectSchema::nullish_field`](crate::schema::ObjectSchema::nullish_field).
+#[derive(Debug, Clone)]
+pub struct NullishSchema<S, T> {
+    inner: S,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T> NullishSchema<S, T> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T> Schema<Option<T>> for NullishSchema<S, T>
+where
+    S: Schema<T>,
+    T: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<Option<T>> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Option<T>> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            self.inner.validate_with(value, opts).map(Some)
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        nullable_json_schema(self.inner.to_json_schema())
+    }
+}
+
+pub fn nullish<S, T>(schema: S) -> NullishSchema<S, T> {
+    NullishSchema::new(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[test]
+    fn test_nullish_validation() {
+        let schema = nullish(string());
+
+        assert_eq!(schema.validate(&json!(null)).unwrap(), None);
+        assert_eq!(
+            schema.validate(&json!("hello")).unwrap(),
+            Some("hello".to_string())
+        );
+        assert!(schema.validate(&json!(123)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Object Fields
+    #[test]
+    fn test_nullish_field_truth_table() {
+        let schema = object().nullish_field("parent", number());
+
+        assert_eq!(
+            schema.validate(&json!({})).unwrap(),
+            json!({"parent": null})
+        );
+        assert_eq!(
+            schema.validate(&json!({"parent": null})).unwrap(),
+            json!({"parent": null})
+        );
+        assert_eq!(
+            schema.validate(&json!({"parent": 1})).unwrap(),
+            json!({"parent": 1.0})
+        );
+        assert!(schema.validate(&json!({"parent": "1"})).is_err());
+    }
+
+    #[test]
+    fn test_nullish_field_parses_to_none() {
+        #[derive(Debug, Deserialize)]
+        struct Node {
+            parent: Option<f64>,
+        }
+
+        let schema = object().nullish_field("parent", number().positive());
+        for input in [json!({}), json!({"parent": null})] {
+            let node: Node = schema.parse_into(&input).unwrap();
+            assert_eq!(node.parent, None);
+        }
+
+        let node: Node = schema.parse_into(&json!({"parent": 2})).unwrap();
+        assert_eq!(node.parent, Some(2.0));
+        assert!(schema.parse_into::<Node>(&json!({"parent": -2})).is_err());
+    }
+
+    #[test]
+    fn test_nullish_method_in_field_requires_key() {
+        let schema = object().field("id", string().nullish());
+
+        assert!(schema.validate(&json!({})).is_err());
+        assert!(schema.validate(&json!({"id": null})).is_ok());
+    }
+
+    #[test]
+    fn test_required_rejects_missing_and_null() {
+        let schema = object().nullish_field("parent", number()).required();
+
+        assert!(schema.validate(&json!({})).is_err());
+        assert!(schema.validate(&json!({"parent": null})).is_err());
+        assert!(schema.validate(&json!({"parent": 1})).is_ok());
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(
+            string().nullish().to_json_schema(),
+            json!({"anyOf": [{"type": "string"}, {"type": "null"}]})
+        );
+
+        let schema = object().nullish_field("parent", number());
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "parent": {"anyOf": [{"type": "number"}, {"type": "null"}], "default": null}
+                },
+                "required": []
+            })
+        );
+    }
+}
@@ -1,72 +1,198 @@
-use crate::schema::Schema;
-use serde_json::Value;
+use crate::schema::{messages::CheckMessages, Schema, ValidateOptions};
+use serde_json::{json, Map, Value};
 use zod_rs_util::{
     NumberConstraint, ValidateResult, ValidationError, ValidationOrigin, ValidationType,
 };
 
+const MULTIPLE_OF_EPSILON: f64 = 1e-9;
+
+/// JavaScript's `Number.MAX_SAFE_INTEGER`, 2^53 - 1.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum NumberCheck {
+    Int,
+    SafeInt,
+    Finite,
+    Min,
+    Max,
+    Positive,
+    Negative,
+    NonNegative,
+    NonPositive,
+    MultipleOf,
+    Step,
+}
+
 #[derive(Debug, Clone)]
 pub struct NumberSchema {
     min: Option<f64>,
+    min_inclusive: bool,
     max: Option<f64>,
+    max_inclusive: bool,
     integer: bool,
+    safe_int: bool,
     positive: bool,
     negative: bool,
     nonnegative: bool,
     nonpositive: bool,
     finite: bool,
+    multiple_of: Option<f64>,
+    step: Option<(f64, f64)>,
+    coerce: bool,
+    messages: CheckMessages<NumberCheck>,
 }
 
 impl NumberSchema {
     pub fn new() -> Self {
         Self {
             min: None,
+            min_inclusive: true,
             max: None,
+            max_inclusive: true,
             integer: false,
+            safe_int: false,
             positive: false,
             negative: false,
             nonnegative: false,
             nonpositive: false,
             finite: false,
+            multiple_of: None,
+            step: None,
+            coerce: false,
+            messages: CheckMessages::new(),
         }
     }
 
-    pub fn min(mut self, min: f64) -> Self {
-        self.min = Some(min);
+    /// Inclusive lower bound; alias for [`NumberSchema::gte`].
+    pub fn min(self, min: f64) -> Self {
+        self.gte(min)
+    }
+
+    /// Inclusive upper bound; alias for [`NumberSchema::lte`].
+    pub fn max(self, max: f64) -> Self {
+        self.lte(max)
+    }
+
+    /// Requires the number to be strictly greater than `value`.
+    pub fn gt(mut self, value: f64) -> Self {
+        self.min = Some(value);
+        self.min_inclusive = false;
+        self.messages.track(&[NumberCheck::Min]);
+        self
+    }
+
+    /// Requires the number to be greater than or equal to `value`.
+    pub fn gte(mut self, value: f64) -> Self {
+        self.min = Some(value);
+        self.min_inclusive = true;
+        self.messages.track(&[NumberCheck::Min]);
+        self
+    }
+
+    /// Requires the number to be strictly less than `value`.
+    pub fn lt(mut self, value: f64) -> Self {
+        self.max = Some(value);
+        self.max_inclusive = false;
+        self.messages.track(&[NumberCheck::Max]);
         self
     }
 
-    pub fn max(mut self, max: f64) -> Self {
-        self.max = Some(max);
+    /// Requires the number to be less than or equal to `value`.
+    pub fn lte(mut self, value: f64) -> Self {
+        self.max = Some(value);
+        self.max_inclusive = true;
+        self.messages.track(&[NumberCheck::Max]);
         self
     }
 
     pub fn int(mut self) -> Self {
         self.integer = true;
+        self.messages.track(&[NumberCheck::Int]);
+        self
+    }
+
+    /// Requires an integer within JavaScript's safe range, ±(2^53 - 1), like Zod's
+    /// `.safe()`. Larger integers cannot be represented exactly on the TypeScript side.
+    pub fn safe_int(mut self) -> Self {
+        self.safe_int = true;
+        self.messages.track(&[NumberCheck::SafeInt]);
         self
     }
 
     pub fn positive(mut self) -> Self {
         self.positive = true;
+        self.messages.track(&[NumberCheck::Positive]);
         self
     }
 
     pub fn negative(mut self) -> Self {
         self.negative = true;
+        self.messages.track(&[NumberCheck::Negative]);
         self
     }
 
     pub fn nonnegative(mut self) -> Self {
         self.nonnegative = true;
+        self.messages.track(&[NumberCheck::NonNegative]);
         self
     }
 
     pub fn nonpositive(mut self) -> Self {
         self.nonpositive = true;
+        self.messages.track(&[NumberCheck::NonPositive]);
         self
     }
 
     pub fn finite(mut self) -> Self {
         self.finite = true;
+        self.messages.track(&[NumberCheck::Finite]);
+        self
+    }
+
+    /// Requires the number to be a multiple of `divisor`, with a small tolerance so
+    /// that e.g. `0.3` counts as a multiple of `0.1`.
+    pub fn multiple_of(mut self, divisor: f64) -> Self {
+        self.multiple_of = Some(divisor);
+        self.messages.track(&[NumberCheck::MultipleOf]);
+        self
+    }
+
+    /// Requires the number to lie on a grid of `step` starting at `start`, such as
+    /// `step(9.0, 15.0)` for 9, 24, 39, ... (and -6, -21, ... below the start).
+    /// Uses the same tolerance as [`multiple_of`](Self::multiple_of), which is
+    /// the special case `step(0.0, n)`. Combine with [`min`](Self::min) to
+    /// reject values below the start.
+    pub fn step(mut self, start: f64, step: f64) -> Self {
+        self.step = Some((start, step));
+        self.messages.track(&[NumberCheck::Step]);
+        self
+    }
+
+    /// Also accepts numbers sent as strings, as by query strings and form bodies.
+    /// The string is trimmed and parsed as a decimal number such as `"25"`,
+    /// `"-3.5"`, `"+4"` or `"1e3"`; the other checks then run on the parsed value.
+    /// Empty strings, non-finite values like `"NaN"` or `"inf"`, and anything
+    /// else that does not parse (`"abc"`, `"12px"`, `"0x1A"`) are rejected.
+    pub fn coerce(mut self) -> Self {
+        self.coerce = true;
+        self
+    }
+
+    /// Replaces the error message of every failing constraint on this schema.
+    /// Type errors (e.g. a string where a number was expected) are not affected.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.messages.set_all(message.into());
+        self
+    }
+
+    /// Replaces the error message of the constraint added just before it, e.g.
+    /// `number().min(18.0).with_message("You must be an adult")`.
+    ///
+    /// Takes precedence over [`message`](Self::message). Called before any
+    /// constraint, it behaves like `message`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.messages.set_last(message.into());
         self
     }
 }
@@ -79,7 +205,15 @@ impl Default for NumberSchema {
 
 impl Schema<f64> for NumberSchema {
     fn validate(&self, value: &Value) -> ValidateResult<f64> {
-        let num = match value.as_f64() {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<f64> {
+        let num = match value {
+            Value::String(s) if self.coerce || opts.coerce => parse_number(s),
+            _ => value.as_f64(),
+        };
+        let num = match num {
             Some(n) => n,
             None => {
                 return Err(ValidationError::invalid_type(
@@ -91,57 +225,231 @@ impl Schema<f64> for NumberSchema {
         };
 
         if self.integer && num.fract() != 0.0 {
-            return Err(ValidationError::invalid_type(
-                ValidationType::custom("integer"),
-                ValidationType::custom("float"),
-            )
-            .into());
+            return Err(self.messages.error(
+                NumberCheck::Int,
+                ValidationError::invalid_type(
+                    ValidationType::custom("integer"),
+                    ValidationType::custom("float"),
+                ),
+            ));
+        }
+
+        if self.safe_int && (num.fract() != 0.0 || num.abs() > MAX_SAFE_INTEGER) {
+            return Err(self.messages.error(
+                NumberCheck::SafeInt,
+                ValidationError::invalid_number(NumberConstraint::SafeInt),
+            ));
         }
 
         if self.finite && !num.is_finite() {
-            return Err(ValidationError::invalid_number(NumberConstraint::Finite).into());
+            return Err(self.messages.error(
+                NumberCheck::Finite,
+                ValidationError::invalid_number(NumberConstraint::Finite),
+            ));
         }
 
         if let Some(min) = self.min {
-            if num < min {
-                return Err(ValidationError::too_small(
-                    ValidationOrigin::Number,
-                    min.to_string(),
-                    true,
-                )
-                .into());
+            let too_small = if self.min_inclusive {
+                num < min
+            } else {
+                num <= min
+            };
+            if too_small {
+                return Err(self.messages.error(
+                    NumberCheck::Min,
+                    ValidationError::too_small(
+                        ValidationOrigin::Number,
+                        min.to_string(),
+                        self.min_inclusive,
+                    )
+                    .with_received(num.to_string()),
+                ));
             }
         }
 
         if let Some(max) = self.max {
-            if num > max {
-                return Err(ValidationError::too_big(
-                    ValidationOrigin::Number,
-                    max.to_string(),
-                    true,
-                )
-                .into());
+            let too_big = if self.max_inclusive {
+                num > max
+            } else {
+                num >= max
+            };
+            if too_big {
+                return Err(self.messages.error(
+                    NumberCheck::Max,
+                    ValidationError::too_big(
+                        ValidationOrigin::Number,
+                        max.to_string(),
+                        self.max_inclusive,
+                    )
+                    .with_received(num.to_string()),
+                ));
             }
         }
 
         if self.positive && num <= 0.0 {
-            return Err(ValidationError::invalid_number(NumberConstraint::Positive).into());
+            return Err(self.messages.error(
+                NumberCheck::Positive,
+                ValidationError::invalid_number(NumberConstraint::Positive),
+            ));
         }
 
         if self.negative && num >= 0.0 {
-            return Err(ValidationError::invalid_number(NumberConstraint::Negative).into());
+            return Err(self.messages.error(
+                NumberCheck::Negative,
+                ValidationError::invalid_number(NumberConstraint::Negative),
+            ));
         }
 
         if self.nonnegative && num < 0.0 {
-            return Err(ValidationError::invalid_number(NumberConstraint::NonNegative).into());
+            return Err(self.messages.error(
+                NumberCheck::NonNegative,
+                ValidationError::invalid_number(NumberConstraint::NonNegative),
+            ));
         }
 
         if self.nonpositive && num > 0.0 {
-            return Err(ValidationError::invalid_number(NumberConstraint::NonPositive).into());
+            return Err(self.messages.error(
+                NumberCheck::NonPositive,
+                ValidationError::invalid_number(NumberConstraint::NonPositive),
+            ));
+        }
+
+        if let Some(divisor) = self.multiple_of {
+            if !is_multiple_of(num, divisor) {
+                return Err(self.messages.error(
+                    NumberCheck::MultipleOf,
+                    ValidationError::invalid_number(NumberConstraint::MultipleOf(
+                        divisor.to_string(),
+                    )),
+                ));
+            }
+        }
+
+        if let Some((start, step)) = self.step {
+            if !is_multiple_of(num - start, step) {
+                return Err(self.messages.error(
+                    NumberCheck::Step,
+                    ValidationError::invalid_number(NumberConstraint::Step {
+                        start: start.to_string(),
+                        step: step.to_string(),
+                    }),
+                ));
+            }
         }
 
         Ok(num)
     }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = Map::new();
+        let kind = if self.integer || self.safe_int {
+            "integer"
+        } else {
+            "number"
+        };
+        schema.insert("type".into(), kind.into());
+
+        let mut lower = vec![];
+        if let Some(min) = self.min {
+            lower.push((min, !self.min_inclusive));
+        }
+        if self.positive {
+            lower.push((0.0, true));
+        }
+        if self.nonnegative {
+            lower.push((0.0, false));
+        }
+        if self.safe_int {
+            lower.push((-MAX_SAFE_INTEGER, false));
+        }
+        if let Some((min, exclusive)) = tightest_bound(&lower, true) {
+            let key = if exclusive {
+                "exclusiveMinimum"
+            } else {
+                "minimum"
+            };
+            schema.insert(key.into(), json_number(min));
+        }
+
+        let mut upper = vec![];
+        if let Some(max) = self.max {
+            upper.push((max, !self.max_inclusive));
+        }
+        if self.negative {
+            upper.push((0.0, true));
+        }
+        if self.nonpositive {
+            upper.push((0.0, false));
+        }
+        if self.safe_int {
+            upper.push((MAX_SAFE_INTEGER, false));
+        }
+        if let Some((max, exclusive)) = tightest_bound(&upper, false) {
+            let key = if exclusive {
+                "exclusiveMaximum"
+            } else {
+                "maximum"
+            };
+            schema.insert(key.into(), json_number(max));
+        }
+
+        if let Some(divisor) = self.multiple_of {
+            schema.insert("multipleOf".into(), json_number(divisor));
+        }
+        // Only a grid that contains 0 can be described with `multipleOf`
+        if let Some((start, step)) = self.step {
+            if is_multiple_of(start, step) && !schema.contains_key("multipleOf") {
+                schema.insert("multipleOf".into(), json_number(step));
+            }
+        }
+
+        if self.coerce {
+            return json!({ "anyOf": [schema, { "type": "string" }] });
+        }
+
+        Value::Object(schema)
+    }
+}
+
+/// Parses a coerced string, see [`NumberSchema::coerce`].
+fn parse_number(s: &str) -> Option<f64> {
+    s.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+/// Picks the strictest of several `(bound, exclusive)` pairs: the largest lower
+/// bound or the smallest upper bound, preferring the exclusive one on a tie.
+fn tightest_bound(bounds: &[(f64, bool)], lower: bool) -> Option<(f64, bool)> {
+    bounds.iter().copied().reduce(|current, next| {
+        if next.0 == current.0 {
+            if next.1 {
+                next
+            } else {
+                current
+            }
+        } else if (next.0 > current.0) == lower {
+            next
+        } else {
+            current
+        }
+    })
+}
+
+/// Writes whole numbers without a fraction, so `min(13.0)` becomes `13`.
+fn json_number(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < i64::MAX as f64 {
+        Value::from(n as i64)
+    } else {
+        Value::from(n)
+    }
+}
+
+fn is_multiple_of(num: f64, divisor: f64) -> bool {
+    if divisor == 0.0 {
+        return false;
+    }
+
+    let quotient = num / divisor;
+    (quotient - quotient.round()).abs() < MULTIPLE_OF_EPSILON
 }
 
 pub fn number() -> NumberSchema {
@@ -235,6 +543,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_float_fails_int() {
         let schema = number().int();
         assert!(schema.validate(&json!(5.5)).is_err());
@@ -248,6 +557,47 @@ mod tests {
         assert!(schema.validate(&json!(5.0000001)).is_err());
     }
 
+    // Safe Integer
+    #[test]
+    fn test_safe_int_boundaries() {
+        let schema = number().safe_int();
+        // 2^53 - 1 and its negation are the largest safe integers
+        assert!(schema.validate(&json!(9_007_199_254_740_991_i64)).is_ok());
+        assert!(schema.validate(&json!(-9_007_199_254_740_991_i64)).is_ok());
+        // 2^53 is representable but no longer safe
+        assert!(schema.validate(&json!(9_007_199_254_740_992_i64)).is_err());
+        assert!(schema.validate(&json!(-9_007_199_254_740_992_i64)).is_err());
+        assert!(schema.validate(&json!(1e300)).is_err());
+    }
+
+    #[test]
+    fn test_safe_int_rejects_floats() {
+        let schema = number().safe_int();
+        assert!(schema.validate(&json!(0)).is_ok());
+        assert!(schema.validate(&json!(3.0)).is_ok());
+
+        let err = schema.validate(&json!(1.5)).unwrap_err();
+        assert_eq!(
+            err.issues[0].error,
+            ValidationError::invalid_number(NumberConstraint::SafeInt)
+        );
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid number: must be a safe integer"
+        );
+    }
+
+    #[test]
+    fn test_safe_int_custom_message() {
+        let schema = number()
+            .safe_int()
+            .with_message("ID too large for JavaScript");
+        assert_eq!(
+            schema.validate(&json!(u64::MAX)).unwrap_err().issues[0].to_string(),
+            "ID too large for JavaScript"
+        );
+    }
+
     // Positive/Negative Constraint Edge Cases
     #[test]
     fn test_zero_with_positive() {
@@ -316,6 +666,69 @@ mod tests {
         assert!(schema.validate(&json!(5.0001)).is_err());
     }
 
+    #[test]
+    fn test_gt_exclusive_boundary() {
+        let schema = number().gt(0.0);
+        assert!(schema.validate(&json!(0.0001)).is_ok());
+        assert!(schema.validate(&json!(0.0)).is_err());
+    }
+
+    #[test]
+    fn test_gte_inclusive_boundary() {
+        let schema = number().gte(0.0);
+        assert!(schema.validate(&json!(0.0)).is_ok());
+        assert!(schema.validate(&json!(-0.0001)).is_err());
+    }
+
+    #[test]
+    fn test_lt_exclusive_boundary() {
+        let schema = number().lt(1.0);
+        assert!(schema.validate(&json!(0.9999)).is_ok());
+        assert!(schema.validate(&json!(1.0)).is_err());
+    }
+
+    #[test]
+    fn test_lte_inclusive_boundary() {
+        let schema = number().lte(1.0);
+        assert!(schema.validate(&json!(1.0)).is_ok());
+        assert!(schema.validate(&json!(1.0001)).is_err());
+    }
+
+    #[test]
+    fn test_exclusive_bound_messages() {
+        let schema = number().gt(0.0).lt(10.0);
+        assert_eq!(
+            schema.validate(&json!(0)).unwrap_err().issues[0].to_string(),
+            "Too small: expected number to have > 0, received 0"
+        );
+        assert_eq!(
+            schema.validate(&json!(10)).unwrap_err().issues[0].to_string(),
+            "Too big: expected number to have < 10, received 10"
+        );
+    }
+
+    #[test]
+    fn test_inclusive_bound_messages() {
+        let schema = number().min(0.0).max(10.0);
+        assert_eq!(
+            schema.validate(&json!(-1)).unwrap_err().issues[0].to_string(),
+            "Too small: expected number to have >= 0, received -1"
+        );
+        assert_eq!(
+            schema.validate(&json!(11)).unwrap_err().issues[0].to_string(),
+            "Too big: expected number to have <= 10, received 11"
+        );
+    }
+
+    #[test]
+    fn test_later_bound_overrides_earlier() {
+        let schema = number().min(5.0).gt(5.0);
+        assert!(schema.validate(&json!(5.0)).is_err());
+
+        let schema = number().lt(5.0).max(5.0);
+        assert!(schema.validate(&json!(5.0)).is_ok());
+    }
+
     #[test]
     fn test_negative_min() {
         let schema = number().min(-10.0);
@@ -428,4 +841,251 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 42.0);
     }
+
+    // Multiple Of
+    #[test]
+    fn test_multiple_of() {
+        let schema = number().multiple_of(15.0);
+        assert!(schema.validate(&json!(0)).is_ok());
+        assert!(schema.validate(&json!(45)).is_ok());
+        assert!(schema.validate(&json!(-30)).is_ok());
+        assert!(schema.validate(&json!(20)).is_err());
+    }
+
+    #[test]
+    fn test_multiple_of_float_tolerance() {
+        let schema = number().multiple_of(0.1);
+        assert!(schema.validate(&json!(0.3)).is_ok());
+        assert!(schema.validate(&json!(1.2)).is_ok());
+        assert!(schema.validate(&json!(0.35)).is_err());
+
+        let cents = number().multiple_of(0.01);
+        assert!(cents.validate(&json!(19.99)).is_ok());
+        assert!(cents.validate(&json!(19.999)).is_err());
+    }
+
+    #[test]
+    fn test_multiple_of_zero_divisor_fails() {
+        let schema = number().multiple_of(0.0);
+        assert!(schema.validate(&json!(0)).is_err());
+        assert!(schema.validate(&json!(5)).is_err());
+    }
+
+    #[test]
+    fn test_multiple_of_error_message() {
+        let schema = number().multiple_of(5.0);
+        let err = schema.validate(&json!(7)).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid number: must be a multiple of 5"
+        );
+    }
+
+    // Step
+    #[test]
+    fn test_step_from_offset() {
+        let schema = number().step(9.0, 15.0);
+        assert!(schema.validate(&json!(9)).is_ok());
+        assert!(schema.validate(&json!(24)).is_ok());
+        assert!(schema.validate(&json!(-6)).is_ok());
+        assert!(schema.validate(&json!(15)).is_err());
+
+        let err = schema.validate(&json!(10)).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid number: must be 9 plus a multiple of 15"
+        );
+    }
+
+    #[test]
+    fn test_step_float_tolerance() {
+        let schema = number().step(0.1, 0.2);
+        assert!(schema.validate(&json!(0.3)).is_ok());
+        assert!(schema.validate(&json!(0.7)).is_ok());
+        assert!(schema.validate(&json!(0.4)).is_err());
+    }
+
+    #[test]
+    fn test_step_from_zero_matches_multiple_of() {
+        let step = number().step(0.0, 0.25);
+        let multiple_of = number().multiple_of(0.25);
+        for n in [0.0, 0.5, 1.75, -0.25, 0.3] {
+            assert_eq!(
+                step.validate(&json!(n)).is_ok(),
+                multiple_of.validate(&json!(n)).is_ok()
+            );
+        }
+
+        assert!(number().step(1.0, 0.0).validate(&json!(1)).is_err());
+    }
+
+    #[test]
+    fn test_step_custom_message() {
+        let schema = number()
+            .min(9.0)
+            .step(9.0, 15.0)
+            .with_message("Pick a quarter-hour slot");
+        assert_eq!(
+            schema.validate(&json!(10)).unwrap_err().issues[0].to_string(),
+            "Pick a quarter-hour slot"
+        );
+    }
+
+    #[test]
+    fn test_step_json_schema() {
+        // A grid through 0 is a `multipleOf`; any other grid has no JSON Schema equivalent
+        assert_eq!(
+            number().step(30.0, 15.0).to_json_schema(),
+            json!({"type": "number", "multipleOf": 15})
+        );
+        assert_eq!(
+            number().step(9.0, 15.0).to_json_schema(),
+            json!({"type": "number"})
+        );
+    }
+
+    // Custom Messages
+    #[test]
+    fn test_with_message_overrides_preceding_constraint() {
+        let schema = number()
+            .min(18.0)
+            .with_message("You must be an adult")
+            .max(150.0);
+
+        let err = schema.validate(&json!(12)).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "You must be an adult");
+
+        let err = schema.validate(&json!(200)).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Too big: expected number to have <= 150, received 200"
+        );
+    }
+
+    #[test]
+    fn test_message_applies_to_every_constraint() {
+        let schema = number()
+            .int()
+            .positive()
+            .message("Expected a positive integer");
+
+        assert_eq!(
+            schema.validate(&json!(1.5)).unwrap_err().issues[0].to_string(),
+            "Expected a positive integer"
+        );
+        assert_eq!(
+            schema.validate(&json!(-1)).unwrap_err().issues[0].to_string(),
+            "Expected a positive integer"
+        );
+        assert!(schema.validate(&json!(3)).is_ok());
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = number().int().min(13.0).max(120.0);
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"type": "integer", "minimum": 13, "maximum": 120})
+        );
+        assert_eq!(
+            number().gt(0.5).lt(1.5).multiple_of(0.25).to_json_schema(),
+            json!({
+                "type": "number",
+                "exclusiveMinimum": 0.5,
+                "exclusiveMaximum": 1.5,
+                "multipleOf": 0.25
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_safe_int() {
+        assert_eq!(
+            number().safe_int().to_json_schema(),
+            json!({
+                "type": "integer",
+                "minimum": -9_007_199_254_740_991_i64,
+                "maximum": 9_007_199_254_740_991_i64
+            })
+        );
+        assert_eq!(
+            number().safe_int().nonnegative().to_json_schema(),
+            json!({"type": "integer", "minimum": 0, "maximum": 9_007_199_254_740_991_i64})
+        );
+    }
+
+    #[test]
+    fn test_json_schema_uses_tightest_bound() {
+        assert_eq!(
+            number().min(0.0).positive().to_json_schema(),
+            json!({"type": "number", "exclusiveMinimum": 0})
+        );
+        assert_eq!(
+            number().min(5.0).nonnegative().to_json_schema(),
+            json!({"type": "number", "minimum": 5})
+        );
+        assert_eq!(
+            number().max(-1.0).nonpositive().to_json_schema(),
+            json!({"type": "number", "maximum": -1})
+        );
+    }
+
+    // Coercion
+    #[test]
+    fn test_coerce_parses_numeric_strings() {
+        let schema = number().coerce();
+        assert_eq!(schema.validate(&json!("25")).unwrap(), 25.0);
+        assert_eq!(schema.validate(&json!("-3.5")).unwrap(), -3.5);
+        assert_eq!(schema.validate(&json!("+4")).unwrap(), 4.0);
+        assert_eq!(schema.validate(&json!("1e3")).unwrap(), 1000.0);
+        assert_eq!(schema.validate(&json!(" 7 ")).unwrap(), 7.0);
+        assert_eq!(schema.validate(&json!(8)).unwrap(), 8.0);
+    }
+
+    #[test]
+    fn test_coerce_rejects_garbage() {
+        let schema = number().coerce();
+        for input in [
+            "abc",
+            "",
+            "   ",
+            "12px",
+            "0x1A",
+            "1,000",
+            "NaN",
+            "inf",
+            "-infinity",
+        ] {
+            let err = schema.validate(&json!(input)).unwrap_err();
+            assert_eq!(
+                err.issues[0].to_string(),
+                "Invalid input: expected number, received string",
+                "{input:?}"
+            );
+        }
+        assert!(schema.validate(&json!(true)).is_err());
+        assert!(schema.validate(&json!(null)).is_err());
+    }
+
+    #[test]
+    fn test_coerce_runs_checks_on_parsed_value() {
+        let schema = number().int().min(18.0).coerce();
+        assert_eq!(schema.validate(&json!("21")).unwrap(), 21.0);
+        assert!(schema.validate(&json!("17")).is_err());
+        assert!(schema.validate(&json!("18.5")).is_err());
+    }
+
+    #[test]
+    fn test_without_coerce_strings_rejected() {
+        assert!(number().validate(&json!("25")).is_err());
+    }
+
+    #[test]
+    fn test_coerce_json_schema() {
+        assert_eq!(
+            number().min(1.0).coerce().to_json_schema(),
+            json!({"anyOf": [{"type": "number", "minimum": 1}, {"type": "string"}]})
+        );
+    }
 }
@@ -1,19 +1,108 @@
-use crate::schema::Schema;
+use crate::schema::{
+    deserialize_validated, into_json_value, nullable_json_schema, EnumValuesSchema, NullableSchema,
+    Schema, ValidateOptions,
+};
 use serde_json::Value;
-use std::{collections::HashMap, fmt::Debug, sync::Arc};
-use zod_rs_util::{ValidateResult, ValidationError, ValidationResult, ValidationType};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
+use zod_rs_util::{Locale, ValidateResult, ValidationError, ValidationResult, ValidationType};
+
+/// How an object schema without [`strict`](ObjectSchema::strict),
+/// [`strip`](ObjectSchema::strip), [`passthrough`](ObjectSchema::passthrough) or
+/// [`catchall`](ObjectSchema::catchall) treats keys it does not declare. See
+/// [`set_default_object_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ObjectMode {
+    /// Copy unknown keys into the output unchanged.
+    #[default]
+    Passthrough,
+    /// Accept unknown keys but leave them out of the output, like Zod.
+    Strip,
+    /// Reject unknown keys with an unrecognized-keys error.
+    Strict,
+}
+
+// Indexed by discriminant, in declaration order
+const OBJECT_MODES: [ObjectMode; 3] = [
+    ObjectMode::Passthrough,
+    ObjectMode::Strip,
+    ObjectMode::Strict,
+];
+
+static DEFAULT_OBJECT_MODE: AtomicU8 = AtomicU8::new(ObjectMode::Passthrough as u8);
+
+/// Sets the process-wide [`ObjectMode`] of object schemas, including derived
+/// ones, that do not choose a mode of their own. Defaults to
+/// [`ObjectMode::Passthrough`]. Call it once at startup: schemas read it every
+/// time they validate, so changing it later also changes schemas already built.
+pub fn set_default_object_mode(mode: ObjectMode) {
+    DEFAULT_OBJECT_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Returns the mode set by [`set_default_object_mode`].
+pub fn default_object_mode() -> ObjectMode {
+    OBJECT_MODES[DEFAULT_OBJECT_MODE.load(Ordering::Relaxed) as usize]
+}
+
+/// What happens to keys that are not declared on the schema.
+#[derive(Debug, Clone)]
+enum UnknownKeys {
+    /// Whatever [`default_object_mode`] says when the schema validates.
+    Default,
+    Passthrough,
+    Strip,
+    Catchall(Arc<dyn ObjectFieldValidator>),
+    Strict,
+}
+
+impl UnknownKeys {
+    /// Orders the policies from the most to the least lenient, for `merge`.
+    fn strictness(&self) -> u8 {
+        match self {
+            Self::Default | Self::Passthrough => 0,
+            Self::Strip => 1,
+            Self::Catchall(_) => 2,
+            Self::Strict => 3,
+        }
+    }
+
+    /// Replaces `Default` with the policy of the current default mode.
+    fn resolve(&self) -> &Self {
+        match self {
+            Self::Default => match default_object_mode() {
+                ObjectMode::Passthrough => &Self::Passthrough,
+                ObjectMode::Strip => &Self::Strip,
+                ObjectMode::Strict => &Self::Strict,
+            },
+            other => other,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ObjectSchema {
     fields: HashMap<String, Arc<dyn ObjectFieldValidator>>,
-    strict: bool,
+    /// Output key of each renamed field, by its input key.
+    renames: HashMap<String, String>,
+    unknown_keys: UnknownKeys,
+    locale: Option<Locale>,
+    coerce: bool,
 }
 
 impl ObjectSchema {
     pub fn new() -> Self {
         Self {
             fields: HashMap::new(),
-            strict: false,
+            renames: HashMap::new(),
+            unknown_keys: UnknownKeys::Default,
+            locale: None,
+            coerce: false,
         }
     }
 
@@ -22,29 +111,480 @@ impl ObjectSchema {
         S: Schema<T> + Send + Sync + 'static,
         T: serde::Serialize + Send + Sync + Debug + 'static,
     {
-        self.fields.insert(
-            name.to_string(),
-            Arc::new(RequiredFieldValidator::new(schema)),
-        );
+        self.insert_field(name, Arc::new(RequiredFieldValidator::new(schema)));
         self
     }
 
+    /// Adds a field that may be missing or `null`; either way it is left out of the output.
+    ///
+    /// | input       | result                |
+    /// |-------------|-----------------------|
+    /// | key missing | `Ok`, key omitted     |
+    /// | `null`      | `Ok`, key omitted     |
+    /// | value       | validated by `schema` |
     pub fn optional_field<S, T>(mut self, name: &str, schema: S) -> Self
     where
         S: Schema<T> + Send + Sync + 'static,
         T: serde::Serialize + Send + Sync + Debug + 'static,
     {
-        self.fields.insert(
-            name.to_string(),
-            Arc::new(OptionalFieldValidator::new(schema)),
+        self.insert_field(name, Arc::new(OptionalFieldValidator::new(schema)));
+        self
+    }
+
+    /// Adds a field that must be present but may be `null`, as in JSON-RPC style APIs
+    /// that tell "not sent" apart from "explicitly cleared".
+    ///
+    /// | input       | result                |
+    /// |-------------|-----------------------|
+    /// | key missing | required error        |
+    /// | `null`      | `Ok`, `null` kept     |
+    /// | value       | validated by `schema` |
+    pub fn nullable_field<S, T>(self, name: &str, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        T: serde::Serialize + Send + Sync + Debug + 'static,
+    {
+        self.field(name, NullableSchema::new(schema))
+    }
+
+    /// Adds a field that may be missing, `null` or set, like Zod's `.nullish()`. Missing
+    /// and `null` both come out as `null`, so the key always deserializes, to `None`
+    /// for an `Option`.
+    ///
+    /// | input       | result                |
+    /// |-------------|-----------------------|
+    /// | key missing | `Ok`, `null` added    |
+    /// | `null`      | `Ok`, `null` kept     |
+    /// | value       | validated by `schema` |
+    pub fn nullish_field<S, T>(mut self, name: &str, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        T: serde::Serialize + Send + Sync + Debug + 'static,
+    {
+        self.insert_field(
+            name,
+            Arc::new(DefaultFieldValidator::new(schema, Value::Null)),
         );
         self
     }
 
+    /// Adds an optional field that falls back to `default` when the value is missing
+    /// or `null`. The default is trusted as-is and is not run through `schema`.
+    ///
+    /// # Panics
+    /// Panics if `default` cannot be serialized to JSON.
+    pub fn default_field<S, T>(mut self, name: &str, schema: S, default: T) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        T: serde::Serialize + Send + Sync + Debug + 'static,
+    {
+        let default = serde_json::to_value(default)
+            .unwrap_or_else(|e| panic!("Invalid default value for field '{}': {}", name, e));
+        self.insert_field(name, Arc::new(DefaultFieldValidator::new(schema, default)));
+        self
+    }
+
+    /// Rejects keys that are not declared on the schema with an
+    /// unrecognized-keys error.
     pub fn strict(mut self) -> Self {
-        self.strict = true;
+        self.unknown_keys = UnknownKeys::Strict;
+        self
+    }
+
+    /// Accepts keys that are not declared on the schema but leaves them out of
+    /// the validated output.
+    pub fn strip(mut self) -> Self {
+        self.unknown_keys = UnknownKeys::Strip;
+        self
+    }
+
+    /// Accepts keys that are not declared on the schema and copies them into the
+    /// validated output unchanged. This is the default, unless changed with
+    /// [`set_default_object_mode`].
+    pub fn passthrough(mut self) -> Self {
+        self.unknown_keys = UnknownKeys::Passthrough;
+        self
+    }
+
+    /// Validates every key that is not declared on the schema against `schema`,
+    /// reporting failures under that key. Replaces [`strict`](Self::strict),
+    /// [`strip`](Self::strip) and [`passthrough`](Self::passthrough).
+    pub fn catchall<S, T>(mut self, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        T: serde::Serialize + Send + Sync + Debug + 'static,
+    {
+        self.unknown_keys = UnknownKeys::Catchall(Arc::new(RequiredFieldValidator::new(schema)));
+        self
+    }
+
+    /// Makes every field optional: a missing or `null` value passes and is omitted
+    /// from the output. Calling `partial()` more than once has no further effect.
+    pub fn partial(mut self) -> Self {
+        self.fields = self
+            .fields
+            .into_iter()
+            .map(|(name, validator)| (name, validator.partial()))
+            .collect();
+        self
+    }
+
+    /// Combines the fields of both schemas. Fields in `other` replace fields of the
+    /// same name, and the result handles unknown keys like the stricter of the two:
+    /// strict over catchall over strip over passthrough. Between two catchalls, the
+    /// one from `other` wins. A schema that never chose a policy defers to the other.
+    ///
+    /// # Panics
+    /// Panics if a [`rename`](Self::rename) in either schema writes to the name of a
+    /// field in the result, or if two renamed fields would share an output key.
+    pub fn merge(mut self, other: ObjectSchema) -> Self {
+        self.fields.extend(other.fields);
+        self.renames.extend(other.renames);
+        self.check_renames();
+        if !matches!(other.unknown_keys, UnknownKeys::Default)
+            && other.unknown_keys.strictness() >= self.unknown_keys.strictness()
+        {
+            self.unknown_keys = other.unknown_keys;
+        }
+        self.locale = self.locale.or(other.locale);
+        self.coerce |= other.coerce;
+        self
+    }
+
+    /// Alias for [`merge`](Self::merge).
+    pub fn extend(self, fields: ObjectSchema) -> Self {
+        self.merge(fields)
+    }
+
+    /// Keeps only the named fields. Names that are not declared on the schema are
+    /// ignored.
+    pub fn pick(mut self, names: &[&str]) -> Self {
+        self.fields.retain(|name, _| names.contains(&name.as_str()));
+        self.renames
+            .retain(|from, _| names.contains(&from.as_str()));
+        self
+    }
+
+    /// Removes the named fields. Names that are not declared on the schema are
+    /// ignored.
+    pub fn omit(mut self, names: &[&str]) -> Self {
+        self.fields
+            .retain(|name, _| !names.contains(&name.as_str()));
+        self.renames
+            .retain(|from, _| !names.contains(&from.as_str()));
         self
     }
+
+    /// Validates the field `from` as usual, but writes it to the output under `to`,
+    /// e.g. to adapt a legacy payload to a canonical shape. Errors keep reporting
+    /// the input key, and strict mode still expects `from` in the input.
+    ///
+    /// An undeclared key that shares its name with `to` is left out of the output,
+    /// so that the renamed field always wins.
+    ///
+    /// # Panics
+    /// Panics if `from` is not a declared field, if `to` is the name of another
+    /// declared field, or if another field is already renamed to `to`. Declaring a
+    /// field named `to` afterwards, directly or through [`merge`](Self::merge),
+    /// panics as well.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = object()
+    ///     .field("userName", string().min(1))
+    ///     .rename("userName", "user_name")
+    ///     .strict();
+    ///
+    /// let value = schema.validate(&json!({"userName": "ada"})).unwrap();
+    /// assert_eq!(value, json!({"user_name": "ada"}));
+    ///
+    /// let err = schema.validate(&json!({"userName": ""})).unwrap_err();
+    /// assert_eq!(err.issues[0].path, vec!["userName"]);
+    /// ```
+    pub fn rename(mut self, from: &str, to: &str) -> Self {
+        if !self.fields.contains_key(from) {
+            panic!(
+                "Cannot rename '{}' to '{}': '{}' is not a declared field",
+                from, to, from
+            );
+        }
+        if from != to && self.fields.contains_key(to) {
+            panic!(
+                "Cannot rename '{}' to '{}': '{}' is already a declared field",
+                from, to, to
+            );
+        }
+        if let Some((other, _)) = self
+            .renames
+            .iter()
+            .find(|(other, target)| *target == to && *other != from)
+        {
+            panic!(
+                "Cannot rename '{}' to '{}': '{}' is already renamed to '{}'",
+                from, to, other, to
+            );
+        }
+
+        self.renames.insert(from.to_string(), to.to_string());
+        self
+    }
+
+    /// Declares a field, making sure it does not take the output key of a renamed
+    /// field.
+    fn insert_field(&mut self, name: &str, validator: Arc<dyn ObjectFieldValidator>) {
+        if let Some((from, _)) = self
+            .renames
+            .iter()
+            .find(|(from, to)| *to == name && *from != name)
+        {
+            panic!(
+                "Cannot declare field '{}': '{}' is already renamed to '{}'",
+                name, from, name
+            );
+        }
+
+        self.fields.insert(name.to_string(), validator);
+    }
+
+    /// Panics if two fields would be written to the same output key.
+    fn check_renames(&self) {
+        for (from, to) in &self.renames {
+            if from != to && self.fields.contains_key(to) {
+                panic!(
+                    "Cannot rename '{}' to '{}': '{}' is already a declared field",
+                    from, to, to
+                );
+            }
+            if let Some((other, _)) = self
+                .renames
+                .iter()
+                .find(|(other, target)| *target == to && *other != from)
+            {
+                panic!(
+                    "Cannot rename '{}' to '{}': '{}' is already renamed to '{}'",
+                    from, to, other, to
+                );
+            }
+        }
+    }
+
+    /// A schema accepting one of the declared field names, like Zod's `.keyof()`,
+    /// e.g. for a `sort` parameter. Other strings fail with `invalid_values` listing
+    /// the names in alphabetical order.
+    pub fn keyof(&self) -> EnumValuesSchema {
+        let mut names: Vec<&str> = self.fields.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        EnumValuesSchema::new(&names)
+    }
+
+    /// Inverse of [`partial`](Self::partial): makes every field required, so a
+    /// missing or `null` value fails with a required error.
+    pub fn required(mut self) -> Self {
+        self.fields = self
+            .fields
+            .into_iter()
+            .map(|(name, validator)| (name, validator.required()))
+            .collect();
+        self
+    }
+
+    /// Stamps the issues this schema reports with `locale`, so that `Display` and
+    /// `to_string()` render them in it instead of the
+    /// [default locale](zod_rs_util::set_default_locale). Issues from a nested
+    /// object with its own locale keep that locale.
+    ///
+    /// # Example
+    /// ```
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = object().field("name", string()).locale(Locale::Fr);
+    /// let err = schema.validate(&json!({})).unwrap_err();
+    /// assert_eq!(err.issues[0].to_string(), "name: Valeur requise mais non fournie");
+    /// ```
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Coerces strings in every number, boolean and big-int field, as if each
+    /// field schema had been built with `.coerce()`. Meant for payloads that
+    /// encode everything as strings, such as form bodies or query strings decoded
+    /// into JSON. The same rules apply: numbers are trimmed and parsed, booleans
+    /// must be exactly `"true"` or `"false"`, and anything else fails with the
+    /// usual type error.
+    ///
+    /// Coercion reaches nested objects, arrays, records and tuples too. It only
+    /// ever adds coercion: a field schema with its own `.coerce()` coerces
+    /// whether or not the object does, and strings stay strings in string, enum
+    /// and literal fields.
+    ///
+    /// # Example
+    /// ```
+    /// use serde::Deserialize;
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let schema = object()
+    ///     .field("age", number().int().min(0.0))
+    ///     .optional_field("active", boolean())
+    ///     .coerce();
+    ///
+    /// let value = schema.validate(&json!({"age": "25", "active": "true"})).unwrap();
+    /// assert_eq!(value, json!({"age": 25.0, "active": true}));
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct Filter {
+    ///     age: u32,
+    ///     active: Option<bool>,
+    /// }
+    ///
+    /// let filter: Filter = schema.parse_into(&json!({"age": "25", "active": "true"})).unwrap();
+    /// assert_eq!((filter.age, filter.active), (25, Some(true)));
+    ///
+    /// let err = schema.validate(&json!({"age": "old"})).unwrap_err();
+    /// assert_eq!(
+    ///     err.issues[0].to_string(),
+    ///     "age: Invalid input: expected number, received string"
+    /// );
+    /// ```
+    pub fn coerce(mut self) -> Self {
+        self.coerce = true;
+        self
+    }
+
+    /// Validates the value and deserializes the validated output into `T` in one
+    /// call. Validation errors are returned as usual; a field that passes the schema
+    /// but fails to deserialize is reported at its path.
+    ///
+    /// # Example
+    /// ```
+    /// use serde::Deserialize;
+    /// use zod_rs::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct Port {
+    ///     number: u16,
+    /// }
+    ///
+    /// let schema = object().field("number", number().int());
+    /// let port: Port = schema.parse_into(&json!({"number": 8080})).unwrap();
+    /// assert_eq!(port.number, 8080);
+    ///
+    /// let err = schema.parse_into::<Port>(&json!({"number": 70000})).unwrap_err();
+    /// assert_eq!(err.issues[0].path, vec!["number"]);
+    /// ```
+    pub fn parse_into<T>(&self, value: &Value) -> ValidateResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let validated = self.validate(value)?;
+        deserialize_validated(validated, value)
+    }
+
+    /// Whether `key` is a declared field or the output key of a renamed one, which
+    /// undeclared keys must not overwrite.
+    fn is_declared_key(&self, key: &str) -> bool {
+        self.fields.contains_key(key) || self.renames.values().any(|to| to == key)
+    }
+
+    fn validate_fields(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let obj = match value.as_object() {
+            Some(o) => o,
+            None => {
+                return Err(ValidationError::invalid_type(
+                    ValidationType::Object,
+                    ValidationType::from(value),
+                )
+                .into());
+            }
+        };
+
+        let mut result = serde_json::Map::new();
+        let mut validation_result = ValidationResult::new();
+
+        for (field_name, validator) in &self.fields {
+            let field_value = obj.get(field_name);
+            match validator.validate_field(field_value, opts) {
+                Ok(validated_value) => {
+                    // An optional field without a value is left out, unless it has a default
+                    if !validated_value.is_null()
+                        || !validator.is_optional()
+                        || validator.default_value().is_some()
+                    {
+                        let output_name = self.renames.get(field_name).unwrap_or(field_name);
+                        result.insert(output_name.clone(), validated_value);
+                    }
+                }
+                Err(mut errors) => {
+                    errors.prefix_path(field_name.clone());
+
+                    if opts.abort_early {
+                        return Err(errors);
+                    }
+                    validation_result.merge(errors);
+                }
+            }
+        }
+
+        match self.unknown_keys.resolve() {
+            UnknownKeys::Strict => {
+                let mut unrecognized_keys = vec![];
+
+                for key in obj.keys() {
+                    if !self.fields.contains_key(key) {
+                        unrecognized_keys.push(key.clone());
+                    }
+                }
+
+                if !unrecognized_keys.is_empty() {
+                    validation_result.add_error_at_path(
+                        vec![],
+                        ValidationError::unrecognized_keys(unrecognized_keys),
+                    );
+                }
+            }
+            UnknownKeys::Strip => {}
+            UnknownKeys::Catchall(validator) => {
+                for (key, value) in obj {
+                    if self.is_declared_key(key) {
+                        continue;
+                    }
+                    if opts.abort_early && !validation_result.is_empty() {
+                        break;
+                    }
+
+                    match validator.validate_field(Some(value), opts) {
+                        Ok(validated_value) => {
+                            result.insert(key.clone(), validated_value);
+                        }
+                        Err(mut errors) => {
+                            errors.prefix_path(key.clone());
+                            validation_result.merge(errors);
+                        }
+                    }
+                }
+            }
+            // `resolve` never returns `Default`
+            UnknownKeys::Default | UnknownKeys::Passthrough => {
+                for (key, value) in obj {
+                    if !self.is_declared_key(key) {
+                        result.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        if validation_result.is_empty() {
+            Ok(Value::Object(result))
+        } else {
+            Err(validation_result)
+        }
+    }
 }
 
 impl Default for ObjectSchema {
@@ -54,8 +594,16 @@ impl Default for ObjectSchema {
 }
 
 trait ObjectFieldValidator: Send + Sync + Debug {
-    fn validate_field(&self, value: Option<&Value>) -> ValidateResult<Value>;
+    fn validate_field(&self, value: Option<&Value>, opts: ValidateOptions)
+        -> ValidateResult<Value>;
     fn is_optional(&self) -> bool;
+    fn partial(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator>;
+    fn required(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator>;
+    /// JSON Schema of a present, non-null field value.
+    fn json_schema(&self) -> Value;
+    fn default_value(&self) -> Option<&Value> {
+        None
+    }
 }
 
 #[derive(Debug)]
@@ -75,17 +623,18 @@ impl<S, T> RequiredFieldValidator<S, T> {
 
 impl<S, T> ObjectFieldValidator for RequiredFieldValidator<S, T>
 where
-    S: Schema<T> + Send + Sync + Debug,
-    T: serde::Serialize + Send + Sync + Debug,
+    S: Schema<T> + Send + Sync + Debug + 'static,
+    T: serde::Serialize + Send + Sync + Debug + 'static,
 {
-    fn validate_field(&self, value: Option<&Value>) -> ValidateResult<Value> {
+    fn validate_field(
+        &self,
+        value: Option<&Value>,
+        opts: ValidateOptions,
+    ) -> ValidateResult<Value> {
         match value {
             Some(v) => {
-                let validated = self.schema.validate(v)?;
-                serde_json::to_value(validated).map_err(|e| {
-                    ValidationError::custom(format!("Failed to serialize validated value: {}", e))
-                        .into()
-                })
+                let validated = self.schema.validate_with(v, opts)?;
+                into_json_value(validated)
             }
             None => Err(ValidationError::required().into()),
         }
@@ -94,6 +643,18 @@ where
     fn is_optional(&self) -> bool {
         false
     }
+
+    fn partial(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        Arc::new(PartialFieldValidator { inner: self })
+    }
+
+    fn required(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self
+    }
+
+    fn json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
 }
 
 #[derive(Debug)]
@@ -113,17 +674,18 @@ impl<S, T> OptionalFieldValidator<S, T> {
 
 impl<S, T> ObjectFieldValidator for OptionalFieldValidator<S, T>
 where
-    S: Schema<T> + Send + Sync + Debug,
-    T: serde::Serialize + Send + Sync + Debug,
+    S: Schema<T> + Send + Sync + Debug + 'static,
+    T: serde::Serialize + Send + Sync + Debug + 'static,
 {
-    fn validate_field(&self, value: Option<&Value>) -> ValidateResult<Value> {
+    fn validate_field(
+        &self,
+        value: Option<&Value>,
+        opts: ValidateOptions,
+    ) -> ValidateResult<Value> {
         match value {
             Some(v) if !v.is_null() => {
-                let validated = self.schema.validate(v)?;
-                serde_json::to_value(validated).map_err(|e| {
-                    ValidationError::custom(format!("Failed to serialize validated value: {}", e))
-                        .into()
-                })
+                let validated = self.schema.validate_with(v, opts)?;
+                into_json_value(validated)
             }
             _ => Ok(Value::Null),
         }
@@ -132,96 +694,231 @@ where
     fn is_optional(&self) -> bool {
         true
     }
+
+    fn partial(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self
+    }
+
+    fn required(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        Arc::new(NonOptionalFieldValidator { inner: self })
+    }
+
+    fn json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
 }
 
-impl Schema<Value> for ObjectSchema {
-    fn validate(&self, value: &Value) -> ValidateResult<Value> {
-        let obj = match value.as_object() {
-            Some(o) => o,
-            None => {
-                return Err(ValidationError::invalid_type(
-                    ValidationType::Object,
-                    ValidationType::from(value),
-                )
-                .into());
-            }
-        };
+#[derive(Debug)]
+struct DefaultFieldValidator<S, T> {
+    schema: S,
+    default: Value,
+    _phantom: std::marker::PhantomData<T>,
+}
 
-        let mut result = serde_json::Map::new();
-        let mut validation_result = ValidationResult::new();
+impl<S, T> DefaultFieldValidator<S, T> {
+    fn new(schema: S, default: Value) -> Self {
+        Self {
+            schema,
+            default,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
 
-        for (field_name, validator) in &self.fields {
-            let field_value = obj.get(field_name);
-            match validator.validate_field(field_value) {
-                Ok(validated_value) => {
-                    if !validated_value.is_null() || !validator.is_optional() {
-                        result.insert(field_name.clone(), validated_value);
-                    }
-                }
-                Err(mut errors) => {
-                    errors.prefix_path(field_name.clone());
-                    validation_result.merge(errors);
-                }
+impl<S, T> ObjectFieldValidator for DefaultFieldValidator<S, T>
+where
+    S: Schema<T> + Send + Sync + Debug + 'static,
+    T: serde::Serialize + Send + Sync + Debug + 'static,
+{
+    fn validate_field(
+        &self,
+        value: Option<&Value>,
+        opts: ValidateOptions,
+    ) -> ValidateResult<Value> {
+        match value {
+            Some(v) if !v.is_null() => {
+                let validated = self.schema.validate_with(v, opts)?;
+                into_json_value(validated)
             }
+            _ => Ok(self.default.clone()),
         }
+    }
 
-        if self.strict {
-            let mut unrecognized_keys = vec![];
+    fn is_optional(&self) -> bool {
+        true
+    }
 
-            for key in obj.keys() {
-                if !self.fields.contains_key(key) {
-                    unrecognized_keys.push(key.clone());
-                }
-            }
+    fn partial(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self
+    }
 
-            if !unrecognized_keys.is_empty() {
-                validation_result.add_error_at_path(
-                    vec![],
-                    ValidationError::unrecognized_keys(unrecognized_keys),
-                );
-            }
-        } else {
-            for (key, value) in obj {
-                if !self.fields.contains_key(key) {
-                    result.insert(key.clone(), value.clone());
-                }
-            }
-        }
+    fn required(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        Arc::new(NonOptionalFieldValidator { inner: self })
+    }
 
-        if validation_result.is_empty() {
-            Ok(Value::Object(result))
-        } else {
-            Err(validation_result)
-        }
+    fn json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
+
+    fn default_value(&self) -> Option<&Value> {
+        Some(&self.default)
     }
 }
 
-pub fn object() -> ObjectSchema {
-    ObjectSchema::new()
+/// Wraps a required field so that a missing or `null` value is accepted.
+#[derive(Debug)]
+struct PartialFieldValidator {
+    inner: Arc<dyn ObjectFieldValidator>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::schema::{array, number, string};
-    use serde_json::json;
+impl ObjectFieldValidator for PartialFieldValidator {
+    fn validate_field(
+        &self,
+        value: Option<&Value>,
+        opts: ValidateOptions,
+    ) -> ValidateResult<Value> {
+        match value {
+            Some(v) if !v.is_null() => self.inner.validate_field(Some(v), opts),
+            _ => Ok(Value::Null),
+        }
+    }
 
-    #[test]
-    fn test_object_validation() {
-        let schema = object()
-            .field("name", string().min(1))
-            .field("age", number().min(0.0))
-            .optional_field("email", string().email());
+    fn is_optional(&self) -> bool {
+        true
+    }
 
-        let schema_strict = schema.clone().strict();
+    fn partial(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self
+    }
 
-        let schema_with_username = schema.clone().field("username", string().min(3));
+    fn required(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self.inner.clone()
+    }
 
-        assert!(schema_with_username
-            .validate(&json!({
-                "name": "John",
-                "age": 25,
-                "email": "john@example.com",
+    fn json_schema(&self) -> Value {
+        self.inner.json_schema()
+    }
+}
+
+/// Wraps an optional field so that a missing or `null` value is rejected.
+#[derive(Debug)]
+struct NonOptionalFieldValidator {
+    inner: Arc<dyn ObjectFieldValidator>,
+}
+
+impl ObjectFieldValidator for NonOptionalFieldValidator {
+    fn validate_field(
+        &self,
+        value: Option<&Value>,
+        opts: ValidateOptions,
+    ) -> ValidateResult<Value> {
+        match value {
+            Some(v) if !v.is_null() => self.inner.validate_field(Some(v), opts),
+            _ => Err(ValidationError::required().into()),
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        false
+    }
+
+    fn partial(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self.inner.clone()
+    }
+
+    fn required(self: Arc<Self>) -> Arc<dyn ObjectFieldValidator> {
+        self
+    }
+
+    fn json_schema(&self) -> Value {
+        self.inner.json_schema()
+    }
+}
+
+impl Schema<Value> for ObjectSchema {
+    fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let opts = ValidateOptions {
+            coerce: opts.coerce || self.coerce,
+            ..opts
+        };
+        let result = self.validate_fields(value, opts);
+        match self.locale {
+            Some(locale) => result.map_err(|errors| errors.with_locale(locale)),
+            None => result,
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = vec![];
+
+        for (name, validator) in &self.fields {
+            let mut schema = validator.json_schema();
+
+            if validator.is_optional() {
+                schema = nullable_json_schema(schema);
+                if let (Some(default), Value::Object(schema)) =
+                    (validator.default_value(), &mut schema)
+                {
+                    schema.insert("default".into(), default.clone());
+                }
+            } else {
+                required.push(name.clone());
+            }
+
+            properties.insert(name.clone(), schema);
+        }
+        // Field order is not tracked, so keep the output stable
+        required.sort();
+
+        let mut schema = serde_json::Map::new();
+        schema.insert("type".into(), "object".into());
+        schema.insert("properties".into(), properties.into());
+        schema.insert("required".into(), required.into());
+        match self.unknown_keys.resolve() {
+            UnknownKeys::Strict => {
+                schema.insert("additionalProperties".into(), false.into());
+            }
+            UnknownKeys::Catchall(validator) => {
+                schema.insert("additionalProperties".into(), validator.json_schema());
+            }
+            UnknownKeys::Default | UnknownKeys::Passthrough | UnknownKeys::Strip => {}
+        }
+
+        Value::Object(schema)
+    }
+}
+
+pub fn object() -> ObjectSchema {
+    ObjectSchema::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{array, number, string};
+    use serde_json::json;
+
+    #[test]
+    fn test_object_validation() {
+        let schema = object()
+            .field("name", string().min(1))
+            .field("age", number().min(0.0))
+            .optional_field("email", string().email());
+
+        let schema_strict = schema.clone().strict();
+
+        let schema_with_username = schema.clone().field("username", string().min(3));
+
+        assert!(schema_with_username
+            .validate(&json!({
+                "name": "John",
+                "age": 25,
+                "email": "john@example.com",
                 "username":"j.doe"
             }))
             .is_ok());
@@ -417,6 +1114,139 @@ mod tests {
         assert_eq!(value.get("extra").unwrap(), "preserved");
     }
 
+    // Strip Mode
+    #[test]
+    fn test_strip_removes_extra_keys() {
+        let schema = object().field("name", string()).strip();
+        let result = schema
+            .validate(&json!({"name": "John", "password": "hunter2", "role": "admin"}))
+            .unwrap();
+        assert_eq!(result, json!({"name": "John"}));
+    }
+
+    #[test]
+    fn test_strip_keeps_declared_optional_fields() {
+        let schema = object()
+            .field("name", string())
+            .optional_field("email", string())
+            .strip();
+
+        let result = schema
+            .validate(&json!({"name": "John", "email": "j@x.io", "extra": 1}))
+            .unwrap();
+        assert_eq!(result, json!({"name": "John", "email": "j@x.io"}));
+    }
+
+    #[test]
+    fn test_strip_still_validates_fields() {
+        let schema = object().field("name", string()).strip();
+        let err = schema
+            .validate(&json!({"name": 1, "extra": "x"}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["name"]);
+    }
+
+    #[test]
+    fn test_strip_applies_to_nested_objects_only_when_set() {
+        let schema = object()
+            .field("inner", object().field("a", number()).strip())
+            .field("b", number());
+
+        let result = schema
+            .validate(&json!({"inner": {"a": 1, "x": 2}, "b": 3, "y": 4}))
+            .unwrap();
+        assert_eq!(result, json!({"inner": {"a": 1.0}, "b": 3.0, "y": 4}));
+    }
+
+    // Passthrough Mode
+    #[test]
+    fn test_passthrough_overrides_strict() {
+        let schema = object().field("name", string()).strict().passthrough();
+        let result = schema
+            .validate(&json!({"name": "John", "extra": true}))
+            .unwrap();
+        assert_eq!(result, json!({"name": "John", "extra": true}));
+    }
+
+    #[test]
+    fn test_strict_overrides_strip() {
+        let schema = object().field("name", string()).strip().strict();
+        assert!(schema
+            .validate(&json!({"name": "John", "extra": 1}))
+            .is_err());
+    }
+
+    // Catchall
+    #[test]
+    fn test_catchall_validates_extra_keys() {
+        let schema = object().field("name", string()).catchall(string());
+
+        let result = schema
+            .validate(&json!({"name": "app", "region": "eu", "tier": "gold"}))
+            .unwrap();
+        assert_eq!(
+            result,
+            json!({"name": "app", "region": "eu", "tier": "gold"})
+        );
+
+        let err = schema
+            .validate(&json!({"name": "app", "region": "eu", "replicas": 3}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["replicas"]);
+    }
+
+    #[test]
+    fn test_catchall_does_not_apply_to_declared_fields() {
+        let schema = object().field("port", number()).catchall(string());
+        assert!(schema.validate(&json!({"port": 8080, "host": "x"})).is_ok());
+        assert!(schema.validate(&json!({"port": "8080"})).is_err());
+    }
+
+    #[test]
+    fn test_catchall_output_is_validated() {
+        let schema = object().catchall(string().trim());
+        let result = schema.validate(&json!({"a": "  x  "})).unwrap();
+        assert_eq!(result, json!({"a": "x"}));
+    }
+
+    #[test]
+    fn test_catchall_replaces_strict() {
+        let schema = object().field("name", string()).strict().catchall(number());
+        assert!(schema.validate(&json!({"name": "a", "extra": 1})).is_ok());
+
+        let schema = object().catchall(number()).strict();
+        assert!(schema.validate(&json!({"extra": 1})).is_err());
+    }
+
+    #[test]
+    fn test_catchall_collects_all_errors() {
+        let schema = object().catchall(number());
+        let err = schema
+            .validate(&json!({"a": "x", "b": "y", "c": 1}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+        let err = schema
+            .validate_with(&json!({"a": "x", "b": "y"}), opts)
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_catchall_json_schema() {
+        let schema = object().field("name", string()).catchall(number());
+        assert_eq!(
+            schema.to_json_schema()["additionalProperties"],
+            json!({"type": "number"})
+        );
+    }
+
     // Key Edge Cases
     #[test]
     fn test_empty_string_key() {
@@ -550,4 +1380,888 @@ mod tests {
         assert!(schema.validate(&json!({"id": 1})).is_ok());
         assert!(schema.validate(&json!({})).is_ok());
     }
+
+    // Partial / Required
+    #[test]
+    fn test_partial_makes_fields_optional() {
+        let schema = object()
+            .field("name", string().min(1))
+            .field("age", number())
+            .partial();
+
+        assert!(schema.validate(&json!({})).is_ok());
+        assert!(schema.validate(&json!({"name": null})).is_ok());
+        assert!(schema.validate(&json!({"age": 30})).is_ok());
+        assert!(schema.validate(&json!({"name": ""})).is_err());
+        assert_eq!(
+            schema.validate(&json!({"age": 30})).unwrap(),
+            json!({"age": 30.0})
+        );
+    }
+
+    #[test]
+    fn test_partial_is_idempotent() {
+        let schema = object().field("name", string()).partial().partial();
+        assert!(schema.validate(&json!({})).is_ok());
+        assert!(schema.validate(&json!({"name": 1})).is_err());
+    }
+
+    #[test]
+    fn test_partial_keeps_strict() {
+        let schema = object().field("name", string()).strict().partial();
+        assert!(schema.validate(&json!({})).is_ok());
+        assert!(schema.validate(&json!({"extra": 1})).is_err());
+    }
+
+    #[test]
+    fn test_required_makes_fields_required() {
+        let schema = object()
+            .field("name", string())
+            .optional_field("bio", string())
+            .required();
+
+        assert!(schema.validate(&json!({"name": "A", "bio": "B"})).is_ok());
+
+        let err = schema.validate(&json!({"name": "A"})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["bio"]);
+        assert_eq!(err.issues[0].error, ValidationError::required());
+
+        assert!(schema.validate(&json!({"name": "A", "bio": null})).is_err());
+    }
+
+    #[test]
+    fn test_required_reverses_partial() {
+        let schema = object().field("name", string()).partial().required();
+        assert!(schema.validate(&json!({})).is_err());
+        assert!(schema.validate(&json!({"name": "A"})).is_ok());
+    }
+
+    // Pick / Omit
+    fn user_schema() -> ObjectSchema {
+        object()
+            .field("id", number())
+            .field("name", string())
+            .field("email", string().email())
+            .field("password", string().min(8))
+    }
+
+    #[test]
+    fn test_pick_keeps_named_fields() {
+        let schema = user_schema().pick(&["name", "email"]);
+
+        assert!(schema
+            .validate(&json!({"name": "Alice", "email": "a@b.com"}))
+            .is_ok());
+        assert!(schema.validate(&json!({"name": "Alice"})).is_err());
+    }
+
+    #[test]
+    fn test_omit_drops_named_fields() {
+        let schema = user_schema().omit(&["id", "password"]);
+
+        assert!(schema
+            .validate(&json!({"name": "Alice", "email": "a@b.com"}))
+            .is_ok());
+        assert!(schema.validate(&json!({"name": "Alice"})).is_err());
+    }
+
+    #[test]
+    fn test_pick_and_omit_ignore_unknown_names() {
+        let picked = user_schema().pick(&["name", "missing"]);
+        assert!(picked.validate(&json!({"name": "Alice"})).is_ok());
+
+        let omitted = user_schema().omit(&["missing"]);
+        assert!(omitted.validate(&json!({"name": "Alice"})).is_err());
+    }
+
+    #[test]
+    fn test_pick_preserves_strict() {
+        let schema = user_schema().strict().pick(&["name"]);
+        assert!(schema.validate(&json!({"name": "Alice"})).is_ok());
+        assert!(schema
+            .validate(&json!({"name": "Alice", "email": "a@b.com"}))
+            .is_err());
+    }
+
+    #[test]
+    fn test_omit_preserves_strict() {
+        let schema = user_schema().strict().omit(&["password", "id", "email"]);
+        assert!(schema
+            .validate(&json!({"name": "Alice", "password": "secret123"}))
+            .is_err());
+    }
+
+    #[test]
+    fn test_keyof_accepts_field_names() {
+        let keys = user_schema().keyof();
+
+        assert_eq!(keys.options(), ["email", "id", "name", "password"]);
+        assert_eq!(keys.validate(&json!("name")).unwrap(), "name");
+        assert!(keys.validate(&json!(1)).is_err());
+
+        let err = keys.validate(&json!("age")).unwrap_err();
+        assert_eq!(
+            err.issues[0].error,
+            ValidationError::invalid_values(vec![
+                "email".into(),
+                "id".into(),
+                "name".into(),
+                "password".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_keyof_follows_pick_and_omit() {
+        assert_eq!(
+            user_schema().pick(&["name", "email"]).keyof().options(),
+            ["email", "name"]
+        );
+        assert_eq!(
+            user_schema().omit(&["password"]).keyof().options(),
+            ["email", "id", "name"]
+        );
+        assert!(object().keyof().validate(&json!("id")).is_err());
+    }
+
+    // Merge / Extend
+    fn timestamps_schema() -> ObjectSchema {
+        object()
+            .field("created_at", string())
+            .field("updated_at", string())
+    }
+
+    #[test]
+    fn test_merge_timestamps_into_post() {
+        let schema = object()
+            .field("title", string().min(1))
+            .field("body", string())
+            .merge(timestamps_schema());
+
+        assert!(schema
+            .validate(&json!({
+                "title": "Hello",
+                "body": "World",
+                "created_at": "2024-01-01",
+                "updated_at": "2024-01-02"
+            }))
+            .is_ok());
+
+        let err = schema
+            .validate(&json!({"title": "Hello", "body": "World"}))
+            .unwrap_err();
+        let mut paths: Vec<_> = err.issues.iter().map(|i| i.path.join(".")).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["created_at", "updated_at"]);
+    }
+
+    #[test]
+    fn test_merge_other_wins_on_collision() {
+        let schema = object()
+            .field("id", string())
+            .merge(object().field("id", number()));
+
+        assert!(schema.validate(&json!({"id": 1})).is_ok());
+        assert!(schema.validate(&json!({"id": "1"})).is_err());
+    }
+
+    #[test]
+    fn test_merge_strict_is_or() {
+        let lenient = object().field("a", string());
+        let strict = object().field("b", string()).strict();
+
+        let merged = lenient.clone().merge(strict.clone());
+        assert!(merged
+            .validate(&json!({"a": "x", "b": "y", "c": "z"}))
+            .is_err());
+
+        let merged = strict.merge(lenient);
+        assert!(merged
+            .validate(&json!({"a": "x", "b": "y", "c": "z"}))
+            .is_err());
+    }
+
+    #[test]
+    fn test_merge_strip_wins_over_passthrough() {
+        let lenient = object().field("a", string());
+        let stripping = object().field("b", string()).strip();
+
+        let merged = lenient.merge(stripping);
+        let result = merged
+            .validate(&json!({"a": "x", "b": "y", "c": "z"}))
+            .unwrap();
+        assert_eq!(result, json!({"a": "x", "b": "y"}));
+    }
+
+    #[test]
+    fn test_merge_explicit_passthrough_wins_over_default() {
+        let default = object().field("a", string());
+        let input = json!({"a": "x", "b": "y", "c": "z"});
+
+        let merged = default
+            .clone()
+            .merge(object().field("b", string()).passthrough());
+        assert_eq!(merged.validate(&input).unwrap(), input);
+
+        // A schema that never chose a mode does not reset the other's explicit one
+        let merged = object().field("b", string()).passthrough().merge(default);
+        assert!(matches!(merged.unknown_keys, UnknownKeys::Passthrough));
+    }
+
+    #[test]
+    fn test_extend_is_merge() {
+        let schema = object()
+            .field("name", string())
+            .extend(object().field("age", number()));
+
+        assert!(schema.validate(&json!({"name": "A", "age": 1})).is_ok());
+        assert!(schema.validate(&json!({"name": "A"})).is_err());
+    }
+
+    // Locale
+    #[test]
+    fn test_locale_stamps_issues() {
+        let schema = object()
+            .field("name", string())
+            .field("age", number())
+            .locale(Locale::Ar);
+        let err = schema.validate(&json!({"age": "x"})).unwrap_err();
+
+        assert!(err
+            .issues
+            .iter()
+            .all(|issue| issue.locale() == Some(Locale::Ar)));
+        assert_eq!(err.issues[0].to_string(), err.issues[0].local(Locale::Ar));
+        assert!(object()
+            .field("name", string())
+            .validate(&json!({}))
+            .unwrap_err()
+            .issues[0]
+            .locale()
+            .is_none());
+    }
+
+    #[test]
+    fn test_nested_locale_wins() {
+        let schema = object()
+            .field("title", string())
+            .field(
+                "author",
+                object().field("name", string()).locale(Locale::Fr),
+            )
+            .locale(Locale::Ar);
+        let err = schema.validate(&json!({"author": {}})).unwrap_err();
+
+        let locale_at = |path: &[&str]| {
+            err.issues
+                .iter()
+                .find(|issue| issue.path == path)
+                .and_then(|issue| issue.locale())
+        };
+        assert_eq!(locale_at(&["title"]), Some(Locale::Ar));
+        assert_eq!(locale_at(&["author", "name"]), Some(Locale::Fr));
+    }
+
+    #[test]
+    fn test_merge_keeps_locale() {
+        let schema = object()
+            .field("a", string())
+            .merge(object().field("b", string()).locale(Locale::Fr));
+        let err = schema.validate(&json!({})).unwrap_err();
+        assert!(err
+            .issues
+            .iter()
+            .all(|issue| issue.locale() == Some(Locale::Fr)));
+    }
+
+    // Default Fields
+    #[test]
+    fn test_default_field_injected_when_missing() {
+        let schema = object()
+            .field("host", string())
+            .default_field("retries", number().int(), 3.0);
+
+        let result = schema.validate(&json!({"host": "localhost"})).unwrap();
+        assert_eq!(result, json!({"host": "localhost", "retries": 3.0}));
+    }
+
+    #[test]
+    fn test_default_field_injected_when_null() {
+        let schema = object().default_field("retries", number(), 3.0);
+        let result = schema.validate(&json!({"retries": null})).unwrap();
+        assert_eq!(result, json!({"retries": 3.0}));
+    }
+
+    #[test]
+    fn test_default_field_uses_provided_value() {
+        let schema = object().default_field("retries", number().int(), 3.0);
+
+        let result = schema.validate(&json!({"retries": 5})).unwrap();
+        assert_eq!(result, json!({"retries": 5.0}));
+        assert!(schema.validate(&json!({"retries": 1.5})).is_err());
+    }
+
+    #[test]
+    fn test_default_field_with_string() {
+        let schema = object().default_field("mode", string(), "fast".to_string());
+        assert_eq!(
+            schema.validate(&json!({})).unwrap(),
+            json!({"mode": "fast"})
+        );
+    }
+
+    // Validation Modes
+    #[test]
+    fn test_abort_early_stops_at_first_failing_field() {
+        let schema = object()
+            .field("a", number())
+            .field("b", number())
+            .field("c", number());
+        let input = json!({"a": "x", "b": "y", "c": "z"});
+
+        assert_eq!(schema.validate(&input).unwrap_err().issues.len(), 3);
+
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            schema.validate_with(&input, opts).unwrap_err().issues.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_abort_early_propagates_to_nested_schemas() {
+        let schema = object().field(
+            "user",
+            object()
+                .field("name", string().min(3))
+                .field("tags", array(string())),
+        );
+        let input = json!({"user": {"name": "a", "tags": [1, 2]}});
+
+        assert_eq!(schema.validate(&input).unwrap_err().issues.len(), 3);
+
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+        let err = schema.validate_with(&input, opts).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path[0], "user");
+    }
+
+    #[test]
+    fn test_abort_early_success_matches_validate() {
+        let schema = object().field("a", number()).optional_field("b", string());
+        let input = json!({"a": 1, "extra": true});
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            schema.validate_with(&input, opts).unwrap(),
+            schema.validate(&input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_default_options_collect_all() {
+        let schema = object().field("a", number()).field("b", number());
+        let input = json!({});
+        let err = schema
+            .validate_with(&input, ValidateOptions::default())
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+    }
+
+    // Coercion
+    #[test]
+    fn test_coerce_parses_string_primitives() {
+        use crate::schema::boolean;
+
+        let schema = object()
+            .field("age", number().int())
+            .field("active", boolean())
+            .field("name", string())
+            .coerce();
+
+        let result = schema
+            .validate(&json!({"age": "25", "active": "true", "name": "42"}))
+            .unwrap();
+        assert_eq!(result, json!({"age": 25.0, "active": true, "name": "42"}));
+
+        let err = schema
+            .validate(&json!({"age": "old", "active": "yes", "name": "x"}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+        assert!(err.issues.iter().any(
+            |issue| issue.to_string() == "age: Invalid input: expected number, received string"
+        ));
+        assert!(err
+            .issues
+            .iter()
+            .any(|issue| issue.to_string()
+                == "active: Invalid input: expected bool, received string"));
+    }
+
+    #[test]
+    fn test_coerce_into_typed_struct() {
+        use crate::schema::boolean;
+
+        #[derive(Debug, serde::Deserialize, PartialEq)]
+        struct Query {
+            age: u32,
+            active: bool,
+        }
+
+        let schema = object()
+            .field("age", number().int())
+            .field("active", boolean())
+            .coerce();
+
+        let query: Query = schema
+            .parse_into(&json!({"age": "25", "active": "true"}))
+            .unwrap();
+        assert_eq!(
+            query,
+            Query {
+                age: 25,
+                active: true
+            }
+        );
+
+        let err = schema
+            .parse_into::<Query>(&json!({"age": "old", "active": "false"}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(
+            err.issues[0].to_string(),
+            "age: Invalid input: expected number, received string"
+        );
+    }
+
+    #[test]
+    fn test_without_coerce_strings_rejected() {
+        let schema = object().field("age", number());
+        assert!(schema.validate(&json!({"age": "25"})).is_err());
+    }
+
+    #[test]
+    fn test_coerce_reaches_nested_schemas() {
+        let schema = object()
+            .field("scores", array(number()))
+            .optional_field("meta", object().field("page", number().min(1.0)))
+            .coerce();
+
+        let result = schema
+            .validate(&json!({"scores": ["1", 2], "meta": {"page": "3"}}))
+            .unwrap();
+        assert_eq!(result, json!({"scores": [1.0, 2.0], "meta": {"page": 3.0}}));
+
+        let err = schema
+            .validate(&json!({"scores": [], "meta": {"page": "0"}}))
+            .unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["meta", "page"]);
+    }
+
+    #[test]
+    fn test_coerce_only_adds_to_field_settings() {
+        // A nested object without `coerce()` still inherits it from its parent, and
+        // a field that coerces on its own keeps doing so in a plain object
+        let inner = object().field("n", number());
+        let schema = object().field("inner", inner.clone()).coerce();
+        assert!(schema.validate(&json!({"inner": {"n": "1"}})).is_ok());
+        assert!(inner.validate(&json!({"n": "1"})).is_err());
+
+        let schema = object().field("n", number().coerce());
+        assert!(schema.validate(&json!({"n": "1"})).is_ok());
+    }
+
+    #[test]
+    fn test_merge_keeps_coerce() {
+        let schema = object()
+            .field("a", number())
+            .merge(object().field("b", number()).coerce());
+        assert!(schema.validate(&json!({"a": "1", "b": "2"})).is_ok());
+    }
+
+    // Renaming
+    #[test]
+    fn test_rename_writes_output_key() {
+        let schema = object()
+            .field("userName", string().min(1))
+            .optional_field("e_mail", string().email())
+            .field("age", number())
+            .rename("userName", "user_name")
+            .rename("e_mail", "email");
+
+        assert_eq!(
+            schema
+                .validate(&json!({"userName": "ada", "e_mail": "a@b.co", "age": 36}))
+                .unwrap(),
+            json!({"user_name": "ada", "email": "a@b.co", "age": 36.0})
+        );
+        // A missing optional field is still left out
+        assert_eq!(
+            schema
+                .validate(&json!({"userName": "ada", "age": 36}))
+                .unwrap(),
+            json!({"user_name": "ada", "age": 36.0})
+        );
+
+        let err = schema
+            .validate(&json!({"userName": "", "age": 1}))
+            .unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["userName"]);
+    }
+
+    #[test]
+    fn test_rename_with_unknown_key_modes() {
+        let schema = object()
+            .field("userName", string())
+            .rename("userName", "user_name");
+        let input = json!({"userName": "ada", "user_name": "legacy", "extra": 1});
+
+        // The input key is what strict mode expects
+        let err = schema.clone().strict().validate(&input).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Unrecognized keys: extra, user_name"
+        );
+        assert!(schema
+            .clone()
+            .strict()
+            .validate(&json!({"userName": "ada"}))
+            .is_ok());
+
+        // The renamed field wins over an undeclared key of the same name
+        assert_eq!(
+            schema.clone().passthrough().validate(&input).unwrap(),
+            json!({"user_name": "ada", "extra": 1})
+        );
+        assert_eq!(
+            schema.clone().catchall(number()).validate(&input).unwrap(),
+            json!({"user_name": "ada", "extra": 1.0})
+        );
+        assert_eq!(
+            schema.strip().validate(&input).unwrap(),
+            json!({"user_name": "ada"})
+        );
+    }
+
+    #[test]
+    fn test_rename_survives_merge_and_pick() {
+        let base = object()
+            .field("userName", string())
+            .rename("userName", "user_name");
+        let schema = object().field("id", number()).merge(base);
+
+        assert_eq!(
+            schema
+                .clone()
+                .validate(&json!({"id": 1, "userName": "ada"}))
+                .unwrap(),
+            json!({"id": 1.0, "user_name": "ada"})
+        );
+        assert_eq!(
+            schema
+                .omit(&["userName"])
+                .validate(&json!({"id": 1, "user_name": "x"}))
+                .unwrap(),
+            json!({"id": 1.0, "user_name": "x"})
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "'nickname' is not a declared field")]
+    fn test_rename_undeclared_field_panics() {
+        let _ = object().field("name", string()).rename("nickname", "alias");
+    }
+
+    #[test]
+    #[should_panic(expected = "'name' is already a declared field")]
+    fn test_rename_onto_declared_field_panics() {
+        let _ = object()
+            .field("name", string())
+            .field("nickname", string())
+            .rename("nickname", "name");
+    }
+
+    #[test]
+    #[should_panic(expected = "'a' is already renamed to 'x'")]
+    fn test_rename_two_fields_onto_same_key_panics() {
+        let _ = object()
+            .field("a", string())
+            .field("b", string())
+            .rename("a", "x")
+            .rename("b", "x");
+    }
+
+    #[test]
+    fn test_rename_same_field_twice_replaces_target() {
+        let schema = object()
+            .field("a", string())
+            .rename("a", "x")
+            .rename("a", "y");
+        assert_eq!(
+            schema.validate(&json!({"a": "1"})).unwrap(),
+            json!({"y": "1"})
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot declare field 'x': 'a' is already renamed to 'x'")]
+    fn test_field_declared_on_rename_target_panics() {
+        let _ = object()
+            .field("a", string())
+            .rename("a", "x")
+            .optional_field("x", string());
+    }
+
+    #[test]
+    #[should_panic(expected = "'x' is already a declared field")]
+    fn test_merge_declaring_rename_target_panics() {
+        let renamed = object().field("a", string()).rename("a", "x");
+        let _ = renamed.merge(object().field("x", string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "is already renamed to 'x'")]
+    fn test_merge_with_conflicting_renames_panics() {
+        let a = object().field("a", string()).rename("a", "x");
+        let b = object().field("b", string()).rename("b", "x");
+        let _ = a.merge(b);
+    }
+
+    // Output Conversion
+    #[test]
+    fn test_nested_values_pass_through_unchanged() {
+        use crate::schema::into_json_value;
+
+        let nested = json!({"a": 1, "b": [1, "x"]});
+        assert_eq!(into_json_value(nested.clone()).unwrap(), nested);
+        assert_eq!(
+            into_json_value(vec![json!({"a": 1}), json!(null)]).unwrap(),
+            json!([{"a": 1}, null])
+        );
+        assert_eq!(into_json_value(2.5).unwrap(), json!(2.5));
+        assert_eq!(into_json_value(Some("s")).unwrap(), json!("s"));
+        assert_eq!(into_json_value(None::<f64>).unwrap(), json!(null));
+    }
+
+    #[test]
+    fn test_deeply_nested_output() {
+        let leaf = || object().field("n", number());
+        let schema = object().field(
+            "a",
+            object().field("b", leaf()).field("list", array(leaf())),
+        );
+
+        let result = schema
+            .validate(&json!({"a": {"b": {"n": 1}, "list": [{"n": 2}]}}))
+            .unwrap();
+        assert_eq!(
+            result,
+            json!({"a": {"b": {"n": 1.0}, "list": [{"n": 2.0}]}})
+        );
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = object()
+            .field("name", string())
+            .optional_field("email", string().email())
+            .default_field("tags", array(string()), Vec::<String>::new())
+            .strict();
+
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string"},
+                    "email": {"anyOf": [{"type": "string", "format": "email"}, {"type": "null"}]},
+                    "tags": {
+                        "anyOf": [{"type": "array", "items": {"type": "string"}}, {"type": "null"}],
+                        "default": []
+                    }
+                },
+                "required": ["name"],
+                "additionalProperties": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_partial_and_required() {
+        let schema = object().field("a", number()).optional_field("b", number());
+
+        let partial = schema.clone().partial().to_json_schema();
+        assert_eq!(partial["required"], json!([]));
+        assert_eq!(
+            partial["properties"]["a"],
+            json!({"anyOf": [{"type": "number"}, {"type": "null"}]})
+        );
+
+        let required = schema.required().to_json_schema();
+        assert_eq!(required["required"], json!(["a", "b"]));
+        assert_eq!(required["properties"]["b"], json!({"type": "number"}));
+    }
+
+    // Typed Parsing
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Order {
+        id: u32,
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Item {
+        sku: String,
+        quantity: u8,
+    }
+
+    fn order_schema() -> ObjectSchema {
+        object().field("id", number().int()).field(
+            "items",
+            array(
+                object()
+                    .field("sku", string().trim())
+                    .field("quantity", number().int().min(1.0)),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_parse_into_returns_validated_output() {
+        let order: Order = order_schema()
+            .parse_into(&json!({"id": 7, "items": [{"sku": " A-1 ", "quantity": 2}]}))
+            .unwrap();
+
+        assert_eq!(
+            order,
+            Order {
+                id: 7,
+                items: vec![Item {
+                    sku: "A-1".to_string(),
+                    quantity: 2
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_into_reports_validation_errors() {
+        let err = order_schema()
+            .parse_into::<Order>(&json!({"id": 7, "items": [{"sku": "A-1", "quantity": 0}]}))
+            .unwrap_err();
+
+        assert_eq!(err.issues[0].path, vec!["items", "0", "quantity"]);
+        assert!(err.issues[0].to_string().contains("Too small"));
+    }
+
+    #[test]
+    fn test_parse_into_reports_deserialization_error_path() {
+        // Passes the schema, but 300 does not fit in a u8
+        let err = order_schema()
+            .parse_into::<Order>(&json!({"id": 7, "items": [{"sku": "A-1", "quantity": 300}]}))
+            .unwrap_err();
+
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["items", "0", "quantity"]);
+        assert!(err.issues[0]
+            .to_string()
+            .starts_with("items.0.quantity: Deserialization failed: invalid value"));
+    }
+
+    #[test]
+    fn test_parse_into_missing_target_field_reported_at_parent() {
+        let schema = object().field("id", number().int());
+        let err = schema.parse_into::<Order>(&json!({"id": 1})).unwrap_err();
+
+        assert!(err.issues[0].path.is_empty());
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Deserialization failed: missing field `items`"
+        );
+    }
+
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Page {
+        page: u32,
+        size: i64,
+    }
+
+    #[test]
+    fn test_parse_into_integer_from_default() {
+        let schema = object()
+            .default_field("page", number().int(), 1.0)
+            .default_field("size", number().int(), -20.0);
+
+        let page: Page = schema.parse_into(&json!({})).unwrap();
+        assert_eq!(page, Page { page: 1, size: -20 });
+    }
+
+    #[test]
+    fn test_parse_into_integer_from_catch() {
+        let schema = object()
+            .field("page", number().int().min(1.0).catch(1.0))
+            .field("size", number().int());
+
+        let page: Page = schema
+            .parse_into(&json!({"page": "first", "size": 10}))
+            .unwrap();
+        assert_eq!(page, Page { page: 1, size: 10 });
+    }
+
+    #[test]
+    fn test_parse_into_integer_from_coerced_string() {
+        let schema = object()
+            .field("page", number().int().coerce())
+            .field("size", number().int().coerce());
+
+        let page: Page = schema
+            .parse_into(&json!({"page": "25", "size": "-5"}))
+            .unwrap();
+        assert_eq!(page, Page { page: 25, size: -5 });
+    }
+
+    #[test]
+    fn test_parse_into_integer_from_renamed_field() {
+        let schema = object()
+            .field("page_number", number().int())
+            .field("size", number().int())
+            .rename("page_number", "page");
+
+        let page: Page = schema
+            .parse_into(&json!({"page_number": 36, "size": 10}))
+            .unwrap();
+        assert_eq!(page, Page { page: 36, size: 10 });
+    }
+
+    #[test]
+    fn test_parse_into_keeps_large_integers_exact() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Id {
+            id: u64,
+        }
+
+        let input: Value = serde_json::from_str(r#"{"id": 9007199254740993}"#).unwrap();
+        let id: Id = object().field("id", number()).parse_into(&input).unwrap();
+        assert_eq!(id.id, 9_007_199_254_740_993);
+    }
+
+    #[test]
+    fn test_parse_into_fractional_default_stays_float() {
+        let schema = object()
+            .default_field("page", number(), 1.5)
+            .field("size", number().int());
+
+        let err = schema.parse_into::<Page>(&json!({"size": 1})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["page"]);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::schema::Schema;
+use crate::schema::{nullable_json_schema, Schema, ValidateOptions};
 use serde_json::Value;
 use std::fmt::Debug;
 use zod_rs_util::ValidateResult;
@@ -24,12 +24,20 @@ where
     T: Debug,
 {
     fn validate(&self, value: &Value) -> ValidateResult<Option<T>> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Option<T>> {
         if value.is_null() {
             Ok(None)
         } else {
-            self.inner.validate(value).map(Some)
+            self.inner.validate_with(value, opts).map(Some)
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        nullable_json_schema(self.inner.to_json_schema())
+    }
 }
 
 pub fn optional<S, T>(schema: S) -> OptionalSchema<S, T> {
@@ -190,4 +198,13 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Some(Some("hello".to_string())));
     }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(
+            optional(number().positive()).to_json_schema(),
+            json!({"anyOf": [{"type": "number", "exclusiveMinimum": 0}, {"type": "null"}]})
+        );
+    }
 }
@@ -0,0 +1,127 @@
+use crate::schema::{into_json_value, Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::Debug;
+use zod_rs_util::ValidateResult;
+
+/// Validates with `first`, then validates its output, converted back to JSON,
+/// with `second`. The output is that of `second`.
+#[derive(Debug, Clone)]
+pub struct PipeSchema<A, B, T, U> {
+    first: A,
+    second: B,
+    _phantom: std::marker::PhantomData<(T, U)>,
+}
+
+impl<A, B, T, U> PipeSchema<A, B, T, U> {
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<A, B, T, U> Schema<U> for PipeSchema<A, B, T, U>
+where
+    A: Schema<T>,
+    B: Schema<U>,
+    T: serde::Serialize + Debug + 'static,
+    U: Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<U> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<U> {
+        let intermediate = into_json_value(self.first.validate_with(value, opts)?)?;
+        self.second.validate_with(&intermediate, opts)
+    }
+
+    /// Describes the accepted input, i.e. the first schema.
+    fn to_json_schema(&self) -> Value {
+        self.first.to_json_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{array, number, object, string, Schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_pipe_validation() {
+        let schema = string().trim().pipe(string().min(1));
+
+        assert_eq!(schema.validate(&json!("  hi ")).unwrap(), "hi");
+
+        let err = schema.validate(&json!("   ")).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].code(), "too_small");
+        assert!(schema.validate(&json!(5)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Changing types
+    #[test]
+    fn test_pipe_parsed_string_into_number() {
+        let schema = string()
+            .regex(r"^-?\d+$")
+            .transform(|s| s.parse::<i64>().unwrap())
+            .pipe(number().positive());
+
+        assert_eq!(schema.validate(&json!("42")).unwrap(), 42.0);
+        assert!(schema.validate(&json!("-3")).is_err());
+        assert!(schema.validate(&json!("abc")).is_err());
+    }
+
+    #[test]
+    fn test_pipe_coerce_then_constrain() {
+        let schema = number().coerce().pipe(number().int().max(65535.0));
+
+        assert_eq!(schema.validate(&json!("8080")).unwrap(), 8080.0);
+        assert!(schema.validate(&json!("1.5")).is_err());
+    }
+
+    // Errors
+    #[test]
+    fn test_second_schema_not_run_on_failure() {
+        let schema = string()
+            .min(3)
+            .pipe(string().refine(|_: &String| panic!("must not run"), "unreachable"));
+        assert!(schema.validate(&json!("ab")).is_err());
+    }
+
+    #[test]
+    fn test_pipe_errors_keep_paths() {
+        let schema = object().field("tags", array(string().trim()).pipe(array(string().min(1))));
+
+        let err = schema.validate(&json!({"tags": ["a", " "]})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["tags", "1"]);
+    }
+
+    // Chaining
+    #[test]
+    fn test_chained_pipes() {
+        let schema = string()
+            .trim()
+            .pipe(string().to_lowercase())
+            .pipe(string().email());
+
+        assert_eq!(
+            schema.validate(&json!(" Ada@Example.COM ")).unwrap(),
+            "ada@example.com"
+        );
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema_describes_input() {
+        let schema = string().max(5).pipe(string().min(1));
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"type": "string", "maxLength": 5})
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::schema::{into_json_value, Schema, ValidateOptions};
+use serde_json::{json, Map, Value};
+use std::fmt::Debug;
+use zod_rs_util::{
+    ValidateResult, ValidationError, ValidationOrigin, ValidationResult, ValidationType,
+};
+
+#[derive(Debug, Clone)]
+pub struct RecordSchema<K, V, T> {
+    key_schema: K,
+    value_schema: V,
+    min_entries: Option<usize>,
+    max_entries: Option<usize>,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<K, V, T> RecordSchema<K, V, T> {
+    pub fn new(key_schema: K, value_schema: V) -> Self {
+        Self {
+            key_schema,
+            value_schema,
+            min_entries: None,
+            max_entries: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn min(mut self, min: usize) -> Self {
+        self.min_entries = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: usize) -> Self {
+        self.max_entries = Some(max);
+        self
+    }
+}
+
+impl<K, V, T> Schema<Value> for RecordSchema<K, V, T>
+where
+    K: Schema<String>,
+    V: Schema<T>,
+    T: serde::Serialize + Debug + 'static,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let obj = match value.as_object() {
+            Some(o) => o,
+            None => {
+                return Err(ValidationError::invalid_type(
+                    ValidationType::Object,
+                    ValidationType::from(value),
+                )
+                .into());
+            }
+        };
+
+        if let Some(min) = self.min_entries {
+            if obj.len() < min {
+                return Err(ValidationError::too_small(
+                    ValidationOrigin::Object,
+                    min.to_string(),
+                    true,
+                )
+                .with_received(obj.len().to_string())
+                .into());
+            }
+        }
+
+        if let Some(max) = self.max_entries {
+            if obj.len() > max {
+                return Err(ValidationError::too_big(
+                    ValidationOrigin::Object,
+                    max.to_string(),
+                    true,
+                )
+                .with_received(obj.len().to_string())
+                .into());
+            }
+        }
+
+        let mut result = serde_json::Map::new();
+        let mut validation_result = ValidationResult::new();
+
+        for (key, item) in obj {
+            if opts.abort_early && !validation_result.is_empty() {
+                break;
+            }
+
+            if let Err(mut errors) = self
+                .key_schema
+                .validate_with(&Value::String(key.clone()), opts)
+            {
+                errors.issues = errors
+                    .issues
+                    .into_iter()
+                    .map(|mut issue| {
+                        issue.error =
+                            ValidationError::invalid_key(ValidationOrigin::Object, issue.error);
+                        issue
+                    })
+                    .collect();
+                errors.prefix_path(key.clone());
+                validation_result.merge(errors);
+                continue;
+            }
+
+            match self
+                .value_schema
+                .validate_with(item, opts)
+                .and_then(into_json_value)
+            {
+                Ok(validated_value) => {
+                    result.insert(key.clone(), validated_value);
+                }
+                Err(mut errors) => {
+                    errors.prefix_path(key.clone());
+                    validation_result.merge(errors);
+                }
+            }
+        }
+
+        if validation_result.is_empty() {
+            Ok(Value::Object(result))
+        } else {
+            Err(validation_result)
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "object".into());
+
+        let key_schema = self.key_schema.to_json_schema();
+        if key_schema != json!({ "type": "string" }) {
+            schema.insert("propertyNames".into(), key_schema);
+        }
+        schema.insert(
+            "additionalProperties".into(),
+            self.value_schema.to_json_schema(),
+        );
+
+        if let Some(min) = self.min_entries {
+            schema.insert("minProperties".into(), min.into());
+        }
+        if let Some(max) = self.max_entries {
+            schema.insert("maxProperties".into(), max.into());
+        }
+
+        Value::Object(schema)
+    }
+}
+
+pub fn record<K, V, T>(key_schema: K, value_schema: V) -> RecordSchema<K, V, T> {
+    RecordSchema::new(key_schema, value_schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string};
+    use serde_json::json;
+
+    #[test]
+    fn test_record_validation() {
+        let schema = record(string(), number());
+
+        assert!(schema.validate(&json!({"usd": 1.0, "eur": 0.9})).is_ok());
+        assert!(schema.validate(&json!({"usd": "1.0"})).is_err());
+        assert!(schema.validate(&json!([1, 2])).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[test]
+    fn test_empty_record() {
+        let schema = record(string(), number());
+        assert_eq!(schema.validate(&json!({})).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn test_record_returns_validated_values() {
+        let schema = record(string(), number());
+        let result = schema.validate(&json!({"a": 1, "b": 2}));
+        assert_eq!(result.unwrap(), json!({"a": 1.0, "b": 2.0}));
+    }
+
+    // Key Validation
+    #[test]
+    fn test_key_schema_applied() {
+        let schema = record(string().regex(r"^[a-z_]+$"), number());
+
+        assert!(schema.validate(&json!({"snake_case": 1})).is_ok());
+
+        let err = schema.validate(&json!({"Bad-Key": 1})).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["Bad-Key"]);
+        assert_eq!(err.issues[0].code(), "invalid_key");
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Bad-Key: Invalid key in object: Invalid value: must match pattern ^[a-z_]+$"
+        );
+    }
+
+    #[test]
+    fn test_invalid_key_inside_object() {
+        let schema = object().field("scores", record(string().min(3), number()));
+
+        let err = schema.validate(&json!({"scores": {"ab": 1}})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["scores", "ab"]);
+        let ValidationError::InvalidKey { detail, .. } = &err.issues[0].error else {
+            panic!("expected an invalid key, got {:?}", err.issues[0].error);
+        };
+        assert_eq!(detail.code(), "too_small");
+    }
+
+    // Error Paths
+    #[test]
+    fn test_value_error_path_uses_key() {
+        let schema = object().field("prices", record(string(), number()));
+        let err = schema
+            .validate(&json!({"prices": {"usd": "ten"}}))
+            .unwrap_err();
+
+        assert_eq!(err.issues[0].path, vec!["prices", "usd"]);
+        assert!(err
+            .to_string()
+            .contains("prices.usd: Invalid input: expected number, received string"));
+    }
+
+    #[test]
+    fn test_multiple_value_errors_collected() {
+        let schema = record(string(), number());
+        let err = schema
+            .validate(&json!({"a": "x", "b": 1, "c": true}))
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+    }
+
+    // Entry Count Constraints
+    #[test]
+    fn test_record_min_entries() {
+        let schema = record(string(), number()).min(2);
+        assert!(schema.validate(&json!({"a": 1, "b": 2})).is_ok());
+        assert!(schema.validate(&json!({"a": 1})).is_err());
+    }
+
+    #[test]
+    fn test_record_max_entries() {
+        let schema = record(string(), number()).max(1);
+        assert!(schema.validate(&json!({"a": 1})).is_ok());
+
+        let err = schema.validate(&json!({"a": 1, "b": 2})).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\n  - Too big: expected object to have <= 1 entries, received 2"
+        );
+    }
+
+    // Nested Values
+    #[test]
+    fn test_record_of_objects() {
+        let schema = record(string(), object().field("name", string()));
+        assert!(schema
+            .validate(&json!({"u1": {"name": "Alice"}, "u2": {"name": "Bob"}}))
+            .is_ok());
+
+        let err = schema.validate(&json!({"u1": {}})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["u1", "name"]);
+    }
+
+    // Type Rejection
+    #[test]
+    fn test_rejects_non_object() {
+        let schema = record(string(), number());
+        assert!(schema.validate(&json!(null)).is_err());
+        assert!(schema.validate(&json!("map")).is_err());
+        assert!(schema.validate(&json!(1)).is_err());
+    }
+
+    // Validation Modes
+    #[test]
+    fn test_abort_early_stops_at_first_failing_entry() {
+        let schema = record(string(), number());
+        let input = json!({"a": "x", "b": "y", "c": 1});
+
+        assert_eq!(schema.validate(&input).unwrap_err().issues.len(), 2);
+
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            schema.validate_with(&input, opts).unwrap_err().issues.len(),
+            1
+        );
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(
+            record(string(), number()).min(1).to_json_schema(),
+            json!({
+                "type": "object",
+                "additionalProperties": {"type": "number"},
+                "minProperties": 1
+            })
+        );
+        assert_eq!(
+            record(string().min(2), number()).to_json_schema(),
+            json!({
+                "type": "object",
+                "propertyNames": {"type": "string", "minLength": 2},
+                "additionalProperties": {"type": "number"}
+            })
+        );
+    }
+}
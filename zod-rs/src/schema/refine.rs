@@ -0,0 +1,167 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::{self, Debug};
+use zod_rs_util::{ValidateResult, ValidationError};
+
+#[derive(Clone)]
+pub struct RefinedSchema<S, T, F> {
+    inner: S,
+    predicate: F,
+    message: String,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T, F> RefinedSchema<S, T, F> {
+    pub fn new(inner: S, predicate: F, message: &str) -> Self {
+        Self {
+            inner,
+            predicate,
+            message: message.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T, F> Debug for RefinedSchema<S, T, F>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefinedSchema")
+            .field("inner", &self.inner)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+impl<S, T, F> Schema<T> for RefinedSchema<S, T, F>
+where
+    S: Schema<T>,
+    T: Debug,
+    F: Fn(&T) -> bool,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<T> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        let validated = self.inner.validate_with(value, opts)?;
+
+        if (self.predicate)(&validated) {
+            Ok(validated)
+        } else {
+            Err(ValidationError::custom(self.message.clone()).into())
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.inner.to_json_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{array, number, object, string, Schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_refine_validation() {
+        let schema = string().refine(
+            |s: &String| s.chars().any(|c| c.is_ascii_digit()),
+            "Password must contain a digit",
+        );
+
+        assert!(schema.validate(&json!("hunter2")).is_ok());
+        assert!(schema.validate(&json!("hunter")).is_err());
+        assert!(schema.validate(&json!(2)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[test]
+    fn test_refine_message() {
+        let schema = number().refine(|n: &f64| n % 2.0 == 0.0, "Must be even");
+        let err = schema.validate(&json!(3)).unwrap_err();
+        assert_eq!(err.issues[0].to_string(), "Must be even");
+    }
+
+    #[test]
+    fn test_refine_runs_after_inner_schema() {
+        // The predicate never sees a value that failed the inner schema
+        let schema = string()
+            .min(5)
+            .refine(|s: &String| !s.is_empty(), "unreachable");
+        let err = schema.validate(&json!("abc")).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_ne!(err.issues[0].to_string(), "unreachable");
+    }
+
+    #[test]
+    fn test_refine_returns_parsed_value() {
+        let schema = number().refine(|n: &f64| *n > 1.0, "Too low");
+        assert_eq!(schema.validate(&json!(2)).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_chained_refinements() {
+        let schema = string()
+            .refine(|s: &String| s.starts_with('a'), "Must start with a")
+            .refine(|s: &String| s.ends_with('z'), "Must end with z");
+
+        assert!(schema.validate(&json!("abcz")).is_ok());
+        assert_eq!(
+            schema.validate(&json!("bz")).unwrap_err().issues[0].to_string(),
+            "Must start with a"
+        );
+        assert_eq!(
+            schema.validate(&json!("ab")).unwrap_err().issues[0].to_string(),
+            "Must end with z"
+        );
+    }
+
+    // Composition
+    #[test]
+    fn test_refine_with_optional() {
+        let schema = string()
+            .refine(|s: &String| s.contains('@'), "Must contain @")
+            .optional();
+
+        assert_eq!(schema.validate(&json!(null)).unwrap(), None);
+        assert!(schema.validate(&json!("a@b")).is_ok());
+        assert!(schema.validate(&json!("ab")).is_err());
+    }
+
+    #[test]
+    fn test_refine_in_object_field_path() {
+        let schema = object().field(
+            "password",
+            string().refine(|s: &String| s.len() >= 8, "Password too short"),
+        );
+
+        let err = schema.validate(&json!({"password": "short"})).unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["password"]);
+        assert_eq!(err.issues[0].to_string(), "password: Password too short");
+    }
+
+    #[test]
+    fn test_refine_on_array() {
+        let schema = array(number()).refine(
+            |items: &Vec<f64>| items.windows(2).all(|w| w[0] <= w[1]),
+            "Must be sorted",
+        );
+
+        assert!(schema.validate(&json!([1, 2, 3])).is_ok());
+        assert!(schema.validate(&json!([3, 1])).is_err());
+    }
+
+    #[test]
+    fn test_json_schema_ignores_predicate() {
+        let schema = string()
+            .min(8)
+            .refine(|s: &String| s.len() < 64, "Too long");
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"type": "string", "minLength": 8})
+        );
+    }
+}
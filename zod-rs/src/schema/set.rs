@@ -0,0 +1,302 @@
+use crate::schema::{array::duplicates, Schema, ValidateOptions};
+use serde_json::{Map, Value};
+use std::fmt::Debug;
+use zod_rs_util::{
+    ValidateResult, ValidationError, ValidationOrigin, ValidationResult, ValidationType,
+};
+
+/// Schema for a collection of distinct elements, e.g. a `HashSet<T>` field. JSON has
+/// no set type, so the input is an array; elements are compared by their serialized
+/// validated output.
+#[derive(Debug, Clone)]
+pub struct SetSchema<S, T> {
+    element_schema: S,
+    min_size: Option<usize>,
+    max_size: Option<usize>,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<S, T> SetSchema<S, T> {
+    pub fn new(element_schema: S) -> Self {
+        Self {
+            element_schema,
+            min_size: None,
+            max_size: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn min(mut self, min: usize) -> Self {
+        self.min_size = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: usize) -> Self {
+        self.max_size = Some(max);
+        self
+    }
+
+    pub fn size(self, size: usize) -> Self {
+        self.min(size).max(size)
+    }
+}
+
+impl<S, T> Schema<Vec<T>> for SetSchema<S, T>
+where
+    S: Schema<T>,
+    T: serde::Serialize + Debug,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<Vec<T>> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Vec<T>> {
+        let array = value.as_array().ok_or_else(|| {
+            ValidationResult::from(ValidationError::invalid_type(
+                ValidationType::Array,
+                ValidationType::from(value),
+            ))
+        })?;
+
+        if let Some(min) = self.min_size {
+            if array.len() < min {
+                return Err(ValidationError::too_small(
+                    ValidationOrigin::Set,
+                    min.to_string(),
+                    true,
+                )
+                .with_received(array.len().to_string())
+                .into());
+            }
+        }
+
+        if let Some(max) = self.max_size {
+            if array.len() > max {
+                return Err(
+                    ValidationError::too_big(ValidationOrigin::Set, max.to_string(), true)
+                        .with_received(array.len().to_string())
+                        .into(),
+                );
+            }
+        }
+
+        let mut results = Vec::new();
+        let mut validation_result = ValidationResult::new();
+
+        for (index, item) in array.iter().enumerate() {
+            match self.element_schema.validate_with(item, opts) {
+                Ok(validated_item) => results.push(validated_item),
+                Err(mut errors) => {
+                    errors.prefix_path(index.to_string());
+                    validation_result.merge(errors);
+
+                    if opts.abort_early {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if !validation_result.is_empty() {
+            return Err(validation_result);
+        }
+
+        // Every repeat is reported at its own index, pointing back to the first occurrence
+        for (first, index) in duplicates(&results) {
+            validation_result.add_error_at_path(
+                vec![index.to_string()],
+                ValidationError::duplicate_set_element(first),
+            );
+
+            if opts.abort_early {
+                break;
+            }
+        }
+
+        if !validation_result.is_empty() {
+            return Err(validation_result);
+        }
+
+        Ok(results)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "array".into());
+        schema.insert("items".into(), self.element_schema.to_json_schema());
+        schema.insert("uniqueItems".into(), true.into());
+
+        if let Some(min) = self.min_size {
+            schema.insert("minItems".into(), min.into());
+        }
+        if let Some(max) = self.max_size {
+            schema.insert("maxItems".into(), max.into());
+        }
+
+        Value::Object(schema)
+    }
+}
+
+pub fn set<S, T>(element_schema: S) -> SetSchema<S, T> {
+    SetSchema::new(element_schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string};
+    use serde_json::json;
+
+    #[test]
+    fn test_set_validation() {
+        let schema = set(string()).min(1).max(3);
+
+        assert_eq!(
+            schema.validate(&json!(["admin", "editor"])).unwrap(),
+            vec!["admin", "editor"]
+        );
+        assert!(schema.validate(&json!([])).is_err());
+        assert!(schema.validate(&json!(["a", "b", "c", "d"])).is_err());
+        assert!(schema.validate(&json!(["a", "a"])).is_err());
+        assert!(schema.validate(&json!("admin")).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Duplicates
+    #[test]
+    fn test_duplicate_reported_at_its_index() {
+        let schema = set(string());
+        let err = schema.validate(&json!(["a", "b", "a"])).unwrap_err();
+
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["2"]);
+        assert_eq!(
+            err.issues[0].error,
+            ValidationError::duplicate_set_element(0)
+        );
+        assert_eq!(
+            err.issues[0].to_string(),
+            "2: Duplicate set element: same as element at index 0"
+        );
+    }
+
+    #[test]
+    fn test_every_duplicate_reported() {
+        let schema = set(number());
+        let err = schema.validate(&json!([1, 2, 1, 2, 1])).unwrap_err();
+
+        let paths: Vec<_> = err.issues.iter().map(|i| i.path[0].as_str()).collect();
+        assert_eq!(paths, vec!["2", "3", "4"]);
+    }
+
+    #[test]
+    fn test_abort_early_stops_at_first_duplicate() {
+        let schema = set(number());
+        let err = schema
+            .validate_with(
+                &json!([1, 1, 1]),
+                ValidateOptions {
+                    abort_early: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_duplicates_compared_after_validation() {
+        // " a" and "a" are equal once trimmed
+        let schema = set(string().trim());
+        assert!(schema.validate(&json!([" a", "a"])).is_err());
+
+        // 1 and 1.0 validate to the same number
+        let schema = set(number());
+        assert!(schema.validate(&json!([1, 1.0])).is_err());
+    }
+
+    #[test]
+    fn test_object_elements_compared_structurally() {
+        let schema = set(object().field("x", number()).field("y", number()));
+
+        assert!(schema
+            .validate(&json!([{"x": 1, "y": 2}, {"y": 2, "x": 1}]))
+            .is_err());
+        assert!(schema
+            .validate(&json!([{"x": 1, "y": 2}, {"x": 2, "y": 1}]))
+            .is_ok());
+    }
+
+    // Element Errors
+    #[test]
+    fn test_element_errors_take_precedence() {
+        let schema = set(string().min(2));
+        let err = schema.validate(&json!(["x", "x"])).unwrap_err();
+
+        assert_eq!(err.issues.len(), 2);
+        assert!(err
+            .issues
+            .iter()
+            .all(|i| i.to_string().contains("Too small")));
+    }
+
+    // Cardinality
+    #[test]
+    fn test_size_messages_use_set_origin() {
+        let schema = set(string()).min(2);
+        let err = schema.validate(&json!(["a"])).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Too small: expected set to have >= 2 elements, received 1"
+        );
+
+        let schema = set(string()).max(1);
+        let err = schema.validate(&json!(["a", "b"])).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Too big: expected set to have <= 1 elements, received 2"
+        );
+    }
+
+    #[test]
+    fn test_exact_size() {
+        let schema = set(number()).size(2);
+        assert!(schema.validate(&json!([1, 2])).is_ok());
+        assert!(schema.validate(&json!([1])).is_err());
+        assert!(schema.validate(&json!([1, 2, 3])).is_err());
+    }
+
+    #[test]
+    fn test_empty_set_allowed_without_min() {
+        let schema = set(string());
+        assert_eq!(schema.validate(&json!([])).unwrap(), Vec::<String>::new());
+    }
+
+    // Type Rejection
+    #[test]
+    fn test_rejects_non_array() {
+        let schema = set(string());
+        let err = schema.validate(&json!({"a": 1})).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input: expected array, received object"
+        );
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = set(string()).min(1).max(5);
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "array",
+                "items": {"type": "string"},
+                "uniqueItems": true,
+                "minItems": 1,
+                "maxItems": 5
+            })
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::Value;
+use std::fmt::{self, Debug};
+use zod_rs_util::ValidateResult;
+
+#[derive(Clone)]
+pub struct TransformSchema<S, T, U, F> {
+    inner: S,
+    transform: F,
+    _phantom: std::marker::PhantomData<(T, U)>,
+}
+
+impl<S, T, U, F> TransformSchema<S, T, U, F> {
+    pub fn new(inner: S, transform: F) -> Self {
+        Self {
+            inner,
+            transform,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T, U, F> Debug for TransformSchema<S, T, U, F>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransformSchema")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<S, T, U, F> Schema<U> for TransformSchema<S, T, U, F>
+where
+    S: Schema<T>,
+    T: Debug,
+    U: Debug,
+    F: Fn(T) -> U,
+{
+    fn validate(&self, value: &Value) -> ValidateResult<U> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<U> {
+        self.inner.validate_with(value, opts).map(&self.transform)
+    }
+
+    /// Describes the accepted input, i.e. the schema before the transform.
+    fn to_json_schema(&self) -> Value {
+        self.inner.to_json_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{array, number, object, string, Schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_validation() {
+        let schema = string().transform(|s| s.len());
+
+        assert_eq!(schema.validate(&json!("hello")).unwrap(), 5);
+        assert!(schema.validate(&json!(5)).is_err());
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    #[test]
+    fn test_transform_not_run_on_failure() {
+        let schema = string()
+            .min(3)
+            .transform(|_| -> usize { panic!("must not run") });
+        assert!(schema.validate(&json!("ab")).is_err());
+    }
+
+    #[test]
+    fn test_transform_into_object_field() {
+        let schema = object().field(
+            "year",
+            string()
+                .regex(r"^\d{4}-")
+                .transform(|s| s[..4].parse::<u32>().unwrap()),
+        );
+
+        let result = schema.validate(&json!({"year": "2024-01-02"})).unwrap();
+        assert_eq!(result, json!({"year": 2024}));
+    }
+
+    #[test]
+    fn test_transform_in_array() {
+        let schema = array(number().transform(|n| n * 2.0));
+        assert_eq!(schema.validate(&json!([1, 2])).unwrap(), vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_transform_then_refine() {
+        let schema = string()
+            .transform(|s| s.trim().to_string())
+            .refine(|s: &String| !s.is_empty(), "Must not be blank");
+
+        assert_eq!(schema.validate(&json!("  hi ")).unwrap(), "hi");
+        assert!(schema.validate(&json!("   ")).is_err());
+    }
+
+    #[test]
+    fn test_chained_transforms() {
+        let schema = number()
+            .transform(|n| n as i64)
+            .transform(|n| n.to_string());
+        assert_eq!(schema.validate(&json!(42)).unwrap(), "42");
+    }
+}
@@ -1,17 +1,19 @@
-use crate::schema::Schema;
-use serde_json::Value;
+use crate::schema::{into_json_value, Schema, ValidateOptions};
+use serde_json::{json, Value};
 use std::{fmt::Debug, sync::Arc};
 use zod_rs_util::{ValidateResult, ValidationError, ValidationResult, ValidationType};
 
 #[derive(Debug, Clone)]
 pub struct TupleSchema {
     elements: Vec<Arc<dyn TupleElementValidator>>,
+    rest: Option<Arc<dyn TupleElementValidator>>,
 }
 
 impl TupleSchema {
     pub fn new() -> Self {
         Self {
             elements: Vec::new(),
+            rest: None,
         }
     }
 
@@ -24,6 +26,29 @@ impl TupleSchema {
             .push(Arc::new(TupleElementValidatorImpl::new(schema)));
         self
     }
+
+    /// Accepts any number of elements after the fixed ones, each validated
+    /// against `schema`, like Zod's `.rest()`. The tuple then needs at least as
+    /// many elements as were declared with [`element`](Self::element).
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use zod_rs::prelude::*;
+    ///
+    /// // [string, number, ...string[]]
+    /// let schema = tuple().element(string()).element(number()).rest(string());
+    /// assert!(schema.validate(&json!(["a", 1])).is_ok());
+    /// assert!(schema.validate(&json!(["a", 1, "b", "c"])).is_ok());
+    /// assert!(schema.validate(&json!(["a"])).is_err());
+    /// ```
+    pub fn rest<S, T>(mut self, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + 'static,
+        T: serde::Serialize + Send + Sync + Debug + 'static,
+    {
+        self.rest = Some(Arc::new(TupleElementValidatorImpl::new(schema)));
+        self
+    }
 }
 
 impl Default for TupleSchema {
@@ -33,7 +58,8 @@ impl Default for TupleSchema {
 }
 
 trait TupleElementValidator: Send + Sync + Debug {
-    fn validate_element(&self, value: &Value) -> ValidateResult<Value>;
+    fn validate_element(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value>;
+    fn json_schema(&self) -> Value;
 }
 
 #[derive(Debug)]
@@ -54,18 +80,24 @@ impl<S, T> TupleElementValidatorImpl<S, T> {
 impl<S, T> TupleElementValidator for TupleElementValidatorImpl<S, T>
 where
     S: Schema<T> + Send + Sync + Debug,
-    T: serde::Serialize + Send + Sync + Debug,
+    T: serde::Serialize + Send + Sync + Debug + 'static,
 {
-    fn validate_element(&self, value: &Value) -> ValidateResult<Value> {
-        let validated = self.schema.validate(value)?;
-        serde_json::to_value(validated).map_err(|e| {
-            ValidationError::custom(format!("Failed to serialize validated value: {}", e)).into()
-        })
+    fn validate_element(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
+        let validated = self.schema.validate_with(value, opts)?;
+        into_json_value(validated)
+    }
+
+    fn json_schema(&self) -> Value {
+        self.schema.to_json_schema()
     }
 }
 
 impl Schema<Value> for TupleSchema {
     fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<Value> {
         let arr = value.as_array().ok_or_else(|| {
             ValidationResult::from(ValidationError::invalid_type(
                 ValidationType::Array,
@@ -73,7 +105,15 @@ impl Schema<Value> for TupleSchema {
             ))
         })?;
 
-        if arr.len() != self.elements.len() {
+        if self.rest.is_some() && arr.len() < self.elements.len() {
+            return Err(ValidationError::custom(format!(
+                "Expected tuple of at least {} elements, got {}",
+                self.elements.len(),
+                arr.len()
+            ))
+            .into());
+        }
+        if self.rest.is_none() && arr.len() != self.elements.len() {
             return Err(ValidationError::custom(format!(
                 "Expected tuple of {} elements, got {}",
                 self.elements.len(),
@@ -85,12 +125,21 @@ impl Schema<Value> for TupleSchema {
         let mut result = Vec::with_capacity(arr.len());
         let mut validation_result = ValidationResult::new();
 
-        for (i, (element, schema)) in arr.iter().zip(&self.elements).enumerate() {
-            match schema.validate_element(element) {
+        // Elements past the fixed ones only remain when there is a rest schema
+        let schemas = self
+            .elements
+            .iter()
+            .chain(std::iter::repeat(&self.rest).flatten());
+        for (i, (element, schema)) in arr.iter().zip(schemas).enumerate() {
+            match schema.validate_element(element, opts) {
                 Ok(validated) => result.push(validated),
                 Err(mut errors) => {
                     errors.prefix_path(i.to_string());
                     validation_result.merge(errors);
+
+                    if opts.abort_early {
+                        break;
+                    }
                 }
             }
         }
@@ -101,6 +150,20 @@ impl Schema<Value> for TupleSchema {
             Err(validation_result)
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        let elements: Vec<Value> = self.elements.iter().map(|e| e.json_schema()).collect();
+        let items = match &self.rest {
+            Some(rest) => rest.json_schema(),
+            None => false.into(),
+        };
+        json!({
+            "type": "array",
+            "prefixItems": elements,
+            "items": items,
+            "minItems": self.elements.len(),
+        })
+    }
 }
 
 pub fn tuple() -> TupleSchema {
@@ -199,4 +262,96 @@ mod tests {
         assert!(schema.validate(&json!([1, 2])).is_err());
         assert!(schema.validate(&json!([1, 2, "3"])).is_err());
     }
+
+    // Rest Elements
+    #[test]
+    fn test_rest_accepts_any_tail_length() {
+        let schema = tuple().element(string()).element(number()).rest(string());
+
+        assert_eq!(
+            schema.validate(&json!(["a", 1])).unwrap(),
+            json!(["a", 1.0])
+        );
+        assert_eq!(
+            schema.validate(&json!(["a", 1, "b", "c"])).unwrap(),
+            json!(["a", 1.0, "b", "c"])
+        );
+    }
+
+    #[test]
+    fn test_rest_requires_fixed_elements() {
+        let schema = tuple().element(string()).element(number()).rest(string());
+
+        let err = schema.validate(&json!(["a"])).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Expected tuple of at least 2 elements, got 1"
+        );
+        assert!(schema.validate(&json!([1, 1])).is_err());
+    }
+
+    #[test]
+    fn test_rest_errors_use_absolute_index() {
+        let schema = tuple().element(string()).rest(number().int());
+
+        let err = schema.validate(&json!(["a", 1, 2.5, "x"])).unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+        assert_eq!(err.issues[0].path, vec!["2"]);
+        assert_eq!(err.issues[1].path, vec!["3"]);
+    }
+
+    #[test]
+    fn test_rest_only_tuple() {
+        let schema = tuple().rest(boolean());
+
+        assert!(schema.validate(&json!([])).is_ok());
+        assert!(schema.validate(&json!([true, false])).is_ok());
+        assert!(schema.validate(&json!([true, 1])).is_err());
+    }
+
+    // Validation Modes
+    #[test]
+    fn test_abort_early_stops_at_first_failing_element() {
+        let schema = tuple()
+            .element(number())
+            .element(string())
+            .element(boolean());
+        let input = json!(["x", 1, "y"]);
+
+        assert_eq!(schema.validate(&input).unwrap_err().issues.len(), 3);
+
+        let opts = ValidateOptions {
+            abort_early: true,
+            ..Default::default()
+        };
+        let err = schema.validate_with(&input, opts).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].path, vec!["0"]);
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = tuple().element(string()).element(boolean());
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "array",
+                "prefixItems": [{"type": "string"}, {"type": "boolean"}],
+                "items": false,
+                "minItems": 2
+            })
+        );
+
+        let schema = tuple().element(string()).rest(number());
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "type": "array",
+                "prefixItems": [{"type": "string"}],
+                "items": {"type": "number"},
+                "minItems": 1
+            })
+        );
+    }
 }
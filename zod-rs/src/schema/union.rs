@@ -1,14 +1,15 @@
-use crate::schema::Schema;
-use serde_json::Value;
+use crate::schema::{Schema, ValidateOptions};
+use serde_json::{json, Value};
 use std::{fmt::Debug, sync::Arc};
 use zod_rs_util::{ValidateResult, ValidationError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct UnionSchema<T>
 where
     T: Debug,
 {
     schemas: Vec<Arc<dyn Schema<T> + Send + Sync>>,
+    labels: Vec<Option<String>>,
 }
 
 impl<T> UnionSchema<T>
@@ -18,6 +19,7 @@ where
     pub fn new() -> Self {
         Self {
             schemas: Vec::new(),
+            labels: Vec::new(),
         }
     }
 
@@ -27,8 +29,49 @@ where
         T: Debug,
     {
         self.schemas.push(Arc::new(schema));
+        self.labels.push(None);
         self
     }
+
+    /// Adds a variant named `label`. When nothing matches, the error lists the
+    /// variants by name and groups each variant's issues under it.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use zod_rs::prelude::*;
+    ///
+    /// let schema = union()
+    ///     .labeled_variant("EmailString", string().email())
+    ///     .labeled_variant("UUID", string().uuid());
+    ///
+    /// let err = schema.validate(&json!("x")).unwrap_err();
+    /// assert_eq!(
+    ///     err.issues[0].to_string(),
+    ///     "Invalid input; did not match any of: EmailString, UUID"
+    /// );
+    /// ```
+    pub fn labeled_variant<S>(mut self, label: impl Into<String>, schema: S) -> Self
+    where
+        S: Schema<T> + Send + Sync + Debug + 'static,
+        T: Debug,
+    {
+        self.schemas.push(Arc::new(schema));
+        self.labels.push(Some(label.into()));
+        self
+    }
+}
+
+// Derived `Clone` would require `T: Clone`, which the shared variants don't need
+impl<T> Clone for UnionSchema<T>
+where
+    T: Debug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            schemas: self.schemas.clone(),
+            labels: self.labels.clone(),
+        }
+    }
 }
 
 impl<T> Default for UnionSchema<T>
@@ -45,18 +88,30 @@ where
     T: Debug,
 {
     fn validate(&self, value: &Value) -> ValidateResult<T> {
-        let mut issues = vec![];
+        self.validate_with(value, ValidateOptions::default())
+    }
+
+    fn validate_with(&self, value: &Value, opts: ValidateOptions) -> ValidateResult<T> {
+        let mut branches = vec![];
 
         for schema in &self.schemas {
-            match schema.validate(value) {
+            match schema.validate_with(value, opts) {
                 Ok(result) => return Ok(result),
-                Err(error) => {
-                    issues.extend(error.issues);
-                }
+                Err(error) => branches.push(error.issues),
             }
         }
 
-        Err(ValidationError::invalid_union(issues).into())
+        if self.labels.iter().any(Option::is_some) {
+            let labels = self.labels.clone();
+            Err(ValidationError::invalid_labeled_union(branches, labels).into())
+        } else {
+            Err(ValidationError::invalid_union(branches).into())
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let schemas: Vec<Value> = self.schemas.iter().map(|s| s.to_json_schema()).collect();
+        json!({ "anyOf": schemas })
     }
 }
 
@@ -70,8 +125,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::{literal, number, string};
+    use crate::schema::{literal, number, object, string};
     use serde_json::json;
+    use zod_rs_util::Locale;
 
     #[test]
     fn test_union_validation() {
@@ -234,4 +290,138 @@ mod tests {
         assert!(schema.validate(&json!("e")).is_ok());
         assert!(schema.validate(&json!("f")).is_err());
     }
+
+    // Branch Errors
+    #[test]
+    fn test_errors_grouped_by_branch() {
+        let schema = union()
+            .variant(object().field("email", string().email()))
+            .variant(object().field("phone", string().min(7)));
+
+        let err = schema.validate(&json!({"phone": "123"})).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input; none of the 2 options matched"
+        );
+
+        let branches = err.union_branches(&[]).unwrap();
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branches[0][0].path, vec!["email"]);
+        assert_eq!(branches[1][0].path, vec!["phone"]);
+    }
+
+    #[test]
+    fn test_union_branches_by_path() {
+        let schema = object().field(
+            "id",
+            union().variant(string().uuid()).variant(string().email()),
+        );
+        let err = schema.validate(&json!({"id": true})).unwrap_err();
+
+        assert!(err.union_branches(&[]).is_none());
+        let branches = err.union_branches(&["id"]).unwrap();
+        assert_eq!(branches[0][0].path, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_verbose_rendering() {
+        let schema = union()
+            .variant(string().min(3))
+            .variant(string().starts_with("#"));
+        let err = schema.validate(&json!("ab")).unwrap_err();
+
+        assert_eq!(
+            err.local_verbose(Locale::En),
+            "Invalid input; none of the 2 options matched:\n  \
+             1) Too small: expected string to have >= 3 characters, received 2\n  \
+             2) Invalid value: must start with \"#\""
+        );
+    }
+
+    // Labeled Variants
+    #[test]
+    fn test_labeled_variants_in_messages() {
+        let schema = union()
+            .labeled_variant("EmailString", string().email())
+            .labeled_variant("UUID", string().uuid());
+
+        assert!(schema.validate(&json!("a@b.co")).is_ok());
+
+        let err = schema.validate(&json!("x")).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input; did not match any of: EmailString, UUID"
+        );
+        assert_eq!(
+            err.local_verbose(Locale::En),
+            "Invalid input; did not match any of: EmailString, UUID\n  \
+             EmailString: Invalid email address\n  \
+             UUID: Invalid UUID"
+        );
+        assert_eq!(err.union_branches(&[]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_mixed_labeled_and_plain_variants() {
+        let schema = union()
+            .labeled_variant("Slug", string().regex(r"^[a-z-]+$"))
+            .variant(string().email());
+
+        let err = schema.validate(&json!(true)).unwrap_err();
+        assert_eq!(
+            err.issues[0].to_string(),
+            "Invalid input; did not match any of: Slug, #2"
+        );
+        assert_eq!(
+            err.issues[0].local(Locale::Fr),
+            "Entrée invalide ; ne correspond à aucune des options : Slug, #2"
+        );
+    }
+
+    // Fluent Combinator
+    #[test]
+    fn test_or_builds_two_variant_union() {
+        let schema = string().email().or(string().length(0));
+
+        assert_eq!(schema.validate(&json!("")).unwrap(), "");
+        assert!(schema.validate(&json!("a@b.co")).is_ok());
+
+        let err = schema.validate(&json!("nope")).unwrap_err();
+        assert_eq!(err.union_branches(&[]).unwrap().len(), 2);
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"anyOf": [
+                {"type": "string", "format": "email"},
+                {"type": "string", "minLength": 0, "maxLength": 0}
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_or_with_literals() {
+        let schema = literal(1u8).or(literal(2u8));
+        assert!(schema.validate(&json!(2)).is_ok());
+        assert!(schema.validate(&json!(3)).is_err());
+    }
+
+    #[test]
+    fn test_clone_without_clone_output() {
+        #[derive(Debug, PartialEq)]
+        struct Tag(String);
+
+        let schema = union().variant(string().min(2).transform(Tag));
+        let cloned = schema.clone();
+        assert_eq!(cloned.validate(&json!("ok")).unwrap(), Tag("ok".into()));
+        assert!(cloned.validate(&json!("x")).is_err());
+    }
+
+    // JSON Schema
+    #[test]
+    fn test_json_schema() {
+        let schema = union().variant(literal("a")).variant(string().min(3));
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({"anyOf": [{"const": "a"}, {"type": "string", "minLength": 3}]})
+        );
+    }
 }
@@ -0,0 +1,72 @@
+use crate::schema::Schema;
+use serde_json::{json, Value};
+use zod_rs_util::ValidateResult;
+
+/// Accepts any JSON value, including `null`, and returns it unchanged.
+///
+/// Behaves exactly like [`AnySchema`](crate::AnySchema); the name signals that the
+/// value is validated later, e.g. with a schema picked from another field.
+#[derive(Debug, Clone)]
+pub struct UnknownSchema;
+
+impl UnknownSchema {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for UnknownSchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Schema<Value> for UnknownSchema {
+    fn validate(&self, value: &Value) -> ValidateResult<Value> {
+        Ok(value.clone())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({})
+    }
+}
+
+pub fn unknown() -> UnknownSchema {
+    UnknownSchema::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object};
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_validation() {
+        let schema = unknown();
+
+        assert_eq!(schema.validate(&json!(null)).unwrap(), json!(null));
+        assert_eq!(
+            schema.validate(&json!({"a": [1, 2]})).unwrap(),
+            json!({"a": [1, 2]})
+        );
+    }
+
+    #[test]
+    fn test_unknown_validated_later() {
+        let schema = object()
+            .field("kind", unknown())
+            .field("payload", unknown());
+        let parsed = schema
+            .validate(&json!({"kind": "count", "payload": 3}))
+            .unwrap();
+
+        assert!(number().int().validate(&parsed["payload"]).is_ok());
+        assert!(schema.validate(&json!({"kind": "count"})).is_err());
+    }
+
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(unknown().to_json_schema(), json!({}));
+    }
+}
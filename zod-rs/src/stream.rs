@@ -0,0 +1,181 @@
+//! Line-by-line validation of NDJSON input, enabled by the `stream` feature.
+//!
+//! [`validate_ndjson`] reads one line at a time, so a large export or log file
+//! is never held in memory as a whole.
+//!
+//! ```
+//! use serde_json::json;
+//! use zod_rs::prelude::*;
+//! use zod_rs::stream::validate_ndjson;
+//!
+//! let input = "{\"id\": \"a1\"}\n{\"id\": 2}\n";
+//! let schema = object().field("id", string());
+//!
+//! for (line, result) in validate_ndjson(input.as_bytes(), &schema) {
+//!     match result {
+//!         Ok(value) => assert_eq!(value, json!({"id": "a1"})),
+//!         Err(errors) => println!("line {line}: {errors}"),
+//!     }
+//! }
+//! ```
+
+use crate::schema::Schema;
+use serde_json::Value;
+use std::{
+    fmt::Debug,
+    io::{BufRead, Lines},
+    marker::PhantomData,
+};
+use zod_rs_util::{ValidateResult, ValidationError};
+
+/// Validates each line of `reader` as a JSON document against `schema`.
+///
+/// Any schema works, e.g. an `object()` for `Value` output or a derived type's
+/// `schema()`; pass `&schema` to keep using it afterwards.
+///
+/// Yields the 1-based line number with the result for that line. Blank lines are
+/// skipped but still counted, so the numbers match the input. A line that is not
+/// valid JSON yields a custom issue with the parse error. A read error yields a
+/// custom issue for the line it happened on and ends the iteration.
+pub fn validate_ndjson<R, S, T>(
+    reader: R,
+    schema: S,
+) -> impl Iterator<Item = (usize, ValidateResult<T>)>
+where
+    R: BufRead,
+    S: Schema<T>,
+    T: Debug,
+{
+    NdjsonLines {
+        lines: reader.lines(),
+        schema,
+        line: 0,
+        done: false,
+        _phantom: PhantomData,
+    }
+}
+
+struct NdjsonLines<R, S, T> {
+    lines: Lines<R>,
+    schema: S,
+    line: usize,
+    done: bool,
+    _phantom: PhantomData<T>,
+}
+
+impl<R, S, T> Iterator for NdjsonLines<R, S, T>
+where
+    R: BufRead,
+    S: Schema<T>,
+    T: Debug,
+{
+    type Item = (usize, ValidateResult<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let line = self.lines.next()?;
+            self.line += 1;
+
+            let result = match line {
+                Err(e) => {
+                    self.done = true;
+                    Err(ValidationError::custom(format!("Failed to read line: {e}")).into())
+                }
+                Ok(text) if text.trim().is_empty() => continue,
+                Ok(text) => match serde_json::from_str::<Value>(&text) {
+                    Ok(value) => self.schema.validate(&value),
+                    Err(e) => Err(ValidationError::custom(format!("Invalid JSON: {e}")).into()),
+                },
+            };
+            return Some((self.line, result));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{number, object, string};
+    use serde_json::json;
+    use std::io::{self, BufReader, Cursor, Read};
+
+    #[test]
+    fn test_validate_ndjson() {
+        let input = "{\"name\": \"Ada\", \"age\": 36}\n{\"name\": \"\", \"age\": 20}\n";
+        let schema = object()
+            .field("name", string().min(1))
+            .field("age", number().int());
+
+        let results: Vec<_> = validate_ndjson(Cursor::new(input), &schema).collect();
+        assert_eq!(results.len(), 2);
+
+        assert_eq!(results[0].0, 1);
+        assert_eq!(
+            results[0].1.as_ref().unwrap(),
+            &json!({"name": "Ada", "age": 36.0})
+        );
+
+        assert_eq!(results[1].0, 2);
+        let err = results[1].1.as_ref().unwrap_err();
+        assert_eq!(err.issues[0].path, vec!["name"]);
+    }
+
+    // ==================== EDGE CASE TESTS ====================
+
+    // Line numbers
+    #[test]
+    fn test_blank_lines_are_skipped_but_counted() {
+        let input = "1\n\n   \n2\r\n";
+        let results: Vec<_> = validate_ndjson(Cursor::new(input), number())
+            .map(|(line, result)| (line, result.unwrap()))
+            .collect();
+
+        assert_eq!(results, vec![(1, 1.0), (4, 2.0)]);
+    }
+
+    #[test]
+    fn test_last_line_without_newline() {
+        let results: Vec<_> = validate_ndjson(Cursor::new("\"a\"\n\"b\""), string()).collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].0, 2);
+        assert_eq!(results[1].1.as_ref().unwrap(), "b");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(validate_ndjson(Cursor::new(""), string()).count(), 0);
+    }
+
+    // Malformed input
+    #[test]
+    fn test_invalid_json_line_does_not_stop_iteration() {
+        let input = "\"ok\"\n{not json}\n\"also ok\"\n";
+        let results: Vec<_> = validate_ndjson(Cursor::new(input), string()).collect();
+
+        assert_eq!(results.len(), 3);
+        let err = results[1].1.as_ref().unwrap_err();
+        assert_eq!(err.issues[0].code(), "custom");
+        assert!(err.issues[0].to_string().starts_with("Invalid JSON: "));
+        assert!(results[2].1.is_ok());
+    }
+
+    #[test]
+    fn test_read_error_ends_iteration() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk gone"))
+            }
+        }
+
+        let results: Vec<_> = validate_ndjson(BufReader::new(Failing), string()).collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 1);
+        assert_eq!(
+            results[0].1.as_ref().unwrap_err().issues[0].to_string(),
+            "Failed to read line: disk gone"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Peak heap usage of large array validation, measured with a counting global
+//! allocator. It is process-wide, so it lives in its own test binary with a
+//! single test, away from other threads allocating at the same time.
+
+use serde_json::{json, Value};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use zod_rs::prelude::*;
+
+struct PeakAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let now = CURRENT.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(now, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAlloc = PeakAlloc;
+
+/// Bytes allocated at the peak of `f`, above what was live before it ran.
+fn peak_during(f: impl FnOnce()) -> usize {
+    let base = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(base, Ordering::SeqCst);
+    f();
+    PEAK.load(Ordering::SeqCst) - base
+}
+
+#[test]
+fn test_large_array_peak_memory() {
+    // `test_large_array` in the unit tests, scaled up to 100k elements
+    let valid: Value = (0..100_000).collect::<Vec<i32>>().into();
+    let invalid: Value = vec!["x"; 100_000].into();
+    let schema = array(number());
+    let capped = array(number()).max_errors(10);
+
+    // Collecting a Vec<f64> of every element
+    let collected = peak_during(|| {
+        let sum: f64 = schema.validate(&valid).unwrap().iter().sum();
+        assert_eq!(sum, 4_999_950_000.0);
+    });
+    let streamed = peak_during(|| {
+        let sum: f64 = schema
+            .validate_streaming(&valid)
+            .unwrap()
+            .map(|result| result.unwrap())
+            .sum();
+        assert_eq!(sum, 4_999_950_000.0);
+    });
+    assert!(collected >= 100_000 * std::mem::size_of::<f64>());
+    assert!(
+        streamed * 100 < collected,
+        "streaming peaked at {streamed} bytes, collecting at {collected}"
+    );
+
+    // Reporting every issue of an entirely invalid array, or only the first 10
+    let uncapped = peak_during(|| {
+        assert_eq!(schema.validate(&invalid).unwrap_err().len(), 100_000);
+    });
+    let with_cap = peak_during(|| {
+        assert_eq!(capped.validate(&invalid).unwrap_err().len(), 10);
+    });
+    assert!(
+        with_cap * 100 < uncapped,
+        "max_errors(10) peaked at {with_cap} bytes, no cap at {uncapped}"
+    );
+
+    // The array check itself still runs before streaming starts
+    assert!(schema.validate_streaming(&json!("nope")).is_err());
+}
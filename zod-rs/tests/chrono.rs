@@ -0,0 +1,56 @@
+//! chrono fields validate as the ISO 8601 strings serde writes for them.
+#![cfg(all(feature = "chrono", feature = "macros"))]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod_rs::prelude::*;
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Booking {
+    created_at: DateTime<Utc>,
+    day: NaiveDate,
+    starts: Option<NaiveTime>,
+    reminders: Vec<NaiveDateTime>,
+}
+
+#[test]
+fn test_chrono_fields() {
+    let booking = Booking {
+        created_at: Utc.with_ymd_and_hms(2024, 5, 1, 9, 30, 0).unwrap(),
+        day: NaiveDate::from_ymd_opt(2024, 5, 3).unwrap(),
+        starts: NaiveTime::from_hms_milli_opt(14, 0, 0, 250),
+        reminders: vec![NaiveDate::from_ymd_opt(2024, 5, 2)
+            .unwrap()
+            .and_hms_opt(18, 0, 0)
+            .unwrap()],
+    };
+
+    // What serde writes round-trips through the schema
+    let json = serde_json::to_value(&booking).unwrap();
+    assert_eq!(json["created_at"], json!("2024-05-01T09:30:00Z"));
+    let parsed = Booking::validate_and_parse(&json).unwrap();
+    assert_eq!(parsed.day, booking.day);
+    assert_eq!(parsed.starts, booking.starts);
+
+    let err = Booking::validate_and_parse(&json!({
+        "created_at": "2024-05-01 09:30:00",
+        "day": "2024-02-30",
+        "starts": "25:00:00",
+        "reminders": ["tomorrow"]
+    }))
+    .unwrap_err();
+    assert_eq!(err.issues.len(), 4);
+    assert_eq!(
+        err.errors_for_path(&["created_at"])[0].to_string(),
+        "created_at: Invalid ISO datetime"
+    );
+    assert_eq!(err.errors_for_path(&["reminders", "0"]).len(), 1);
+
+    let json_schema = Booking::schema().to_json_schema();
+    assert_eq!(
+        json_schema["properties"]["created_at"],
+        json!({"type": "string", "format": "date-time"})
+    );
+    assert_eq!(json_schema["properties"]["day"]["format"], json!("date"));
+}
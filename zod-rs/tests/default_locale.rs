@@ -0,0 +1,57 @@
+//! `set_default_locale` is process-wide, so it is exercised in its own test
+//! binary instead of alongside the unit tests that rely on English messages.
+#![cfg(feature = "macros")]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod_rs::prelude::*;
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Signup {
+    #[zod(min_length(3))]
+    username: String,
+    #[zod(email)]
+    email: String,
+}
+
+#[test]
+fn test_default_locale() {
+    let english = "Invalid input: expected string, received number";
+    assert_eq!(zod_rs::default_locale(), Locale::En);
+    assert_eq!(
+        string().validate(&json!(1)).unwrap_err().to_string(),
+        format!("\n  - {english}")
+    );
+
+    set_default_locale(Locale::Ar);
+    assert_eq!(zod_rs::default_locale(), Locale::Ar);
+
+    // Derived and hand-written schemas render in the default locale
+    let err = Signup::validate_and_parse(&json!({"username": "a", "email": "nope"})).unwrap_err();
+    assert_eq!(err.len(), 2);
+    for issue in &err {
+        assert_eq!(issue.locale(), None);
+        assert_eq!(issue.to_string(), issue.local(Locale::Ar));
+        assert_ne!(issue.to_string(), issue.local(Locale::En));
+    }
+    let err = string().validate(&json!(1)).unwrap_err();
+    assert_eq!(err.issues[0].to_string(), err.issues[0].local(Locale::Ar));
+
+    // A schema's own locale wins over the default
+    let schema = object().field("name", string()).locale(Locale::Fr);
+    let err = schema.validate(&json!({})).unwrap_err();
+    assert_eq!(err.issues[0].locale(), Some(Locale::Fr));
+    assert_eq!(err.issues[0].to_string(), err.issues[0].local(Locale::Fr));
+
+    // Explicit localization ignores the default
+    assert_eq!(
+        string().validate(&json!(1)).unwrap_err().issues[0].local(Locale::En),
+        english
+    );
+
+    set_default_locale(Locale::En);
+    assert_eq!(
+        string().validate(&json!(1)).unwrap_err().issues[0].to_string(),
+        english
+    );
+}
@@ -0,0 +1,59 @@
+//! `set_default_object_mode` is process-wide, so it is exercised in its own test
+//! binary instead of alongside the unit tests that rely on the default.
+#![cfg(feature = "macros")]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod_rs::prelude::*;
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+struct Profile {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ZodSchema)]
+#[zod(passthrough)]
+struct Metadata {
+    name: String,
+}
+
+#[test]
+fn test_default_object_mode() {
+    let input = json!({"name": "Ada", "role": "admin"});
+    let schema = object().field("name", string());
+
+    assert_eq!(zod_rs::default_object_mode(), ObjectMode::Passthrough);
+    assert_eq!(schema.validate(&input).unwrap(), input);
+
+    set_default_object_mode(ObjectMode::Strip);
+    assert_eq!(schema.validate(&input).unwrap(), json!({"name": "Ada"}));
+    assert_eq!(
+        Profile::schema().validate(&input).unwrap(),
+        json!({"name": "Ada"})
+    );
+    // An explicit mode, on a schema or a derived struct, wins over the default
+    assert_eq!(
+        schema.clone().passthrough().validate(&input).unwrap(),
+        input
+    );
+    assert_eq!(Metadata::schema().validate(&input).unwrap(), input);
+
+    set_default_object_mode(ObjectMode::Strict);
+    let err = schema.validate(&input).unwrap_err();
+    assert_eq!(err.issues[0].to_string(), "Unrecognized key: role");
+    assert!(Profile::validate_and_parse(&input).is_err());
+    assert!(Profile::validate_and_parse(&json!({"name": "Ada"})).is_ok());
+    assert_eq!(
+        schema.to_json_schema()["additionalProperties"],
+        json!(false)
+    );
+    assert!(schema.clone().strip().validate(&input).is_ok());
+    // A discriminated union's tag is not an unknown key of its variants
+    let shapes = discriminated_union("type").variant("circle", object().field("radius", number()));
+    assert!(shapes
+        .validate(&json!({"type": "circle", "radius": 1}))
+        .is_ok());
+
+    set_default_object_mode(ObjectMode::Passthrough);
+    assert_eq!(schema.validate(&input).unwrap(), input);
+}